@@ -1,2 +1,4 @@
+pub use ibc_proto::cosmos::tx::v1beta1::AuxSignerData;
 pub use ibc_proto::cosmos::tx::v1beta1::SignDoc;
+pub use ibc_proto::cosmos::tx::v1beta1::SignDocDirectAux;
 pub use ibc_proto::cosmos::tx::v1beta1::SignerInfo;
@@ -111,6 +111,58 @@ pub struct CompactBitArray {
     pub elems: Vec<u8>,
 }
 
+impl CompactBitArray {
+    /// Creates a bit array with `bits` bits, all of them unset
+    pub fn new(bits: usize) -> Self {
+        Self {
+            extra_bits_stored: (bits % 8) as u32,
+            elems: vec![0; bits.div_ceil(8)],
+        }
+    }
+
+    /// Returns the number of bits in the bit array
+    pub fn count(&self) -> usize {
+        match self.extra_bits_stored {
+            0 => self.elems.len() * 8,
+            extra => self
+                .elems
+                .len()
+                .checked_sub(1)
+                .map(|full| full * 8 + extra as usize)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns whether the bit at index `i` is set, bits out of range are unset
+    pub fn get_index(&self, i: usize) -> bool {
+        i < self.count()
+            && self
+                .elems
+                .get(i >> 3)
+                .is_some_and(|elem| elem & (1 << (7 - i % 8)) > 0)
+    }
+
+    /// Sets the bit at index `i` to `value`. Returns `false` if `i` is out of range.
+    pub fn set_index(&mut self, i: usize, value: bool) -> bool {
+        if i >= self.count() {
+            return false;
+        }
+
+        match self.elems.get_mut(i >> 3) {
+            Some(elem) if value => *elem |= 1 << (7 - i % 8),
+            Some(elem) => *elem &= !(1 << (7 - i % 8)),
+            None => return false,
+        }
+
+        true
+    }
+
+    /// Returns the number of set bits
+    pub fn num_true_bits(&self) -> usize {
+        (0..self.count()).filter(|i| self.get_index(*i)).count()
+    }
+}
+
 impl From<RawCompactBitArray> for CompactBitArray {
     fn from(value: RawCompactBitArray) -> Self {
         let RawCompactBitArray {
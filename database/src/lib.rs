@@ -27,6 +27,9 @@ pub trait Database: Clone + Send + Sync + 'static {
         &'a self,
         prefix: Vec<u8>,
     ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a;
+
+    /// Persist any buffered writes to disk
+    fn flush(&self) {}
 }
 
 pub trait DatabaseBuilder<DB> {
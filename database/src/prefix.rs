@@ -47,6 +47,10 @@ impl<T: Database> Database for PrefixDB<T> {
             (key.into_boxed_slice(), v)
         })
     }
+
+    fn flush(&self) {
+        self.db.flush()
+    }
 }
 
 #[cfg(test)]
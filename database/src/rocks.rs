@@ -57,6 +57,12 @@ impl Database for RocksDB {
             .map(|res| res.unwrap_or_else(|e| panic!("unrecoverable database error {}", e)))
             .take_while(move |(k, _)| k.starts_with(&prefix)) //rocks db returns keys beyond the prefix see https://github.com/rust-rocksdb/rust-rocksdb/issues/577
     }
+
+    fn flush(&self) {
+        self.db
+            .flush()
+            .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
+    }
 }
 
 #[cfg(test)]
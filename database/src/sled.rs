@@ -57,4 +57,8 @@ impl Database for SledDb {
                 )
             })
     }
+
+    fn flush(&self) {
+        let _ = self.0.flush().unwrap_or_corrupt();
+    }
 }
@@ -6,8 +6,12 @@ use crate::{
     store_keys::{GaiaParamsStoreKey, GaiaStoreKey},
     GaiaNodeQueryRequest, GaiaNodeQueryResponse,
 };
+use gears::baseapp::mode::RunTxMode;
+use gears::core::any::google::Any;
 use gears::tendermint::types::request::query::RequestQuery;
 use gears::types::tx::raw::TxWithRaw;
+use gears::x::ante::AnteDecorator;
+use gears::x::post::BasePostHandler;
 use gears::{application::handlers::node::ABCIHandler, x::ante::BaseAnteHandler};
 use gears::{application::handlers::node::ModuleInfo, context::init::InitContext};
 use gears::{application::handlers::node::TxError, config::Config};
@@ -50,8 +54,22 @@ impl ModuleInfo for GovModuleInfo {
     const NAME: &'static str = "gov";
 }
 
+/// Ante handler of gaia, which runs the default steps of the cosmos sdk
+pub type GaiaAnteHandler = BaseAnteHandler<
+    bank::Keeper<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+        GaiaModules,
+    >,
+    auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+    GaiaStoreKey,
+    DefaultSignGasConsumer,
+    GaiaModules,
+>;
+
 #[derive(Debug, Clone)]
-pub struct GaiaABCIHandler {
+pub struct GaiaABCIHandler<AH: AnteDecorator<GaiaStoreKey> = GaiaAnteHandler> {
     bank_abci_handler: bank::BankABCIHandler<
         GaiaStoreKey,
         GaiaParamsStoreKey,
@@ -79,16 +97,15 @@ pub struct GaiaABCIHandler {
         StakingModuleInfo,
     >,
     ibc_abci_handler: ibc_rs::ABCIHandler<GaiaStoreKey, GaiaParamsStoreKey, IbcModuleInfo>,
-    ante_handler: BaseAnteHandler<
+    ante_handler: AH,
+    post_handler: BasePostHandler<
         bank::Keeper<
             GaiaStoreKey,
             GaiaParamsStoreKey,
             auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
             GaiaModules,
         >,
-        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
         GaiaStoreKey,
-        DefaultSignGasConsumer,
         GaiaModules,
     >,
     genutil_handler: GenutilAbciHandler<
@@ -107,7 +124,7 @@ pub struct GaiaABCIHandler {
             GaiaModules,
         >,
         GaiaModules,
-        DefaultSignGasConsumer,
+        AH,
     >,
     gov_handler: GovAbciHandler<
         GaiaStoreKey,
@@ -189,6 +206,7 @@ impl GaiaABCIHandler {
             DefaultSignGasConsumer,
             GaiaModules::FeeCollector,
         );
+        let post_handler = BasePostHandler::new(bank_keeper.clone());
 
         let gov_keeper = GovKeeper::new(
             GaiaStoreKey::Gov,
@@ -217,12 +235,37 @@ impl GaiaABCIHandler {
             staking_abci_handler: staking::StakingABCIHandler::new(staking_keeper),
             ibc_abci_handler: ibc_rs::ABCIHandler::new(ibc_keeper.clone()),
             ante_handler,
+            post_handler,
             gov_handler: GovAbciHandler::new(gov_keeper),
         }
     }
 }
 
-impl ABCIHandler for GaiaABCIHandler {
+impl<AH: AnteDecorator<GaiaStoreKey> + Clone> GaiaABCIHandler<AH> {
+    /// Replaces the ante handler, e.g. with a chain of the current one and the decorators
+    /// of an app. Gentxs are checked by the new ante handler too.
+    pub fn with_ante_handler<N: AnteDecorator<GaiaStoreKey> + Clone>(
+        self,
+        ante_handler: impl FnOnce(AH) -> N,
+    ) -> GaiaABCIHandler<N> {
+        let ante_handler = ante_handler(self.ante_handler);
+
+        GaiaABCIHandler {
+            bank_abci_handler: self.bank_abci_handler,
+            auth_abci_handler: self.auth_abci_handler,
+            staking_abci_handler: self.staking_abci_handler,
+            ibc_abci_handler: self.ibc_abci_handler,
+            genutil_handler: self.genutil_handler.with_ante_handler(ante_handler.clone()),
+            ante_handler,
+            post_handler: self.post_handler,
+            gov_handler: self.gov_handler,
+        }
+    }
+}
+
+impl<AH: AnteDecorator<GaiaStoreKey> + Clone + Send + Sync + 'static> ABCIHandler
+    for GaiaABCIHandler<AH>
+{
     type Message = Message;
     type Genesis = GenesisState;
     type StoreKey = GaiaStoreKey;
@@ -233,7 +276,7 @@ impl ABCIHandler for GaiaABCIHandler {
         &self,
         ctx: &mut TxContext<'_, DB, GaiaStoreKey>,
         msg: &Message,
-    ) -> Result<(), TxError> {
+    ) -> Result<Any, TxError> {
         match msg {
             Message::Bank(msg) => self.bank_abci_handler.msg(ctx, msg),
             Message::Staking(msg) => self.staking_abci_handler.msg(ctx, msg),
@@ -303,15 +346,21 @@ impl ABCIHandler for GaiaABCIHandler {
         &self,
         ctx: &mut TxContext<'_, DB, GaiaStoreKey>,
         tx: &TxWithRaw<Message>,
-        is_check: bool,
+        mode: RunTxMode,
+    ) -> Result<(), TxError> {
+        let node_opt = ctx.node_opt.clone();
+        let gas_meter = ctx.gas_meter.clone();
+        self.ante_handler
+            .ante_handle(ctx, tx, mode, &node_opt, &gas_meter)
+    }
+
+    fn run_post_handlers<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, GaiaStoreKey>,
+        tx: &TxWithRaw<Message>,
+        mode: RunTxMode,
     ) -> Result<(), TxError> {
-        self.ante_handler.run(
-            ctx,
-            tx,
-            is_check,
-            ctx.node_opt.clone(),
-            ctx.gas_meter.clone(),
-        )
+        self.post_handler.run(ctx, tx, mode)
     }
 
     fn typed_query<DB: Database + Send + Sync>(
@@ -1,9 +1,83 @@
 use gears::config::ApplicationConfig;
 use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Serialize, Default, Clone)]
+/// Messages of IBC relayers, which are exempt from the minimum gas prices by default
+pub const DEFAULT_BYPASS_MIN_FEE_MSG_TYPES: [&str; 5] = [
+    "/ibc.core.channel.v1.MsgRecvPacket",
+    "/ibc.core.channel.v1.MsgAcknowledgement",
+    "/ibc.core.client.v1.MsgUpdateClient",
+    "/ibc.core.channel.v1.MsgTimeout",
+    "/ibc.core.channel.v1.MsgTimeoutOnClose",
+];
+
+pub const DEFAULT_MAX_TOTAL_BYPASS_MIN_FEE_MSG_GAS_USAGE: u64 = 1_000_000;
+
+#[derive(Deserialize, Serialize, Clone)]
+#[serde(default)]
 pub struct AppConfig {
     pub example: u32,
+    /// Type URLs of messages which are exempt from the minimum gas prices in CheckTx
+    pub bypass_min_fee_msg_types: Vec<String>,
+    /// Maximum gas limit of txs whose messages are exempt from the minimum gas prices
+    pub max_total_bypass_min_fee_msg_gas_usage: u64,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            example: Default::default(),
+            bypass_min_fee_msg_types: DEFAULT_BYPASS_MIN_FEE_MSG_TYPES
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            max_total_bypass_min_fee_msg_gas_usage: DEFAULT_MAX_TOTAL_BYPASS_MIN_FEE_MSG_GAS_USAGE,
+        }
+    }
+}
+
+impl ApplicationConfig for AppConfig {
+    fn bypass_min_fee_msg_types(&self) -> Vec<String> {
+        self.bypass_min_fee_msg_types.clone()
+    }
+
+    fn max_total_bypass_min_fee_msg_gas_usage(&self) -> u64 {
+        self.max_total_bypass_min_fee_msg_gas_usage
+    }
 }
 
-impl ApplicationConfig for AppConfig {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_bypass_options_default_to_relayer_messages() {
+        let config: AppConfig =
+            serde_json::from_str(r#"{ "example": 1 }"#).expect("hard coded config is valid");
+
+        assert_eq!(
+            config.bypass_min_fee_msg_types(),
+            DEFAULT_BYPASS_MIN_FEE_MSG_TYPES
+        );
+        assert_eq!(
+            config.max_total_bypass_min_fee_msg_gas_usage(),
+            DEFAULT_MAX_TOTAL_BYPASS_MIN_FEE_MSG_GAS_USAGE
+        );
+    }
+
+    #[test]
+    fn bypass_options_are_read_from_config() {
+        let config: AppConfig = serde_json::from_str(
+            r#"{
+                "bypass_min_fee_msg_types": ["/cosmos.bank.v1beta1.MsgSend"],
+                "max_total_bypass_min_fee_msg_gas_usage": 200000
+            }"#,
+        )
+        .expect("hard coded config is valid");
+
+        assert_eq!(
+            config.bypass_min_fee_msg_types(),
+            vec!["/cosmos.bank.v1beta1.MsgSend".to_owned()]
+        );
+        assert_eq!(config.max_total_bypass_min_fee_msg_gas_usage(), 200_000);
+    }
+}
@@ -351,9 +351,9 @@ impl RouterBuilder<GaiaNodeQueryRequest, GaiaNodeQueryResponse> for GaiaCore {
             .add_service(reflection_service)
             .add_service(staking::grpc::new(app.clone()))
             .add_service(auth::grpc::new(app.clone()))
-            .add_service(bank::grpc::new(app))
+            .add_service(bank::grpc::new(app.clone()))
             .add_service(health_server())
-            .add_service(tx_server())
+            .add_service(tx_server(app))
     }
 }
 
@@ -23,8 +23,8 @@ impl ValueRenderer for Message {
     fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
         match self {
             Message::Bank(msg) => msg.format(get_metadata),
-            Message::Staking(_) => Err(RenderError::NotImplemented),
-            Message::IBC(_) => Err(RenderError::NotImplemented),
+            Message::Staking(msg) => msg.format(get_metadata),
+            Message::IBC(msg) => msg.format(get_metadata),
         }
     }
 }
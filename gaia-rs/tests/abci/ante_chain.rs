@@ -0,0 +1,165 @@
+use std::cell::RefCell;
+use std::num::NonZero;
+use std::sync::{Arc, Mutex};
+
+use gaia_rs::abci_handler::GaiaABCIHandler;
+use gaia_rs::config::AppConfig;
+use gaia_rs::message::Message;
+use gaia_rs::store_keys::GaiaStoreKey;
+use gears::application::handlers::node::TxError;
+use gears::baseapp::mode::RunTxMode;
+use gears::baseapp::options::NodeOptions;
+use gears::config::Config;
+use gears::context::TransactionalContext;
+use gears::signing::renderer::amino_renderer::AminoRenderer;
+use gears::signing::renderer::value_renderer::ValueRenderer;
+use gears::store::database::Database;
+use gears::tendermint::types::time::timestamp::Timestamp;
+use gears::types::address::AccAddress;
+use gears::types::base::coins::Coins;
+use gears::types::gas::kind::TxKind;
+use gears::types::gas::GasMeter;
+use gears::types::msg::send::MsgSend;
+use gears::types::tx::raw::TxWithRaw;
+use gears::types::tx::TxMessage;
+use gears::utils::node::generate_tx;
+use gears::x::ante::{AnteDecorator, AnteHandlerBuilder};
+
+use crate::{balance, setup_mock_node_with_handler};
+
+/// Records its name when it runs
+#[derive(Debug, Clone)]
+struct RecordingDecorator {
+    name: &'static str,
+    calls: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl AnteDecorator<GaiaStoreKey> for RecordingDecorator {
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, GaiaStoreKey>,
+    >(
+        &self,
+        _ctx: &mut CTX,
+        _tx: &TxWithRaw<M>,
+        _mode: RunTxMode,
+        _node_opt: &NodeOptions,
+        _gas_meter: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        self.calls.lock().expect("poisoned lock").push(self.name);
+        Ok(())
+    }
+}
+
+/// Rejects every tx
+#[derive(Debug, Clone)]
+struct RejectingDecorator;
+
+impl AnteDecorator<GaiaStoreKey> for RejectingDecorator {
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, GaiaStoreKey>,
+    >(
+        &self,
+        _ctx: &mut CTX,
+        _tx: &TxWithRaw<M>,
+        _mode: RunTxMode,
+        _node_opt: &NodeOptions,
+        _gas_meter: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        Err(TxError {
+            msg: "rejected by the app".into(),
+            code: NonZero::new(1).expect("hard coded code is not zero"),
+            codespace: "app",
+        })
+    }
+}
+
+fn send_msg(from_address: AccAddress) -> Message {
+    Message::Bank(bank::Message::Send(MsgSend {
+        from_address,
+        to_address: "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+            .parse()
+            .expect("hard coded address is valid"),
+        amount: Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+            .expect("hard coded coins are valid"),
+    }))
+}
+
+#[test]
+/// Decorators of an app run in the order they were added around the base ante handler
+fn custom_decorators_run_in_order() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let recording = |name| RecordingDecorator {
+        name,
+        calls: calls.clone(),
+    };
+
+    let config: Config<AppConfig> = Config::default();
+    let handler = GaiaABCIHandler::new(config).with_ante_handler(|base| {
+        AnteHandlerBuilder::new(recording("before"))
+            .decorator(base)
+            .decorator(recording("after"))
+            .build()
+    });
+    let (mut node, user) = setup_mock_node_with_handler(handler, None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let tx = generate_tx(
+        vec1::vec1![send_msg(user.address())],
+        0,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], Timestamp::UNIX_EPOCH);
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+
+    assert_eq!(
+        *calls.lock().expect("poisoned lock"),
+        vec!["before", "after"]
+    );
+    // 34uatom - 10uatom transfer - 1uatom fee deducted by the base ante handler
+    assert_eq!(balance(&node, user.address()), "23uatom");
+}
+
+#[test]
+/// A failing decorator stops the chain, so neither the following decorators nor the
+/// base ante handler run
+fn failing_decorator_stops_chain() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let recording = |name| RecordingDecorator {
+        name,
+        calls: calls.clone(),
+    };
+
+    let config: Config<AppConfig> = Config::default();
+    let handler = GaiaABCIHandler::new(config).with_ante_handler(|base| {
+        AnteHandlerBuilder::new(recording("before"))
+            .decorator(RejectingDecorator)
+            .decorator(base)
+            .decorator(recording("after"))
+            .build()
+    });
+    let (mut node, user) = setup_mock_node_with_handler(handler, None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let tx = generate_tx(
+        vec1::vec1![send_msg(user.address())],
+        0,
+        &user,
+        node.chain_id().clone(),
+    );
+    let res = node.step(vec![tx], Timestamp::UNIX_EPOCH);
+    let tx_res = &res.tx_responses[0];
+    assert_eq!(tx_res.code, 1);
+    assert_eq!(tx_res.codespace, "app");
+    assert_eq!(tx_res.log, "rejected by the app");
+
+    assert_eq!(*calls.lock().expect("poisoned lock"), vec!["before"]);
+    // no fee was deducted
+    assert_eq!(balance(&node, user.address()), "34uatom");
+}
@@ -0,0 +1,54 @@
+use gears::tendermint::types::request::check_tx::{CheckTxType, RequestCheckTx};
+use gears::types::base::coins::Coins;
+use gears::types::msg::send::MsgSend;
+use gears::utils::node::generate_tx;
+use gears::{tendermint::types::time::timestamp::Timestamp, types::address::AccAddress};
+
+use crate::setup_mock_node;
+
+#[test]
+/// In this scenario, a new transaction is checked and the events of the ante handler
+/// which allow to search it by fee payer, sequence and signature are returned.
+fn check_tx_emits_ante_events() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+    let amount = Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+        .expect("hard coded coins are valid");
+
+    let msg = gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address,
+        amount,
+    }));
+
+    let tx = generate_tx(vec1::vec1![msg], 0, &user, node.chain_id().clone());
+
+    let res = node.check_tx(RequestCheckTx {
+        tx,
+        r#type: CheckTxType::New.into(),
+    });
+    assert_eq!(res.code, 0, "{}", res.log);
+
+    let tx_attribute = |key: &str| {
+        res.events
+            .iter()
+            .filter(|event| event.r#type == "tx")
+            .flat_map(|event| event.attributes.iter())
+            .filter(|attribute| attribute.key == key.as_bytes())
+            .map(|attribute| String::from_utf8_lossy(&attribute.value).into_owned())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(tx_attribute("fee"), vec!["1uatom".to_owned()]);
+    assert_eq!(tx_attribute("fee_payer"), vec![user.address().to_string()]);
+    assert_eq!(
+        tx_attribute("acc_seq"),
+        vec![format!("{}/0", user.address())]
+    );
+    assert_eq!(tx_attribute("signature").len(), 1);
+}
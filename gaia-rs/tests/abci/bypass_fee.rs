@@ -0,0 +1,154 @@
+use gaia_rs::abci_handler::GaiaABCIHandler;
+use gaia_rs::config::AppConfig;
+use gaia_rs::genesis::GenesisState;
+use gaia_rs::message::Message;
+use gaia_rs::store_keys::GaiaParamsStoreKey;
+use gaia_rs::GaiaApplication;
+use gears::baseapp::options::NodeOptions;
+use gears::baseapp::BaseApp;
+use gears::config::Config;
+use gears::store::database::MemDB;
+use gears::tendermint::types::request::check_tx::{CheckTxType, RequestCheckTx};
+use gears::tendermint::types::time::timestamp::Timestamp;
+use gears::types::address::AccAddress;
+use gears::types::auth::fee::Fee;
+use gears::types::base::coins::Coins;
+use gears::types::msg::send::MsgSend;
+use gears::utils::node::{generate_tx_with_fee, MockNode, User};
+use staking::DelegateMsg;
+use vec1::Vec1;
+
+use crate::{balance, setup_mock_node_with_app};
+
+const BYPASS_MSG_TYPE: &str = "/cosmos.bank.v1beta1.MsgSend";
+const MAX_BYPASS_GAS: u64 = 200_000;
+
+type GaiaNode =
+    MockNode<BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication>, GenesisState>;
+
+/// Node which requires 1uatom per unit of gas, except for txs which only send coins
+fn setup_node() -> (GaiaNode, User) {
+    let options = NodeOptions::new("1uatom".parse().expect("hard coded gas price is valid"))
+        .with_bypass_min_fee_msg_types(vec![BYPASS_MSG_TYPE.to_owned()], MAX_BYPASS_GAS);
+    let config: Config<AppConfig> = Config::default();
+    let app = BaseApp::new(
+        MemDB::new(),
+        GaiaParamsStoreKey::BaseApp,
+        GaiaABCIHandler::new(config),
+        options,
+    );
+
+    let (mut node, user) = setup_mock_node_with_app(app, None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    (node, user)
+}
+
+fn send_msg(from_address: AccAddress) -> Message {
+    Message::Bank(bank::Message::Send(MsgSend {
+        from_address,
+        to_address: "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+            .parse()
+            .expect("hard coded address is valid"),
+        amount: Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+            .expect("hard coded coins are valid"),
+    }))
+}
+
+fn delegate_msg(delegator_address: AccAddress) -> Message {
+    Message::Staking(staking::Message::Delegate(DelegateMsg {
+        delegator_address,
+        validator_address: "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4"
+            .parse()
+            .expect("hard coded address is valid"),
+        amount: "10uatom".parse().expect("hard coded coin is valid"),
+    }))
+}
+
+/// Fee without any coins
+fn zero_fee(gas_limit: u64) -> Fee {
+    Fee {
+        amount: None,
+        gas_limit: gas_limit.try_into().expect("gas limit is valid"),
+        payer: None,
+        granter: "".into(),
+    }
+}
+
+fn check_tx(node: &GaiaNode, user: &User, msgs: Vec1<Message>, fee: Fee) -> (u32, String) {
+    let res = node.check_tx(RequestCheckTx {
+        tx: generate_tx_with_fee(msgs, 0, user, node.chain_id().clone(), fee),
+        r#type: CheckTxType::New.into(),
+    });
+
+    (res.code, res.log)
+}
+
+#[test]
+/// Txs which only contain bypass messages don't need to pay the minimum gas prices
+fn bypass_tx_under_gas_cap_is_accepted_without_fee() {
+    let (node, user) = setup_node();
+
+    let (code, log) = check_tx(
+        &node,
+        &user,
+        vec1::vec1![send_msg(user.address()), send_msg(user.address())],
+        zero_fee(MAX_BYPASS_GAS),
+    );
+    assert_eq!(code, 0, "{log}");
+}
+
+#[test]
+/// Bypass txs with a gas limit above the cap of the node must pay the minimum gas prices
+fn bypass_tx_over_gas_cap_is_rejected() {
+    let (node, user) = setup_node();
+
+    let (code, log) = check_tx(
+        &node,
+        &user,
+        vec1::vec1![send_msg(user.address())],
+        zero_fee(MAX_BYPASS_GAS + 1),
+    );
+    assert_ne!(code, 0);
+    assert_eq!(log, "fee required");
+}
+
+#[test]
+/// A single message which isn't a bypass message makes the whole tx pay the minimum gas prices
+fn tx_mixing_bypass_and_other_messages_is_rejected() {
+    let (node, user) = setup_node();
+
+    let (code, log) = check_tx(
+        &node,
+        &user,
+        vec1::vec1![send_msg(user.address()), delegate_msg(user.address())],
+        zero_fee(MAX_BYPASS_GAS),
+    );
+    assert_ne!(code, 0);
+    assert_eq!(log, "fee required");
+}
+
+#[test]
+/// The minimum gas prices and their bypass only apply to the mempool, not to DeliverTx
+fn deliver_tx_is_unaffected() {
+    let (mut node, user) = setup_node();
+
+    // a transfer with a fee way below the minimum gas prices
+    let fee = Fee {
+        amount: Some(
+            Coins::new(vec!["1uatom".parse().expect("hard coded coin is valid")])
+                .expect("hard coded coins are valid"),
+        ),
+        ..zero_fee(MAX_BYPASS_GAS + 1)
+    };
+    let msgs = vec1::vec1![send_msg(user.address())];
+
+    let (code, _) = check_tx(&node, &user, msgs.clone(), fee.clone());
+    assert_ne!(code, 0);
+
+    let tx = generate_tx_with_fee(msgs, 0, &user, node.chain_id().clone(), fee);
+    let res = node.step(vec![tx], Timestamp::UNIX_EPOCH);
+    assert_eq!(res.tx_responses[0].code, 0, "{}", res.tx_responses[0].log);
+    // 34uatom - 10uatom transfer - 1uatom fee
+    assert_eq!(balance(&node, user.address()), "23uatom");
+}
@@ -0,0 +1,153 @@
+use gaia_rs::abci_handler::GaiaABCIHandler;
+use gaia_rs::config::AppConfig;
+use gaia_rs::genesis::GenesisState;
+use gaia_rs::message::Message;
+use gaia_rs::store_keys::GaiaParamsStoreKey;
+use gears::application::handlers::node::{ABCIHandler, TxError};
+use gears::baseapp::mode::RunTxMode;
+use gears::baseapp::{BaseAppParamsKeeper, ConsensusParams};
+use gears::config::Config;
+use gears::context::QueryableContext;
+use gears::context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext};
+use gears::core::any::google::Any;
+use gears::store::database::Database;
+use gears::tendermint::types::proto::validator::ValidatorUpdate;
+use gears::tendermint::types::request::{
+    begin_block::RequestBeginBlock, end_block::RequestEndBlock, query::RequestQuery,
+};
+use gears::tendermint::types::time::timestamp::Timestamp;
+use gears::types::tx::raw::TxWithRaw;
+
+use crate::setup_mock_node_with_handler;
+
+const UPDATE_HEIGHT: u32 = 2;
+
+/// Gaia handler which changes the consensus params at the end of block `UPDATE_HEIGHT`,
+/// like an executed governance proposal would
+#[derive(Clone)]
+struct ParamsUpdatingHandler {
+    inner: GaiaABCIHandler,
+    update: fn(&mut ConsensusParams),
+}
+
+impl ParamsUpdatingHandler {
+    fn new(update: fn(&mut ConsensusParams)) -> Self {
+        let config: Config<AppConfig> = Config::default();
+        Self {
+            inner: GaiaABCIHandler::new(config),
+            update,
+        }
+    }
+}
+
+impl ABCIHandler for ParamsUpdatingHandler {
+    type Message = Message;
+    type Genesis = GenesisState;
+    type StoreKey = <GaiaABCIHandler as ABCIHandler>::StoreKey;
+    type QReq = <GaiaABCIHandler as ABCIHandler>::QReq;
+    type QRes = <GaiaABCIHandler as ABCIHandler>::QRes;
+
+    fn typed_query<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        query: Self::QReq,
+    ) -> Self::QRes {
+        self.inner.typed_query(ctx, query)
+    }
+
+    fn run_ante_checks<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, Self::StoreKey>,
+        tx: &TxWithRaw<Self::Message>,
+        mode: RunTxMode,
+    ) -> Result<(), TxError> {
+        self.inner.run_ante_checks(ctx, tx, mode)
+    }
+
+    fn msg<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, Self::StoreKey>,
+        msg: &Self::Message,
+    ) -> Result<Any, TxError> {
+        self.inner.msg(ctx, msg)
+    }
+
+    fn begin_block<DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, Self::StoreKey>,
+        request: RequestBeginBlock,
+    ) {
+        self.inner.begin_block(ctx, request)
+    }
+
+    fn end_block<DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, Self::StoreKey>,
+        request: RequestEndBlock,
+    ) -> Vec<ValidatorUpdate> {
+        let validator_updates = self.inner.end_block(ctx, request);
+
+        if ctx.height() == UPDATE_HEIGHT {
+            let keeper = BaseAppParamsKeeper {
+                params_subspace_key: GaiaParamsStoreKey::BaseApp,
+            };
+            let mut params = keeper.consensus_params(&*ctx);
+            (self.update)(&mut params);
+            keeper.set_consensus_params(ctx, params);
+        }
+
+        validator_updates
+    }
+
+    fn init_genesis<DB: Database>(
+        &self,
+        ctx: &mut InitContext<'_, DB, Self::StoreKey>,
+        genesis: Self::Genesis,
+    ) -> Vec<ValidatorUpdate> {
+        self.inner.init_genesis(ctx, genesis)
+    }
+
+    fn query<DB: Database + Send + Sync>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        query: RequestQuery,
+    ) -> Result<Vec<u8>, gears::baseapp::errors::QueryError> {
+        self.inner.query(ctx, query)
+    }
+}
+
+#[test]
+/// EndBlock returns the consensus params of the block in which they changed, and only then
+fn end_block_returns_changed_consensus_params() {
+    let (mut node, _) = setup_mock_node_with_handler(
+        ParamsUpdatingHandler::new(|params| params.block.max_gas = 5_000_000),
+        None::<&str>,
+    );
+
+    let res = node.step(vec![], Timestamp::UNIX_EPOCH);
+    assert_eq!(res.height, UPDATE_HEIGHT - 1);
+    assert_eq!(res.consensus_param_updates, None);
+
+    let res = node.step(vec![], Timestamp::UNIX_EPOCH);
+    assert_eq!(res.height, UPDATE_HEIGHT);
+    let updates = res
+        .consensus_param_updates
+        .expect("consensus params changed in this block");
+    assert_eq!(updates.block.max_gas, 5_000_000);
+
+    // the new params are now the params of every block
+    let res = node.step(vec![], Timestamp::UNIX_EPOCH);
+    assert_eq!(res.consensus_param_updates, None);
+}
+
+#[test]
+/// Writing the current consensus params back to the store isn't an update
+fn end_block_returns_no_update_for_unchanged_consensus_params() {
+    let (mut node, _) =
+        setup_mock_node_with_handler(ParamsUpdatingHandler::new(|_| ()), None::<&str>);
+
+    for _ in 0..=UPDATE_HEIGHT {
+        let res = node.step(vec![], Timestamp::UNIX_EPOCH);
+        assert_eq!(res.consensus_param_updates, None);
+    }
+}
@@ -0,0 +1,288 @@
+use bytes::Bytes;
+use gaia_rs::abci_handler::GaiaABCIHandler;
+use gaia_rs::config::AppConfig;
+use gaia_rs::genesis::GenesisState;
+use gaia_rs::message::Message;
+use gears::application::handlers::node::{ABCIHandler, TxError};
+use gears::baseapp::mode::RunTxMode;
+use gears::config::Config;
+use gears::context::{
+    block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext, InfallibleContext,
+};
+use gears::core::any::google::Any;
+use gears::store::database::Database;
+use gears::tendermint::application::{ABCIApplication, ABCIPlusPlusApplication};
+use gears::tendermint::types::proto::info::{ExtendedCommitInfo, ExtendedVoteInfo, LastCommitInfo};
+use gears::tendermint::types::proto::validator::{Validator, ValidatorUpdate, VotingPower};
+use gears::tendermint::types::request::finalize_block::RequestFinalizeBlock;
+use gears::tendermint::types::request::proposal::RequestPrepareProposal;
+use gears::tendermint::types::request::vote_extension::{
+    RequestExtendVote, RequestVerifyVoteExtension,
+};
+use gears::tendermint::types::request::{
+    begin_block::RequestBeginBlock, end_block::RequestEndBlock, query::RequestQuery,
+};
+use gears::tendermint::types::response::vote_extension::VerifyStatus;
+use gears::types::address::ValAddress;
+use gears::types::base::coins::Coins;
+use gears::types::msg::send::MsgSend;
+use gears::types::tx::raw::TxWithRaw;
+use gears::utils::node::generate_tx;
+use gears::{tendermint::types::time::timestamp::Timestamp, types::address::AccAddress};
+
+use crate::{setup_mock_node, setup_mock_node_with_handler};
+
+/// Vote extension of validators which want the next proposal in reverse mempool order
+const REVERSE: &[u8] = b"reverse";
+
+fn send_msg(from_address: AccAddress) -> Message {
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+    let amount = Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+        .expect("hard coded coins are valid");
+
+    Message::Bank(bank::Message::Send(MsgSend {
+        from_address,
+        to_address,
+        amount,
+    }))
+}
+
+fn prepare_request(txs: Vec<Bytes>, votes: Vec<ExtendedVoteInfo>) -> RequestPrepareProposal {
+    RequestPrepareProposal {
+        max_tx_bytes: txs.iter().map(|tx| tx.len() as i64).sum(),
+        txs,
+        local_last_commit: ExtendedCommitInfo { round: 0, votes },
+        misbehavior: vec![],
+        height: 2,
+        time: Timestamp::UNIX_EPOCH,
+        next_validators_hash: Default::default(),
+        proposer_address: Default::default(),
+    }
+}
+
+#[test]
+/// In this scenario, a block is proposed and executed with ABCI++ calls instead of
+/// BeginBlock, DeliverTx and EndBlock. The app hash returned by FinalizeBlock must be
+/// the hash which is committed.
+fn finalize_block_returns_committed_app_hash() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let tx = generate_tx(
+        vec1::vec1![send_msg(user.address())],
+        0,
+        &user,
+        node.chain_id().clone(),
+    );
+
+    let mut request = prepare_request(vec![tx.clone(), tx.clone()], vec![]);
+    request.max_tx_bytes = tx.len() as i64;
+    let proposal = node.app().prepare_proposal(request);
+    assert_eq!(proposal.txs.len(), 1);
+
+    let res = node.app().finalize_block(RequestFinalizeBlock {
+        txs: proposal.txs,
+        decided_last_commit: LastCommitInfo {
+            round: 0,
+            votes: vec![],
+        },
+        misbehavior: vec![],
+        hash: Default::default(),
+        height: 2,
+        time: Timestamp::UNIX_EPOCH,
+        next_validators_hash: Default::default(),
+        proposer_address: Default::default(),
+    });
+    assert_eq!(res.tx_results.len(), 1);
+    assert_eq!(res.tx_results[0].code, 0, "{}", res.tx_results[0].log);
+
+    let commit = node.app().commit();
+    assert_eq!(commit.data, res.app_hash);
+}
+
+/// Gaia handler whose validators vote with a vote extension to propose the next block in
+/// reverse mempool order
+#[derive(Clone)]
+struct ReversingHandler {
+    inner: GaiaABCIHandler,
+}
+
+impl ReversingHandler {
+    fn new() -> Self {
+        let config: Config<AppConfig> = Config::default();
+        Self {
+            inner: GaiaABCIHandler::new(config),
+        }
+    }
+}
+
+impl ABCIHandler for ReversingHandler {
+    type Message = Message;
+    type Genesis = GenesisState;
+    type StoreKey = <GaiaABCIHandler as ABCIHandler>::StoreKey;
+    type QReq = <GaiaABCIHandler as ABCIHandler>::QReq;
+    type QRes = <GaiaABCIHandler as ABCIHandler>::QRes;
+
+    fn typed_query<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        query: Self::QReq,
+    ) -> Self::QRes {
+        self.inner.typed_query(ctx, query)
+    }
+
+    fn run_ante_checks<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, Self::StoreKey>,
+        tx: &TxWithRaw<Self::Message>,
+        mode: RunTxMode,
+    ) -> Result<(), TxError> {
+        self.inner.run_ante_checks(ctx, tx, mode)
+    }
+
+    fn msg<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, Self::StoreKey>,
+        msg: &Self::Message,
+    ) -> Result<Any, TxError> {
+        self.inner.msg(ctx, msg)
+    }
+
+    fn begin_block<DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, Self::StoreKey>,
+        request: RequestBeginBlock,
+    ) {
+        self.inner.begin_block(ctx, request)
+    }
+
+    fn end_block<DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, Self::StoreKey>,
+        request: RequestEndBlock,
+    ) -> Vec<ValidatorUpdate> {
+        self.inner.end_block(ctx, request)
+    }
+
+    fn prepare_proposal<DB: Database, CTX: InfallibleContext<DB, Self::StoreKey>>(
+        &self,
+        _ctx: &CTX,
+        request: &RequestPrepareProposal,
+        mut txs: Vec<Bytes>,
+    ) -> Vec<Bytes> {
+        let votes = &request.local_last_commit.votes;
+        if !votes.is_empty() && votes.iter().all(|vote| vote.vote_extension == REVERSE) {
+            txs.reverse();
+        }
+
+        txs
+    }
+
+    fn extend_vote<DB: Database, CTX: InfallibleContext<DB, Self::StoreKey>>(
+        &self,
+        _ctx: &CTX,
+        _request: RequestExtendVote,
+    ) -> Bytes {
+        Bytes::from_static(REVERSE)
+    }
+
+    fn verify_vote_extension<DB: Database, CTX: InfallibleContext<DB, Self::StoreKey>>(
+        &self,
+        _ctx: &CTX,
+        request: RequestVerifyVoteExtension,
+    ) -> bool {
+        request.vote_extension.is_empty() || request.vote_extension == REVERSE
+    }
+
+    fn init_genesis<DB: Database>(
+        &self,
+        ctx: &mut InitContext<'_, DB, Self::StoreKey>,
+        genesis: Self::Genesis,
+    ) -> Vec<ValidatorUpdate> {
+        self.inner.init_genesis(ctx, genesis)
+    }
+
+    fn query<DB: Database + Send + Sync>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        query: RequestQuery,
+    ) -> Result<Vec<u8>, gears::baseapp::errors::QueryError> {
+        self.inner.query(ctx, query)
+    }
+}
+
+#[test]
+/// The vote extensions of the last block reach the handler of the next proposer, which
+/// reorders the mempool transactions of its proposal with them
+fn prepare_proposal_reorders_txs_with_vote_extensions() {
+    let (mut node, user) = setup_mock_node_with_handler(ReversingHandler::new(), None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let vote_extension = node
+        .app()
+        .extend_vote(RequestExtendVote {
+            hash: Default::default(),
+            height: 1,
+            time: Timestamp::UNIX_EPOCH,
+            txs: vec![],
+            proposed_last_commit: LastCommitInfo {
+                round: 0,
+                votes: vec![],
+            },
+            misbehavior: vec![],
+            next_validators_hash: Default::default(),
+            proposer_address: Default::default(),
+        })
+        .vote_extension;
+    assert_eq!(vote_extension, REVERSE);
+
+    let verify = |vote_extension: &'static [u8]| {
+        node.app()
+            .verify_vote_extension(RequestVerifyVoteExtension {
+                hash: Default::default(),
+                validator_address: Default::default(),
+                height: 1,
+                vote_extension: Bytes::from_static(vote_extension),
+            })
+            .status
+    };
+    assert_eq!(verify(REVERSE), VerifyStatus::Accept);
+    assert_eq!(verify(b"forward"), VerifyStatus::Reject);
+
+    let first = generate_tx(
+        vec1::vec1![send_msg(user.address())],
+        0,
+        &user,
+        node.chain_id().clone(),
+    );
+    let second = generate_tx(
+        vec1::vec1![send_msg(user.address())],
+        1,
+        &user,
+        node.chain_id().clone(),
+    );
+    let mempool = vec![first.clone(), second.clone()];
+
+    let proposal = node
+        .app()
+        .prepare_proposal(prepare_request(mempool.clone(), vec![]));
+    assert_eq!(proposal.txs, mempool);
+
+    let vote = ExtendedVoteInfo {
+        validator: Validator {
+            address: ValAddress::try_from(vec![1; 20]).expect("20 bytes are a valid address"),
+            power: VotingPower::new(10).expect("hard coded power is less than the max power"),
+        },
+        signed_last_block: true,
+        vote_extension: vote_extension.clone(),
+        extension_signature: Default::default(),
+    };
+    let proposal = node
+        .app()
+        .prepare_proposal(prepare_request(mempool, vec![vote]));
+    assert_eq!(proposal.txs, vec![second, first]);
+}
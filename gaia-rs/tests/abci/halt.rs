@@ -0,0 +1,59 @@
+use gaia_rs::abci_handler::GaiaABCIHandler;
+use gaia_rs::config::AppConfig;
+use gaia_rs::store_keys::GaiaParamsStoreKey;
+use gaia_rs::GaiaApplication;
+use gears::baseapp::options::NodeOptions;
+use gears::baseapp::snapshot::{SnapshotManager, SnapshotOptions};
+use gears::baseapp::BaseApp;
+use gears::config::Config;
+use gears::store::database::MemDB;
+use gears::tendermint::application::ABCIApplication;
+use gears::tendermint::types::time::timestamp::Timestamp;
+
+use crate::setup_mock_node_with_app;
+
+#[test]
+/// Committing the block at the halt height returns the commit response instead of exiting
+/// the process. Shutting down afterwards waits for the snapshot of that block.
+fn commit_at_halt_height_raises_halt_signal() {
+    let dir = std::env::temp_dir().join(format!("gaia-halt-{}", std::process::id()));
+
+    let config: Config<AppConfig> = Config::default();
+    let app: BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication> = BaseApp::new(
+        MemDB::new(),
+        GaiaParamsStoreKey::BaseApp,
+        GaiaABCIHandler::new(config),
+        NodeOptions::default(),
+    )
+    .with_snapshot_manager(SnapshotManager::new(
+        &dir,
+        SnapshotOptions {
+            interval: 2,
+            keep_recent: 0,
+        },
+    ))
+    .with_halt_height(2);
+    let halt = app.halt_signal();
+
+    let (mut node, _) = setup_mock_node_with_app(app.clone(), None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+    assert!(!halt.is_raised());
+
+    let res = node.step(vec![], Timestamp::UNIX_EPOCH);
+    assert_eq!(res.height, 2);
+    assert!(halt.is_raised());
+
+    app.shutdown();
+
+    let snapshots = app.list_snapshots().snapshots;
+    assert_eq!(
+        snapshots
+            .iter()
+            .map(|snapshot| snapshot.height)
+            .collect::<Vec<_>>(),
+        vec![2]
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
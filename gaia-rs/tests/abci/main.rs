@@ -1,27 +1,57 @@
+use bank::types::query::{QueryBalanceRequest, QueryBalanceResponse};
 use gaia_rs::abci_handler::GaiaABCIHandler;
 use gaia_rs::config::AppConfig;
 use gaia_rs::genesis::GenesisState;
 use gaia_rs::store_keys::GaiaParamsStoreKey;
 use gaia_rs::GaiaApplication;
+use gears::application::handlers::node::ABCIHandler;
 use gears::baseapp::genesis::Genesis;
 use gears::baseapp::options::NodeOptions;
 use gears::baseapp::BaseApp;
 use gears::config::Config;
+use gears::core::Protobuf;
 use gears::crypto::keys::ReadAccAddress;
+use gears::extensions::testing::UnwrapTesting;
 use gears::store::database::MemDB;
 use gears::tendermint::types::chain_id::ChainId;
 use gears::tendermint::types::consensus::ConsensusParams;
 use gears::tendermint::types::proto::validator::{ValidatorUpdate, VotingPower};
+use gears::tendermint::types::request::query::RequestQuery;
 use gears::tendermint::types::time::timestamp::Timestamp;
+use gears::types::address::AccAddress;
 use gears::utils::node::{InitState, MockNode, User};
 use keyring::key::pair::KeyPair;
 use std::fs;
 use std::path::Path;
 
+#[cfg(test)]
+mod ante_chain;
+#[cfg(test)]
+mod ante_events;
+#[cfg(test)]
+mod bypass_fee;
+#[cfg(test)]
+mod consensus_params;
+#[cfg(test)]
+mod finalize_block;
+#[cfg(test)]
+mod halt;
+#[cfg(test)]
+mod panic;
+#[cfg(test)]
+mod recheck;
 mod scenario_1;
 mod scenario_2;
 mod scenario_3;
 #[cfg(test)]
+mod simulate;
+#[cfg(test)]
+mod snapshot;
+#[cfg(test)]
+mod store_query;
+#[cfg(test)]
+mod tip;
+#[cfg(test)]
 mod two_tx;
 
 // cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux
@@ -46,16 +76,36 @@ fn setup_mock_node(
 ) -> (
     MockNode<BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication>, GenesisState>,
     User,
+) {
+    let config: Config<AppConfig> = Config::default();
+    setup_mock_node_with_handler(GaiaABCIHandler::new(config), genesis_path)
+}
+
+/// Same as [`setup_mock_node`] but runs the chain with a custom handler, e.g. one wrapping
+/// the gaia handler to change its behaviour
+fn setup_mock_node_with_handler<H: ABCIHandler<Genesis = GenesisState>>(
+    abci_handler: H,
+    genesis_path: Option<impl AsRef<Path>>,
+) -> (
+    MockNode<BaseApp<MemDB, GaiaParamsStoreKey, H, GaiaApplication>, GenesisState>,
+    User,
 ) {
     let db = MemDB::new();
     let node_options = NodeOptions::default();
-    let config: Config<AppConfig> = Config::default();
-    let app: BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication> = BaseApp::new(
-        db,
-        GaiaParamsStoreKey::BaseApp,
-        GaiaABCIHandler::new(config),
-        node_options,
-    );
+    let app: BaseApp<MemDB, GaiaParamsStoreKey, H, GaiaApplication> =
+        BaseApp::new(db, GaiaParamsStoreKey::BaseApp, abci_handler, node_options);
+    setup_mock_node_with_app(app, genesis_path)
+}
+
+/// Same as [`setup_mock_node`] but runs the chain with a custom base app, e.g. one with
+/// peer filters
+fn setup_mock_node_with_app<H: ABCIHandler<Genesis = GenesisState>>(
+    app: BaseApp<MemDB, GaiaParamsStoreKey, H, GaiaApplication>,
+    genesis_path: Option<impl AsRef<Path>>,
+) -> (
+    MockNode<BaseApp<MemDB, GaiaParamsStoreKey, H, GaiaApplication>, GenesisState>,
+    User,
+) {
     let chain_id = ChainId::default();
 
     let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
@@ -102,3 +152,26 @@ fn setup_mock_node(
         },
     )
 }
+
+/// uatom balance of `address`, e.g. `34uatom`
+fn balance<H: ABCIHandler<Genesis = GenesisState>>(
+    node: &MockNode<BaseApp<MemDB, GaiaParamsStoreKey, H, GaiaApplication>, GenesisState>,
+    address: AccAddress,
+) -> String {
+    let query = QueryBalanceRequest {
+        address,
+        denom: "uatom".try_into().unwrap_test(),
+    };
+    let res = node.query(RequestQuery {
+        data: query.encode_vec().into(),
+        path: "/cosmos.bank.v1beta1.Query/Balance".to_string(),
+        height: 0,
+        prove: false,
+    });
+
+    QueryBalanceResponse::decode(res.value)
+        .unwrap_test()
+        .balance
+        .expect("account has a balance")
+        .to_string()
+}
@@ -0,0 +1,195 @@
+use gaia_rs::abci_handler::GaiaABCIHandler;
+use gaia_rs::config::AppConfig;
+use gaia_rs::genesis::GenesisState;
+use gaia_rs::message::Message;
+use gears::application::handlers::node::{ABCIHandler, TxError};
+use gears::baseapp::mode::RunTxMode;
+use gears::config::Config;
+use gears::context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext};
+use gears::core::any::google::Any;
+use gears::store::database::Database;
+use gears::tendermint::types::proto::validator::ValidatorUpdate;
+use gears::tendermint::types::request::check_tx::{CheckTxType, RequestCheckTx};
+use gears::tendermint::types::request::{
+    begin_block::RequestBeginBlock, end_block::RequestEndBlock, query::RequestQuery,
+};
+use gears::types::base::coins::Coins;
+use gears::types::msg::send::MsgSend;
+use gears::types::tx::raw::TxWithRaw;
+use gears::utils::node::generate_tx;
+use gears::{tendermint::types::time::timestamp::Timestamp, types::address::AccAddress};
+
+use crate::setup_mock_node_with_handler;
+
+// `PANIC_CODE` of the base app
+const PANIC_CODE: u32 = u16::MAX as u32 + 6;
+// gas limit set by `generate_tx`
+const GAS_LIMIT: i64 = 200_000;
+
+#[derive(Debug, Clone, Copy)]
+enum PanicIn {
+    AnteHandler,
+    Message,
+}
+
+/// Gaia handler which panics after running the ante handler or a message, like a module with a bug
+#[derive(Clone)]
+struct PanickingHandler {
+    inner: GaiaABCIHandler,
+    panic_in: PanicIn,
+}
+
+impl PanickingHandler {
+    fn new(panic_in: PanicIn) -> Self {
+        let config: Config<AppConfig> = Config::default();
+        Self {
+            inner: GaiaABCIHandler::new(config),
+            panic_in,
+        }
+    }
+}
+
+impl ABCIHandler for PanickingHandler {
+    type Message = Message;
+    type Genesis = GenesisState;
+    type StoreKey = <GaiaABCIHandler as ABCIHandler>::StoreKey;
+    type QReq = <GaiaABCIHandler as ABCIHandler>::QReq;
+    type QRes = <GaiaABCIHandler as ABCIHandler>::QRes;
+
+    fn typed_query<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        query: Self::QReq,
+    ) -> Self::QRes {
+        self.inner.typed_query(ctx, query)
+    }
+
+    fn run_ante_checks<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, Self::StoreKey>,
+        tx: &TxWithRaw<Self::Message>,
+        mode: RunTxMode,
+    ) -> Result<(), TxError> {
+        self.inner.run_ante_checks(ctx, tx, mode)?;
+        match self.panic_in {
+            PanicIn::AnteHandler => panic!("ante handler bug"),
+            PanicIn::Message => Ok(()),
+        }
+    }
+
+    fn msg<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, Self::StoreKey>,
+        msg: &Self::Message,
+    ) -> Result<Any, TxError> {
+        self.inner.msg(ctx, msg)?;
+        panic!("message handler bug")
+    }
+
+    fn begin_block<DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, Self::StoreKey>,
+        request: RequestBeginBlock,
+    ) {
+        self.inner.begin_block(ctx, request)
+    }
+
+    fn end_block<DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, Self::StoreKey>,
+        request: RequestEndBlock,
+    ) -> Vec<ValidatorUpdate> {
+        self.inner.end_block(ctx, request)
+    }
+
+    fn init_genesis<DB: Database>(
+        &self,
+        ctx: &mut InitContext<'_, DB, Self::StoreKey>,
+        genesis: Self::Genesis,
+    ) -> Vec<ValidatorUpdate> {
+        self.inner.init_genesis(ctx, genesis)
+    }
+
+    fn query<DB: Database + Send + Sync>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        query: RequestQuery,
+    ) -> Result<Vec<u8>, gears::baseapp::errors::QueryError> {
+        self.inner.query(ctx, query)
+    }
+}
+
+fn send_msg(from_address: AccAddress) -> Message {
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+    let amount = Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+        .expect("hard coded coins are valid");
+
+    Message::Bank(bank::Message::Send(MsgSend {
+        from_address,
+        to_address,
+        amount,
+    }))
+}
+
+#[test]
+/// A panic while running a message fails the transaction with the gas it used instead of
+/// crashing the node
+fn deliver_tx_recovers_from_message_panic() {
+    let (mut node, user) =
+        setup_mock_node_with_handler(PanickingHandler::new(PanicIn::Message), None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let tx = generate_tx(
+        vec1::vec1![send_msg(user.address())],
+        0,
+        &user,
+        node.chain_id().clone(),
+    );
+
+    let res = node.step(vec![tx], Timestamp::UNIX_EPOCH);
+    let tx_res = &res.tx_responses[0];
+
+    assert_eq!(tx_res.code, PANIC_CODE);
+    assert_eq!(tx_res.codespace, "base");
+    assert_eq!(
+        tx_res.log,
+        "panic while running transaction: message handler bug"
+    );
+    assert_eq!(tx_res.gas_wanted, GAS_LIMIT);
+    assert!(tx_res.gas_used > 0 && tx_res.gas_used <= GAS_LIMIT);
+    assert!(tx_res.events.is_empty());
+
+    // the node keeps producing blocks
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+}
+
+#[test]
+/// A panic in the ante handler during CheckTx rejects the transaction with the gas it used
+fn check_tx_recovers_from_ante_handler_panic() {
+    let (mut node, user) =
+        setup_mock_node_with_handler(PanickingHandler::new(PanicIn::AnteHandler), None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let tx = generate_tx(
+        vec1::vec1![send_msg(user.address())],
+        0,
+        &user,
+        node.chain_id().clone(),
+    );
+
+    let res = node.check_tx(RequestCheckTx {
+        tx,
+        r#type: CheckTxType::New.into(),
+    });
+
+    assert_eq!(res.code, PANIC_CODE);
+    assert_eq!(res.codespace, "base");
+    assert_eq!(res.log, "panic while running transaction: ante handler bug");
+    assert_eq!(res.gas_wanted, GAS_LIMIT);
+    // the signature verification of the ante handler consumes gas before the panic
+    assert!(res.gas_used > 0 && res.gas_used <= GAS_LIMIT);
+}
@@ -0,0 +1,49 @@
+use gears::tendermint::types::request::check_tx::{CheckTxType, RequestCheckTx};
+use gears::types::base::coins::Coins;
+use gears::types::msg::send::MsgSend;
+use gears::utils::node::generate_tx;
+use gears::{tendermint::types::time::timestamp::Timestamp, types::address::AccAddress};
+
+use crate::setup_mock_node;
+
+#[test]
+/// In this scenario, a transaction which was accepted into the mempool is included in a block.
+/// Rechecking it afterwards must fail because its sequence was already used.
+fn recheck_rejects_committed_tx() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+    let amount = Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+        .expect("hard coded coins are valid");
+
+    let msg = gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address,
+        amount,
+    }));
+
+    let tx = generate_tx(vec1::vec1![msg], 0, &user, node.chain_id().clone());
+
+    let res = node.check_tx(RequestCheckTx {
+        tx: tx.clone(),
+        r#type: CheckTxType::New.into(),
+    });
+    assert_eq!(res.code, 0, "{}", res.log);
+
+    node.step(vec![tx.clone()], Timestamp::UNIX_EPOCH);
+
+    let res = node.check_tx(RequestCheckTx {
+        tx: tx.clone(),
+        r#type: CheckTxType::Recheck.into(),
+    });
+    assert_ne!(res.code, 0);
+    assert!(res.log.contains("incorrect tx sequence"), "{}", res.log);
+
+    let res = node.check_tx(RequestCheckTx { tx, r#type: 2 });
+    assert_ne!(res.code, 0);
+    assert_eq!(res.log, "unknown CheckTx type: 2");
+}
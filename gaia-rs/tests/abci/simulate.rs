@@ -0,0 +1,54 @@
+use gears::tendermint::types::request::query::RequestQuery;
+use gears::types::base::coins::Coins;
+use gears::types::msg::send::MsgSend;
+use gears::utils::node::generate_tx;
+use gears::{tendermint::types::time::timestamp::Timestamp, types::address::AccAddress};
+
+use crate::setup_mock_node;
+
+#[test]
+/// In this scenario, we simulate a balance transfer on block three and check that it doesn't change the state
+/// by delivering the same transaction afterwards
+fn simulate_tx_leaves_state_unchanged() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+    let amount = Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+        .expect("hard coded coins are valid");
+
+    let msg = gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address,
+        amount,
+    }));
+
+    let tx = generate_tx(vec1::vec1![msg], 0, &user, node.chain_id().clone());
+
+    let res = node.query(RequestQuery {
+        data: tx.clone(),
+        path: "/app/simulate".to_owned(),
+        height: 0,
+        prove: false,
+    });
+    assert_eq!(res.code, 0, "{}", res.log);
+    assert!(!res.value.is_empty());
+    // the response of the send message is returned in the tx result
+    let response_url = b"/cosmos.bank.v1beta1.MsgSendResponse";
+    assert!(res
+        .value
+        .windows(response_url.len())
+        .any(|window| window == response_url));
+
+    // the sequence wasn't incremented by the simulation, so the tx is still valid
+    let app_hash = node.step(vec![tx], Timestamp::UNIX_EPOCH).app_hash;
+    assert_eq!(
+        hex::encode(app_hash),
+        "c87125d99593ca696ce60c2195ef67125fb434714c36c4b4d1fe1d1eb4f1580e"
+    );
+}
@@ -0,0 +1,131 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use gaia_rs::abci_handler::GaiaABCIHandler;
+use gaia_rs::config::AppConfig;
+use gaia_rs::store_keys::GaiaParamsStoreKey;
+use gaia_rs::GaiaApplication;
+use gears::baseapp::options::NodeOptions;
+use gears::baseapp::snapshot::{SnapshotManager, SnapshotOptions};
+use gears::baseapp::BaseApp;
+use gears::config::Config;
+use gears::store::database::MemDB;
+use gears::tendermint::application::ABCIApplication;
+use gears::tendermint::types::proto::info::Snapshot;
+use gears::tendermint::types::request::info::RequestInfo;
+use gears::tendermint::types::request::snapshot::{
+    RequestApplySnapshotChunk, RequestLoadSnapshotChunk, RequestOfferSnapshot,
+};
+use gears::tendermint::types::response::snapshot::{ApplySnapshotChunkResult, OfferResult};
+use gears::tendermint::types::time::timestamp::Timestamp;
+
+use crate::setup_mock_node_with_app;
+
+type GaiaApp = BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication>;
+
+/// App which takes a snapshot after every block
+fn snapshotting_app(snapshot_dir: &Path) -> GaiaApp {
+    let config: Config<AppConfig> = Config::default();
+    BaseApp::new(
+        MemDB::new(),
+        GaiaParamsStoreKey::BaseApp,
+        GaiaABCIHandler::new(config),
+        NodeOptions::default(),
+    )
+    .with_snapshot_manager(SnapshotManager::new(
+        snapshot_dir,
+        SnapshotOptions {
+            interval: 1,
+            keep_recent: 0,
+        },
+    ))
+}
+
+/// Snapshots are taken in the background, wait until the first one is saved
+fn wait_for_snapshot(app: &GaiaApp) -> Snapshot {
+    let start = Instant::now();
+    loop {
+        if let Some(snapshot) = app.list_snapshots().snapshots.pop() {
+            return snapshot;
+        }
+
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "no snapshot was taken"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Offer the snapshot with `app_hash` as the trusted app hash and apply all of its chunks,
+/// returning the result of the last chunk
+fn restore(
+    app: &GaiaApp,
+    snapshot: &Snapshot,
+    chunks: &[prost::bytes::Bytes],
+    app_hash: prost::bytes::Bytes,
+) -> ApplySnapshotChunkResult {
+    let res = app.offer_snapshot(RequestOfferSnapshot {
+        snapshot: Some(snapshot.clone()),
+        app_hash,
+    });
+    assert_eq!(res.result, OfferResult::Accept as i32);
+
+    let mut result = ApplySnapshotChunkResult::Unknown;
+    for (index, chunk) in chunks.iter().enumerate() {
+        let res = app.apply_snapshot_chunk(RequestApplySnapshotChunk {
+            index: index.try_into().expect("snapshots have few chunks"),
+            chunk: chunk.clone(),
+            sender: "peer".to_owned(),
+        });
+        result = res.result.try_into().expect("valid result");
+    }
+
+    result
+}
+
+#[test]
+/// A snapshot which doesn't match the trusted app hash is rejected without changing the
+/// state of the app, so that it can restore another snapshot
+fn restore_snapshot_after_rejected_snapshot() {
+    let dir = std::env::temp_dir().join(format!("gaia-snapshot-{}", std::process::id()));
+    let source_dir = dir.join("source");
+    let target_dir = dir.join("target");
+
+    let source = snapshotting_app(&source_dir);
+    let (mut node, _) = setup_mock_node_with_app(source.clone(), None::<&str>);
+    let app_hash = node.step(vec![], Timestamp::UNIX_EPOCH).app_hash;
+
+    let snapshot = wait_for_snapshot(&source);
+    assert_eq!(snapshot.height, 1);
+    let chunks = (0..snapshot.chunks)
+        .map(|chunk| {
+            source
+                .load_snapshot_chunk(RequestLoadSnapshotChunk {
+                    height: snapshot.height,
+                    format: snapshot.format,
+                    chunk,
+                })
+                .chunk
+        })
+        .collect::<Vec<_>>();
+
+    let target = snapshotting_app(&target_dir);
+
+    let wrong_app_hash = vec![0; app_hash.len()].into();
+    assert_eq!(
+        restore(&target, &snapshot, &chunks, wrong_app_hash),
+        ApplySnapshotChunkResult::RejectSnapshot
+    );
+    assert_eq!(target.info(RequestInfo::default()).last_block_height, 0);
+
+    assert_eq!(
+        restore(&target, &snapshot, &chunks, app_hash.clone()),
+        ApplySnapshotChunkResult::Accept
+    );
+    let info = target.info(RequestInfo::default());
+    assert_eq!(info.last_block_height, 1);
+    assert_eq!(info.last_block_app_hash, app_hash);
+
+    std::fs::remove_dir_all(dir).expect("temp dir is writable");
+}
@@ -0,0 +1,259 @@
+use gaia_rs::abci_handler::GaiaABCIHandler;
+use gaia_rs::config::AppConfig;
+use gaia_rs::store_keys::GaiaParamsStoreKey;
+use gaia_rs::GaiaApplication;
+use gears::application::ApplicationInfo;
+use gears::baseapp::options::NodeOptions;
+use gears::baseapp::{BaseApp, PeerFilters};
+use gears::config::Config;
+use gears::store::database::MemDB;
+use gears::tendermint::types::proto::crypto::{ProofOp, ProofOps};
+use gears::tendermint::types::request::query::RequestQuery;
+use gears::tendermint::types::time::timestamp::Timestamp;
+use ics23::commitment_proof::Proof;
+use ics23::{CommitmentProof, HostFunctionsManager};
+use prost::Message;
+
+use crate::{setup_mock_node, setup_mock_node_with_app};
+
+fn decode_proof(op: &ProofOp) -> CommitmentProof {
+    CommitmentProof::decode(op.data.as_slice()).expect("proof op holds a commitment proof")
+}
+
+/// Root hash of the tree the proof was built from
+fn proof_root(proof: &CommitmentProof) -> Vec<u8> {
+    let existence = match &proof.proof {
+        Some(Proof::Exist(proof)) => proof,
+        Some(Proof::Nonexist(proof)) => proof
+            .left
+            .as_ref()
+            .or(proof.right.as_ref())
+            .expect("store isn't empty"),
+        proof => panic!("unexpected proof {proof:?}"),
+    };
+
+    ics23::calculate_existence_root::<HostFunctionsManager>(existence)
+        .expect("existence proof is valid")
+}
+
+/// Verify the proof of `key` in the IAVL tree of `store` and the proof of that tree
+/// in the app hash, checking that the key is set to `value` or absent if `value` is `None`
+fn verify_proof_ops(
+    proof_ops: &ProofOps,
+    store: &str,
+    key: &[u8],
+    value: Option<&[u8]>,
+    app_hash: &[u8],
+) {
+    let [store_op, commit_op] = proof_ops.ops.as_slice() else {
+        panic!("expected a store and a commit proof op, got {proof_ops:?}");
+    };
+    assert_eq!(store_op.r#type, "ics23:iavl");
+    assert_eq!(store_op.key, key);
+    assert_eq!(commit_op.r#type, "ics23:simple");
+    assert_eq!(commit_op.key, store.as_bytes());
+
+    let store_proof = decode_proof(store_op);
+    let store_root = proof_root(&store_proof);
+    let verified = match value {
+        Some(value) => ics23::verify_membership::<HostFunctionsManager>(
+            &store_proof,
+            &ics23::iavl_spec(),
+            &store_root,
+            key,
+            value,
+        ),
+        None => ics23::verify_non_membership::<HostFunctionsManager>(
+            &store_proof,
+            &ics23::iavl_spec(),
+            &store_root,
+            key,
+        ),
+    };
+    assert!(verified, "invalid store proof");
+
+    let commit_proof = decode_proof(commit_op);
+    assert!(
+        ics23::verify_membership::<HostFunctionsManager>(
+            &commit_proof,
+            &ics23::tendermint_spec(),
+            &app_hash.to_vec(),
+            store.as_bytes(),
+            &store_root,
+        ),
+        "invalid commit proof"
+    );
+}
+
+#[test]
+/// Raw store queries return the stored value and, when requested, the proofs
+/// of the key in the store and of the store in the app hash.
+fn store_query_returns_proof_ops() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+
+    let app_hash = node.step(vec![], Timestamp::UNIX_EPOCH).app_hash;
+
+    let address = user.address();
+    let balance_key = [&[2, address.len()][..], address.as_ref(), b"uatom"].concat();
+
+    let res = node.query(RequestQuery {
+        data: balance_key.clone().into(),
+        path: "/store/bank/key".to_owned(),
+        height: 0,
+        prove: true,
+    });
+    assert_eq!(res.code, 0, "{}", res.log);
+    assert!(!res.value.is_empty());
+
+    let proof_ops = res.proof_ops.expect("proof was requested");
+    verify_proof_ops(
+        &proof_ops,
+        "bank",
+        &balance_key,
+        Some(res.value.as_ref()),
+        &app_hash,
+    );
+
+    // the proof doesn't verify any other value for the key
+    let store_proof = decode_proof(&proof_ops.ops[0]);
+    assert!(!ics23::verify_membership::<HostFunctionsManager>(
+        &store_proof,
+        &ics23::iavl_spec(),
+        &proof_root(&store_proof),
+        &balance_key,
+        b"1uatom",
+    ));
+
+    let res = node.query(RequestQuery {
+        data: balance_key.into(),
+        path: "/store/bank/key".to_owned(),
+        height: 0,
+        prove: false,
+    });
+    assert_eq!(res.code, 0, "{}", res.log);
+    assert_eq!(res.proof_ops, None);
+
+    let missing_key = [&[2, address.len()][..], address.as_ref(), b"unknown"].concat();
+    let res = node.query(RequestQuery {
+        data: missing_key.clone().into(),
+        path: "/store/bank/key".to_owned(),
+        height: 0,
+        prove: true,
+    });
+    assert_eq!(res.code, 0, "{}", res.log);
+    assert!(res.value.is_empty());
+    verify_proof_ops(
+        &res.proof_ops.expect("proof was requested"),
+        "bank",
+        &missing_key,
+        None,
+        &app_hash,
+    );
+
+    let res = node.query(RequestQuery {
+        data: vec![1].into(),
+        path: "/store/unknown/key".to_owned(),
+        height: 0,
+        prove: true,
+    });
+    assert_ne!(res.code, 0);
+}
+
+#[test]
+/// Subspace, version and peer filter queries are answered by the base app.
+fn base_app_query_routes() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let address = user.address();
+    let balances_prefix = [&[2, address.len()][..], address.as_ref()].concat();
+
+    let res = node.query(RequestQuery {
+        data: balances_prefix.clone().into(),
+        path: "/store/bank/subspace".to_owned(),
+        height: 0,
+        prove: false,
+    });
+    assert_eq!(res.code, 0, "{}", res.log);
+    let balance_key = [balances_prefix, b"uatom".to_vec()].concat();
+    assert!(res
+        .value
+        .windows(balance_key.len())
+        .any(|window| window == balance_key));
+
+    let res = node.query(RequestQuery {
+        data: Default::default(),
+        path: "/app/version".to_owned(),
+        height: 0,
+        prove: false,
+    });
+    assert_eq!(res.code, 0, "{}", res.log);
+    assert_eq!(res.value.as_ref(), GaiaApplication::APP_VERSION.as_bytes());
+
+    let res = node.query(RequestQuery {
+        data: Default::default(),
+        path: "/p2p/filter/id/abcd".to_owned(),
+        height: 0,
+        prove: false,
+    });
+    assert_eq!(res.code, 0, "{}", res.log);
+
+    let res = node.query(RequestQuery {
+        data: Default::default(),
+        path: "/app/unknown".to_owned(),
+        height: 0,
+        prove: false,
+    });
+    assert_ne!(res.code, 0);
+}
+
+fn reject_private_addresses(address: &str) -> Result<(), String> {
+    if address.starts_with("10.") {
+        Err(format!("{address} is a private address"))
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+/// Peer filter queries are answered with the filters the app was built with
+fn peer_filter_query_rejects_filtered_peers() {
+    let config: Config<AppConfig> = Config::default();
+    let app: BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication> = BaseApp::new(
+        MemDB::new(),
+        GaiaParamsStoreKey::BaseApp,
+        GaiaABCIHandler::new(config),
+        NodeOptions::default(),
+    )
+    .with_peer_filters(PeerFilters {
+        addr: Some(reject_private_addresses),
+        id: None,
+    });
+    let (mut node, _) = setup_mock_node_with_app(app, None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let query = |path: &str| {
+        node.query(RequestQuery {
+            data: Default::default(),
+            path: path.to_owned(),
+            height: 0,
+            prove: false,
+        })
+    };
+
+    let res = query("/p2p/filter/addr/10.0.0.1:26656");
+    assert_ne!(res.code, 0);
+    assert_eq!(
+        res.log,
+        "peer rejected: 10.0.0.1:26656 is a private address"
+    );
+
+    let res = query("/p2p/filter/addr/1.2.3.4:26656");
+    assert_eq!(res.code, 0, "{}", res.log);
+
+    // no id filter was set
+    let res = query("/p2p/filter/id/abcd");
+    assert_eq!(res.code, 0, "{}", res.log);
+}
@@ -0,0 +1,283 @@
+use std::path::Path;
+
+use gaia_rs::message::Message;
+use gears::commands::client::tx::{combine_aux_signatures, sign_aux_body, AuxSignerData};
+use gears::core::Protobuf;
+use gears::extensions::testing::UnwrapTesting;
+use gears::tendermint::types::chain_id::ChainId;
+use gears::tendermint::types::request::check_tx::{CheckTxType, RequestCheckTx};
+use gears::tendermint::types::time::timestamp::Timestamp;
+use gears::types::address::AccAddress;
+use gears::types::auth::fee::Fee;
+use gears::types::base::coins::{Coins, UnsignedCoins};
+use gears::types::msg::send::MsgSend;
+use gears::types::tx::body::TxBody;
+use gears::utils::node::User;
+
+use crate::{balance, setup_mock_node, USER_0, USER_1};
+
+const GENESIS_PATH: &str = "./tests/abci/assets/scenario_2_genesis.json";
+
+fn coins(coins: &str) -> UnsignedCoins {
+    Coins::new(vec![coins.parse().expect("hard coded coin is valid")])
+        .expect("hard coded coins are valid")
+}
+
+fn send_body(from_address: AccAddress, amount: &str) -> TxBody<Message> {
+    TxBody::new_with_defaults(vec1::vec1![Message::Bank(bank::Message::Send(MsgSend {
+        from_address,
+        to_address: "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+            .parse()
+            .expect("hard coded address is valid"),
+        amount: coins(amount),
+    }))])
+}
+
+fn fee() -> Fee {
+    Fee {
+        amount: Some(coins("1uatom")),
+        gas_limit: 200_000_u64
+            .try_into()
+            .expect("hard coded gas limit is valid"),
+        payer: None,
+        granter: "".into(),
+    }
+}
+
+/// Signs `body` in direct aux mode with a tip and hands the signature to the fee payer
+/// through its json encoding, like the `--aux` and `--aux-signature` flags of the client
+fn aux_signature(
+    tipper: &User,
+    body: &TxBody<Message>,
+    chain_id: &ChainId,
+    tip: Option<&str>,
+) -> AuxSignerData {
+    let aux_signer_data = sign_aux_body(
+        &tipper.key_pair,
+        body,
+        chain_id,
+        tipper.account_number,
+        0,
+        tip.map(coins),
+    )
+    .expect("signing with a key pair doesn't fail");
+
+    serde_json::from_str(&serde_json::to_string(&aux_signer_data).unwrap_test()).unwrap_test()
+}
+
+#[test]
+/// The tipper signs a transfer in direct aux mode, the fee payer pays the fee and receives
+/// the tip once the transfer succeeded
+fn tip_is_paid_to_fee_payer() {
+    let (mut node, _) = setup_mock_node(Some(Path::new(GENESIS_PATH)));
+    let fee_payer = crate::user(5, USER_0);
+    let tipper = crate::user(6, USER_1);
+    let chain_id = node.chain_id().clone();
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let body = send_body(tipper.address(), "10uatom");
+    let aux_signer_data = aux_signature(&tipper, &body, &chain_id, Some("100uatom"));
+    let tx = combine_aux_signatures(
+        &fee_payer.key_pair,
+        body,
+        &[aux_signer_data],
+        fee(),
+        &chain_id,
+        fee_payer.account_number,
+        0,
+    )
+    .unwrap_test();
+
+    let res = node.step(vec![tx.encode_vec().into()], Timestamp::UNIX_EPOCH);
+    let tx_res = &res.tx_responses[0];
+    assert_eq!(tx_res.code, 0, "{}", tx_res.log);
+
+    let tip_attribute = |key: &str| {
+        tx_res
+            .events
+            .iter()
+            .filter(|event| event.r#type == "tip")
+            .flat_map(|event| event.attributes.iter())
+            .filter(|attribute| attribute.key == key.as_bytes())
+            .map(|attribute| String::from_utf8_lossy(&attribute.value).into_owned())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(tip_attribute("tip"), vec!["100uatom".to_owned()]);
+    assert_eq!(tip_attribute("tipper"), vec![tipper.address().to_string()]);
+
+    // 34uatom - 1uatom fee + 100uatom tip
+    assert_eq!(balance(&node, fee_payer.address()), "133uatom");
+    // 500000uatom - 10uatom transfer - 100uatom tip
+    assert_eq!(balance(&node, tipper.address()), "499890uatom");
+}
+
+#[test]
+/// The tip is paid after the messages, so it isn't paid when a message fails
+fn tip_is_not_paid_when_messages_fail() {
+    let (mut node, _) = setup_mock_node(Some(Path::new(GENESIS_PATH)));
+    let fee_payer = crate::user(5, USER_0);
+    let tipper = crate::user(6, USER_1);
+    let chain_id = node.chain_id().clone();
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    // the tipper can't afford the transfer
+    let body = send_body(tipper.address(), "1000000uatom");
+    let aux_signer_data = aux_signature(&tipper, &body, &chain_id, Some("100uatom"));
+    let tx = combine_aux_signatures(
+        &fee_payer.key_pair,
+        body,
+        &[aux_signer_data],
+        fee(),
+        &chain_id,
+        fee_payer.account_number,
+        0,
+    )
+    .unwrap_test();
+
+    let res = node.step(vec![tx.encode_vec().into()], Timestamp::UNIX_EPOCH);
+    let tx_res = &res.tx_responses[0];
+    assert_ne!(tx_res.code, 0);
+    assert!(tx_res.events.iter().all(|event| event.r#type != "tip"));
+
+    // only the fee, which is deducted by the ante handler, was paid
+    assert_eq!(balance(&node, fee_payer.address()), "33uatom");
+    assert_eq!(balance(&node, tipper.address()), "500000uatom");
+}
+
+#[test]
+/// Post handlers run in CheckTx, so a tx whose tipper can't afford the tip doesn't enter the
+/// mempool even though its messages aren't executed
+fn check_tx_rejects_unaffordable_tip() {
+    let (mut node, _) = setup_mock_node(Some(Path::new(GENESIS_PATH)));
+    let fee_payer = crate::user(5, USER_0);
+    let tipper = crate::user(6, USER_1);
+    let chain_id = node.chain_id().clone();
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let body = send_body(tipper.address(), "10uatom");
+    let aux_signer_data = aux_signature(&tipper, &body, &chain_id, Some("1000000uatom"));
+    let tx = combine_aux_signatures(
+        &fee_payer.key_pair,
+        body,
+        &[aux_signer_data],
+        fee(),
+        &chain_id,
+        fee_payer.account_number,
+        0,
+    )
+    .unwrap_test();
+
+    let res = node.check_tx(RequestCheckTx {
+        tx: tx.encode_vec().into(),
+        r#type: CheckTxType::New.into(),
+    });
+    assert_ne!(res.code, 0);
+    assert!(res.log.starts_with("failed to send coins"), "{}", res.log);
+}
+
+#[test]
+/// The fee isn't part of the direct aux sign doc, so the fee payer must sign in another mode
+fn fee_payer_cant_sign_in_direct_aux_mode() {
+    let (mut node, _) = setup_mock_node(Some(Path::new(GENESIS_PATH)));
+    let fee_payer = crate::user(5, USER_0);
+    let tipper = crate::user(6, USER_1);
+    let chain_id = node.chain_id().clone();
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let body = send_body(tipper.address(), "10uatom");
+    let aux_signers = [
+        aux_signature(&tipper, &body, &chain_id, Some("100uatom")),
+        aux_signature(&fee_payer, &body, &chain_id, None),
+    ];
+    let tx = combine_aux_signatures(
+        &fee_payer.key_pair,
+        body,
+        &aux_signers,
+        fee(),
+        &chain_id,
+        fee_payer.account_number,
+        0,
+    )
+    .unwrap_test();
+
+    let res = node.check_tx(RequestCheckTx {
+        tx: tx.encode_vec().into(),
+        r#type: CheckTxType::New.into(),
+    });
+    assert_ne!(res.code, 0);
+    assert_eq!(
+        res.log,
+        format!(
+            "fee payer {} can't sign in direct aux mode",
+            fee_payer.address()
+        )
+    );
+}
+
+#[test]
+/// The direct aux signature covers the tip, so the fee payer can't change it
+fn direct_aux_signature_covers_tip() {
+    let (mut node, _) = setup_mock_node(Some(Path::new(GENESIS_PATH)));
+    let fee_payer = crate::user(5, USER_0);
+    let tipper = crate::user(6, USER_1);
+    let chain_id = node.chain_id().clone();
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let body = send_body(tipper.address(), "10uatom");
+    let mut aux_signer_data = aux_signature(&tipper, &body, &chain_id, Some("100uatom"));
+    if let Some(tip) = aux_signer_data.tip.as_mut() {
+        tip.amount = Some(coins("1000uatom"));
+    }
+    let tx = combine_aux_signatures(
+        &fee_payer.key_pair,
+        body,
+        &[aux_signer_data],
+        fee(),
+        &chain_id,
+        fee_payer.account_number,
+        0,
+    )
+    .unwrap_test();
+
+    let res = node.check_tx(RequestCheckTx {
+        tx: tx.encode_vec().into(),
+        r#type: CheckTxType::New.into(),
+    });
+    assert_ne!(res.code, 0);
+    assert!(res.log.contains("invalid signature"), "{}", res.log);
+}
+
+#[test]
+/// Aux signatures are only combined into a tx with the messages which were signed
+fn combine_aux_signatures_rejects_other_body() {
+    let fee_payer = crate::user(5, USER_0);
+    let tipper = crate::user(6, USER_1);
+    let chain_id = ChainId::default();
+
+    let aux_signer_data = aux_signature(
+        &tipper,
+        &send_body(tipper.address(), "10uatom"),
+        &chain_id,
+        Some("100uatom"),
+    );
+
+    let err = combine_aux_signatures(
+        &fee_payer.key_pair,
+        send_body(tipper.address(), "20uatom"),
+        &[aux_signer_data],
+        fee(),
+        &chain_id,
+        fee_payer.account_number,
+        0,
+    )
+    .expect_err("signed body differs");
+    assert_eq!(
+        err.to_string(),
+        format!("tx doesn't match the tx signed by {}", tipper.address())
+    );
+}
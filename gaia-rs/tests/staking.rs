@@ -182,6 +182,7 @@ fn redelegate_failed_on_invalid_amount() -> anyhow::Result<()> {
         home: gaia.home(),
         keyring_backend: gears::commands::client::keys::KeyringBackend::Test,
         bip39_mnemonic: Some(mnemonic.to_owned()),
+        multisig: None,
     }))?;
 
     // create destination validator
@@ -530,6 +531,7 @@ mod helpers {
             home: node.home(),
             keyring_backend: gears::commands::client::keys::KeyringBackend::Test,
             bip39_mnemonic: Some(mnemonic.to_owned()),
+            multisig: None,
         }))?;
 
         // create destination validator
@@ -118,6 +118,9 @@ impl GaiaNode {
                 read_buf_size: 1048576,
                 log_level: LOG_LEVEL,
                 min_gas_prices: Default::default(),
+                halt_height: None,
+                halt_time: None,
+                abci_version: None,
                 tendermint_rpc_addr: Some(rpc_addr_moved.try_into().expect("invalid rpc addr")),
             };
 
@@ -1,15 +1,26 @@
 use std::{borrow::Cow, num::NonZero};
 
 use crate::{
-    baseapp::{errors::QueryError, genesis::Genesis, QueryRequest, QueryResponse},
-    context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
+    baseapp::{errors::QueryError, genesis::Genesis, mode::RunTxMode, QueryRequest, QueryResponse},
+    context::{
+        block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext,
+        InfallibleContext,
+    },
     types::tx::{raw::TxWithRaw, TxMessage},
 };
+use bytes::Bytes;
+use core_types::any::google::Any;
 use database::Database;
 use kv_store::StoreKey;
 use tendermint::types::{
     proto::validator::ValidatorUpdate,
-    request::{begin_block::RequestBeginBlock, end_block::RequestEndBlock, query::RequestQuery},
+    request::{
+        begin_block::RequestBeginBlock,
+        end_block::RequestEndBlock,
+        proposal::{RequestPrepareProposal, RequestProcessProposal},
+        query::RequestQuery,
+        vote_extension::{RequestExtendVote, RequestVerifyVoteExtension},
+    },
 };
 use thiserror::Error;
 
@@ -54,20 +65,37 @@ pub trait ABCIHandler: Clone + Send + Sync + 'static {
         &self,
         ctx: &mut TxContext<'_, DB, Self::StoreKey>,
         tx: &TxWithRaw<Self::Message>,
-        is_check: bool,
+        mode: RunTxMode,
     ) -> Result<(), TxError> {
         Ok(())
     }
 
-    // TODO: this should return a Result similar to the SDK. See:
-    // 1. https://github.com/cosmos/cosmos-sdk/blob/2582f0aab7b2cbf66ade066fe570a4622cf0b098/baseapp/baseapp.go#L717
-    // 2. https://github.com/cosmos/cosmos-sdk/blob/2582f0aab7b2cbf66ade066fe570a4622cf0b098/types/abci.pb.go#L323-L333
-    // 3. https://github.com/cosmos/cosmos-sdk/blob/2582f0aab7b2cbf66ade066fe570a4622cf0b098/types/result.go#L233-L258
+    /// Runs after all messages of a transaction succeeded, e.g. to pay tips. Its changes are
+    /// discarded together with the changes of the messages when it fails. In check mode no
+    /// messages run, so it runs right after the ante checks.
+    #[allow(unused_variables)]
+    fn run_post_handlers<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, Self::StoreKey>,
+        tx: &TxWithRaw<Self::Message>,
+        mode: RunTxMode,
+    ) -> Result<(), TxError> {
+        Ok(())
+    }
+
+    /// Priority of a transaction in tendermint's mempool, higher priority transactions
+    /// are included in blocks first. Defaults to the fee paid per unit of gas.
+    fn tx_priority(&self, tx: &TxWithRaw<Self::Message>) -> i64 {
+        tx.tx.auth_info.fee.priority()
+    }
+
+    /// Execute a message and return its response, which is included in the `TxMsgData`
+    /// of the transaction. Messages without data can return [`TxMessage::empty_response`].
     fn msg<DB: Database>(
         &self,
         ctx: &mut TxContext<'_, DB, Self::StoreKey>,
         msg: &Self::Message,
-    ) -> Result<(), TxError>;
+    ) -> Result<Any, TxError>;
 
     #[allow(unused_variables)]
     fn begin_block<'a, DB: Database>(
@@ -86,6 +114,50 @@ pub trait ABCIHandler: Clone + Send + Sync + 'static {
         Vec::new()
     }
 
+    /// Called on the proposer of a block to reorder, drop or add transactions. `txs` are the
+    /// mempool transactions of the request which fit into the block, the result must fit
+    /// too. The vote extensions of the last block are in `request.local_last_commit`.
+    #[allow(unused_variables)]
+    fn prepare_proposal<DB: Database, CTX: InfallibleContext<DB, Self::StoreKey>>(
+        &self,
+        ctx: &CTX,
+        request: &RequestPrepareProposal,
+        txs: Vec<Bytes>,
+    ) -> Vec<Bytes> {
+        txs
+    }
+
+    /// Called on validators to accept or reject a block proposed by another validator
+    #[allow(unused_variables)]
+    fn process_proposal<DB: Database, CTX: InfallibleContext<DB, Self::StoreKey>>(
+        &self,
+        ctx: &CTX,
+        request: &RequestProcessProposal,
+    ) -> bool {
+        true
+    }
+
+    /// Application data attached to the precommit vote of this validator, passed to the
+    /// proposer of the next block
+    #[allow(unused_variables)]
+    fn extend_vote<DB: Database, CTX: InfallibleContext<DB, Self::StoreKey>>(
+        &self,
+        ctx: &CTX,
+        request: RequestExtendVote,
+    ) -> Bytes {
+        Bytes::new()
+    }
+
+    /// Check the vote extension of another validator
+    #[allow(unused_variables)]
+    fn verify_vote_extension<DB: Database, CTX: InfallibleContext<DB, Self::StoreKey>>(
+        &self,
+        ctx: &CTX,
+        request: RequestVerifyVoteExtension,
+    ) -> bool {
+        true
+    }
+
     fn init_genesis<DB: Database>(
         &self,
         ctx: &mut InitContext<'_, DB, Self::StoreKey>,
@@ -13,6 +13,7 @@ use crate::commands::node::{
     AppCommands,
 };
 use crate::{
+    baseapp::PeerFilters,
     config::{ApplicationConfig, Config},
     params::ParamsSubspaceKey,
 };
@@ -38,6 +39,7 @@ pub struct NodeApplication<
 
     params_subspace_key: Core::ParamsSubspaceKey,
     db_builder: DBO,
+    peer_filters: PeerFilters,
     _marker: PhantomData<DB>,
 }
 
@@ -59,10 +61,17 @@ impl<
             abci_handler_builder,
             params_subspace_key,
             db_builder,
+            peer_filters: PeerFilters::default(),
             _marker: PhantomData,
         }
     }
 
+    /// Filter the peers tendermint connects to, all peers are accepted by default
+    pub fn with_peer_filters(mut self, peer_filters: PeerFilters) -> Self {
+        self.peer_filters = peer_filters;
+        self
+    }
+
     /// Runs the command passed on the command line.
     pub fn execute<AI: ApplicationInfo>(
         self,
@@ -79,6 +88,7 @@ impl<
                 self.params_subspace_key,
                 self.abci_handler_builder,
                 self.core,
+                self.peer_filters,
             )?,
             AppCommands::GenesisAdd(cmd) => {
                 genesis_account_add::<<<Core as Node>::Handler as ABCIHandler>::Genesis>(cmd)?
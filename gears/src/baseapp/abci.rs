@@ -1,5 +1,11 @@
 use super::{
-    mode::{check::CheckTxMode, deliver::DeliverTxMode},
+    errors::RunTxError,
+    mode::{
+        check::{CheckTxMode, ReCheckTxMode},
+        deliver::DeliverTxMode,
+    },
+    snapshot::{self, ChunkApplied, RestoredSnapshot},
+    state::ApplicationState,
     BaseApp,
 };
 use crate::error::POISONED_LOCK;
@@ -14,12 +20,14 @@ use crate::{baseapp::RunTxInfo, context::block::BlockContext};
 use bytes::Bytes;
 use database::Database;
 use extensions::lock::AcquireRwLock;
+use kv_store::bank::multi::application::MultiBankImporter;
 use tendermint::{
     application::ABCIApplication,
     types::{
+        proto::header::Header,
         request::{
             begin_block::RequestBeginBlock,
-            check_tx::RequestCheckTx,
+            check_tx::{CheckTxType, RequestCheckTx},
             deliver_tx::RequestDeliverTx,
             echo::RequestEcho,
             end_block::RequestEndBlock,
@@ -38,8 +46,8 @@ use tendermint::{
             init_chain::ResponseInitChain,
             query::ResponseQuery,
             snapshot::{
-                ResponseApplySnapshotChunk, ResponseListSnapshots, ResponseLoadSnapshotChunk,
-                ResponseOfferSnapshot,
+                ApplySnapshotChunkResult, OfferResult, ResponseApplySnapshotChunk,
+                ResponseListSnapshots, ResponseLoadSnapshotChunk, ResponseOfferSnapshot,
             },
             ResponseCommit, ResponseFlush,
         },
@@ -58,17 +66,33 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             consensus_params,
             validators: _, // TODO: should it be ignored?
             app_genesis,
-            ..
+            initial_height,
         }: RequestInitChain<H::Genesis>,
     ) -> ResponseInitChain {
         let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
         let mut state = self.state.write().expect(POISONED_LOCK);
 
-        //TODO: handle request height > 1 as is done in SDK
         // On a new chain, we consider the init chain block height as 0, even though
-        // req.InitialHeight is 1 by default.
-        // see https://github.com/cosmos/cosmos-sdk/blob/2582f0aab7b2cbf66ade066fe570a4622cf0b098/baseapp/abci.go#L28-L29
-        let initial_height = 0;
+        // req.InitialHeight is 1 by default. Chains starting at a later height commit
+        // their first block as version `initial_height`.
+        // see https://github.com/cosmos/cosmos-sdk/blob/2582f0aab7b2cbf66ade066fe570a4622cf0b098/baseapp/abci.go#L28-L45
+        let initial_height = if initial_height > 1 {
+            if let Err(err) = multi_store.set_initial_version(initial_height) {
+                panic!("failed to set initial version {initial_height}: {err}")
+            }
+            state.last_height = multi_store.head_version();
+
+            initial_height
+        } else {
+            0
+        };
+
+        // FinalizeBlock requests don't include the chain id, keep it for the first block
+        self.set_block_header(Header {
+            chain_id: chain_id.clone(),
+            time,
+            ..Default::default()
+        });
 
         let mut ctx = InitContext::new(
             &mut multi_store,
@@ -92,7 +116,8 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         ResponseInitChain {
             consensus_params: Some(consensus_params),
             validators: val_updates,
-            app_hash: "hash_goes_here".into(), //TODO: set app hash - note this will be the hash of block 1
+            // genesis state is committed with the first block, report its hash as if committed now
+            app_hash: multi_store.working_hash().to_vec().into(),
         }
     }
 
@@ -110,14 +135,14 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
 
     fn query(&self, request: RequestQuery) -> ResponseQuery {
         match self.run_query(&request) {
-            Ok(res) => ResponseQuery {
+            Ok((res, proof_ops)) => ResponseQuery {
                 code: 0,
                 log: "exists".to_string(),
                 info: "".to_string(),
                 index: 0,
                 key: request.data,
                 value: res,
-                proof_ops: None,
+                proof_ops,
                 height: request.height as u32,
                 codespace: "".to_string(),
             },
@@ -143,21 +168,32 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             multi_store,
         } = &mut state.check_mode;
 
-        let result = match r#type {
-            0 | 1 => self.run_tx::<CheckTxMode<_, _>>(tx.clone(), multi_store, block_gas_meter),
-            _ => panic!("unknown Request CheckTx type: {}", r#type),
+        let result = match CheckTxType::try_from(r#type) {
+            Ok(CheckTxType::New) => {
+                self.run_tx::<CheckTxMode<_, _>>(tx.clone(), multi_store, block_gas_meter)
+            }
+            Ok(CheckTxType::Recheck) => {
+                self.run_tx::<ReCheckTxMode<_, _>>(tx.clone(), multi_store, block_gas_meter)
+            }
+            Err(_) => Err(RunTxError::UnknownCheckTxType(r#type)),
         };
 
         match result {
-            Ok(RunTxInfo {
-                events,
-                gas_wanted,
-                gas_used,
-            }) => {
+            Ok(tx_info) => {
+                let data = tx_info.data();
+                let RunTxInfo {
+                    events,
+                    gas_wanted,
+                    gas_used,
+                    priority,
+                    sender,
+                    ..
+                } = tx_info;
+
                 debug!("{:?}", events);
                 ResponseCheckTx {
                     code: 0,
-                    data: Default::default(),
+                    data,
                     log: "".to_string(),
                     info: "".to_string(),
                     gas_wanted: gas_wanted.into(),
@@ -165,19 +201,23 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
                     events,
                     codespace: "".to_string(),
                     mempool_error: "".to_string(),
-                    priority: 0,
-                    sender: "".to_string(),
+                    priority,
+                    sender,
                 }
             }
             Err(e) => {
                 error!("check err: {e}");
+                let (gas_wanted, gas_used) = e
+                    .gas_info()
+                    .map(|(wanted, used)| (wanted.into(), used.into()))
+                    .unwrap_or((1, 0));
                 ResponseCheckTx {
                     code: e.code(),
                     data: Default::default(),
                     log: e.to_string(),
                     info: "".to_string(),
-                    gas_wanted: 1,
-                    gas_used: 0,
+                    gas_wanted,
+                    gas_used,
                     events: vec![],
                     codespace: e.codespace().to_string(),
                     mempool_error: "".to_string(),
@@ -199,29 +239,29 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         let result = self.run_tx::<DeliverTxMode<_, _>>(tx.clone(), multi_store, block_gas_meter);
 
         match result {
-            Ok(RunTxInfo {
-                events,
-                gas_wanted,
-                gas_used,
-            }) => ResponseDeliverTx {
+            Ok(tx_info) => ResponseDeliverTx {
                 code: 0,
-                data: Default::default(),
+                data: tx_info.data(),
                 log: "".to_string(),
                 info: "".to_string(),
-                gas_wanted: gas_wanted.into(),
-                gas_used: gas_used.into(),
-                events: events.into_iter().collect(),
+                gas_wanted: tx_info.gas_wanted.into(),
+                gas_used: tx_info.gas_used.into(),
+                events: tx_info.events,
                 codespace: "".to_string(),
             },
             Err(e) => {
                 info!("Failed to process tx: {}", e);
+                let (gas_wanted, gas_used) = e
+                    .gas_info()
+                    .map(|(wanted, used)| (wanted.into(), used.into()))
+                    .unwrap_or_default();
                 ResponseDeliverTx {
                     code: e.code(),
                     data: Bytes::new(),
                     log: e.to_string(),
                     info: "".to_string(),
-                    gas_wanted: 0,
-                    gas_used: 0,
+                    gas_wanted,
+                    gas_used,
                     events: vec![],
                     codespace: e.codespace().to_string(),
                 }
@@ -233,7 +273,9 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
         let mut state = self.state.write().expect(POISONED_LOCK);
 
-        let height = self.get_block_header().height;
+        let header = self.get_block_header();
+        let height = header.height;
+        let header_chain_id = header.chain_id;
 
         let hash = state.commit(&mut multi_store);
 
@@ -243,9 +285,47 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             hex::encode(hash)
         );
 
+        if let Some(snapshot_manager) = &self.snapshot_manager {
+            snapshot_manager.maybe_snapshot(&multi_store, state.last_height);
+        }
+
+        let evidence_params = self
+            .baseapp_params_keeper
+            .consensus_params(&SimpleContext::new(
+                SimpleBackend::Application(&mut multi_store),
+                height,
+                header_chain_id,
+            ))
+            .evidence;
+
+        if should_halt(
+            height,
+            i64::from(header.time.timestamp_seconds()),
+            self.halt_height,
+            self.halt_time,
+        ) {
+            info!(
+                "Halting node at height {height} per configuration, halt height: {} halt time: {}",
+                self.halt_height, self.halt_time
+            );
+
+            // Exiting here would kill the process while the locks are held, before
+            // tendermint receives the response and possibly while a snapshot is written.
+            // The node is stopped once the commit returned, see `BaseApp::shutdown`.
+            self.halt.raise();
+        }
+
         ResponseCommit {
             data: hash.to_vec().into(),
-            retain_height: 0, // this is the height above which tendermint will retain all blocks // TODO: make this configurable as in Cosmos
+            retain_height: retain_height(
+                height,
+                self.min_retain_blocks,
+                evidence_params.max_age_num_blocks,
+                self.snapshot_manager
+                    .as_ref()
+                    .map(|manager| manager.options().retention_blocks())
+                    .unwrap_or_default(),
+            ),
         }
     }
 
@@ -278,6 +358,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         let consensus_params = self.baseapp_params_keeper.consensus_params(&ctx);
 
         state.replace_meter(Gas::from(max_gas));
+        state.block_consensus_params = consensus_params.clone();
 
         let mut ctx = BlockContext::new(
             &mut multi_store,
@@ -324,6 +405,17 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
 
         let validator_updates = self.abci_handler.end_block(&mut ctx, request);
 
+        // params may have been changed by transactions or by modules (e.g. an
+        // executed governance proposal) at any point during the block
+        let consensus_params = self.baseapp_params_keeper.consensus_params(&ctx);
+        let consensus_param_updates = match consensus_params != state.block_consensus_params {
+            true => {
+                info!("Consensus params updated at height {}", header.height);
+                Some(consensus_params.into())
+            }
+            false => None,
+        };
+
         let events = ctx.events;
 
         state.append_block_cache(&mut multi_store);
@@ -331,10 +423,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         ResponseEndBlock {
             events: events.into_iter().collect(),
             validator_updates,
-            consensus_param_updates: None,
-            // TODO: there is only one call to BaseAppParamsKeeper::set_consensus_params,
-            // which is made during init. This means that these params cannot change.
-            // However a get method should be implemented in future.
+            consensus_param_updates,
         }
     }
 
@@ -345,24 +434,240 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
 
     /// Used during state sync to discover available snapshots on peers.
     fn list_snapshots(&self) -> ResponseListSnapshots {
-        Default::default()
+        ResponseListSnapshots {
+            snapshots: self
+                .snapshot_manager
+                .as_ref()
+                .map(|manager| manager.list())
+                .unwrap_or_default(),
+        }
     }
 
     /// Called when bootstrapping the node using state sync.
-    fn offer_snapshot(&self, _request: RequestOfferSnapshot) -> ResponseOfferSnapshot {
-        Default::default()
+    fn offer_snapshot(
+        &self,
+        RequestOfferSnapshot { snapshot, app_hash }: RequestOfferSnapshot,
+    ) -> ResponseOfferSnapshot {
+        let result = match &self.snapshot_manager {
+            Some(_) if self.multi_store.acquire_read().head_version() != 0 => {
+                error!("Snapshot offered to a node which already has state");
+                OfferResult::Abort
+            }
+            Some(manager) => match manager.offer(snapshot, app_hash) {
+                OfferResult::Accept => match self.multi_store.acquire_read().importer() {
+                    Ok(importer) => {
+                        *self.snapshot_import.lock().expect(POISONED_LOCK) = Some(importer);
+                        OfferResult::Accept
+                    }
+                    Err(err) => {
+                        error!("Failed to start restoring snapshot: {err}");
+                        OfferResult::Abort
+                    }
+                },
+                result => result,
+            },
+            None => OfferResult::Abort,
+        };
+
+        ResponseOfferSnapshot {
+            result: result.into(),
+        }
     }
 
     /// Used during state sync to retrieve chunks of snapshots from peers.
-    fn load_snapshot_chunk(&self, _request: RequestLoadSnapshotChunk) -> ResponseLoadSnapshotChunk {
-        Default::default()
+    fn load_snapshot_chunk(
+        &self,
+        RequestLoadSnapshotChunk {
+            height,
+            format,
+            chunk,
+        }: RequestLoadSnapshotChunk,
+    ) -> ResponseLoadSnapshotChunk {
+        ResponseLoadSnapshotChunk {
+            chunk: self
+                .snapshot_manager
+                .as_ref()
+                .and_then(|manager| manager.load_chunk(height, format, chunk))
+                .unwrap_or_default()
+                .into(),
+        }
     }
 
     /// Apply the given snapshot chunk to the application's state.
     fn apply_snapshot_chunk(
         &self,
-        _request: RequestApplySnapshotChunk,
+        request: RequestApplySnapshotChunk,
     ) -> ResponseApplySnapshotChunk {
-        Default::default()
+        let Some(manager) = &self.snapshot_manager else {
+            return ResponseApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Abort.into(),
+                refetch_chunks: vec![],
+                reject_senders: vec![],
+            };
+        };
+
+        let mut import = self.snapshot_import.lock().expect(POISONED_LOCK);
+
+        let applied = match import.as_mut() {
+            Some(importer) => {
+                manager.apply_chunk(request, |item| snapshot::import_item(importer, item))
+            }
+            None => {
+                error!("Received snapshot chunk without an accepted snapshot offer");
+                ChunkApplied::Abort
+            }
+        };
+
+        let (result, refetch_chunks, reject_senders) = match applied {
+            ChunkApplied::Pending => (ApplySnapshotChunkResult::Accept, vec![], vec![]),
+            ChunkApplied::Retry { index, sender } => {
+                (ApplySnapshotChunkResult::Retry, vec![index], vec![sender])
+            }
+            ChunkApplied::Abort => {
+                *import = None;
+                (ApplySnapshotChunkResult::Abort, vec![], vec![])
+            }
+            ChunkApplied::Reject => {
+                *import = None;
+                (ApplySnapshotChunkResult::RejectSnapshot, vec![], vec![])
+            }
+            ChunkApplied::Complete(snapshot) => {
+                let importer = import
+                    .take()
+                    .expect("checked above that an import is in progress");
+                (self.restore_snapshot(snapshot, importer), vec![], vec![])
+            }
+        };
+
+        ResponseApplySnapshotChunk {
+            result: result.into(),
+            refetch_chunks,
+            reject_senders,
+        }
+    }
+}
+
+impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
+    BaseApp<DB, PSK, H, AI>
+{
+    fn restore_snapshot(
+        &self,
+        RestoredSnapshot { height, app_hash }: RestoredSnapshot,
+        importer: MultiBankImporter<DB, H::StoreKey>,
+    ) -> ApplySnapshotChunkResult {
+        let imported = match importer.finish() {
+            Ok(imported) => imported,
+            Err(err) => {
+                error!("Failed to restore snapshot at height {height}: {err}");
+                return ApplySnapshotChunkResult::RejectSnapshot;
+            }
+        };
+
+        if imported.hash().as_slice() != app_hash.as_ref() {
+            // nothing was written to the multistore yet, so another snapshot can be restored
+            error!(
+                "Restored app hash {} doesn't match the trusted app hash {}",
+                hex::encode(imported.hash()),
+                hex::encode(app_hash)
+            );
+            return ApplySnapshotChunkResult::RejectSnapshot;
+        }
+
+        let mut multi_store = self.multi_store.acquire_write();
+        let mut state = self.state.acquire_write();
+
+        let hash = match multi_store.finish_import(height, imported) {
+            Ok(hash) => hash,
+            Err(err) => {
+                error!("Failed to restore snapshot at height {height}: {err}");
+                return ApplySnapshotChunkResult::Abort;
+            }
+        };
+
+        let max_gas = self
+            .baseapp_params_keeper
+            .block_params(&SimpleContext::new(
+                SimpleBackend::Application(&mut multi_store),
+                height,
+                Default::default(),
+            ))
+            .map(|e| e.max_gas)
+            .unwrap_or_default();
+
+        *state = ApplicationState::new(Gas::from(max_gas), &multi_store);
+
+        info!(
+            "Restored snapshot at height {height} app hash: {}",
+            hex::encode(hash)
+        );
+
+        ApplySnapshotChunkResult::Accept
+    }
+}
+
+/// Height below which tendermint may prune blocks after committing `commit_height`.
+///
+/// Blocks are kept for at least `min_retain_blocks`, the evidence max age (so
+/// misbehaviour can still be punished and light clients can operate safely) and
+/// long enough to cover all kept state sync snapshots. 0 means keep all blocks.
+fn retain_height(
+    commit_height: u32,
+    min_retain_blocks: u64,
+    evidence_max_age_num_blocks: i64,
+    snapshot_retention_blocks: u64,
+) -> u32 {
+    if min_retain_blocks == 0 {
+        return 0;
+    }
+
+    let commit_height = u64::from(commit_height);
+
+    let retain_blocks = [
+        min_retain_blocks,
+        u64::try_from(evidence_max_age_num_blocks).unwrap_or_default(),
+        snapshot_retention_blocks,
+    ]
+    .into_iter()
+    .max()
+    .unwrap_or_default();
+
+    commit_height
+        .checked_sub(retain_blocks)
+        .and_then(|height| u32::try_from(height).ok())
+        .unwrap_or_default()
+}
+
+/// Whether the node must stop after committing the block at `height` with
+/// the given block time in unix seconds. A zero halt height or time is ignored.
+fn should_halt(height: u32, block_time: i64, halt_height: u32, halt_time: u64) -> bool {
+    let reached_height = halt_height > 0 && height >= halt_height;
+    let reached_time =
+        halt_time > 0 && u64::try_from(block_time).is_ok_and(|time| time >= halt_time);
+
+    reached_height || reached_time
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retain_height_respects_all_limits() {
+        assert_eq!(retain_height(1000, 0, 10, 0), 0);
+        assert_eq!(retain_height(1000, 100, 10, 0), 900);
+        assert_eq!(retain_height(1000, 100, 500, 0), 500);
+        assert_eq!(retain_height(1000, 100, 10, 300), 700);
+        assert_eq!(retain_height(1000, 2000, 10, 0), 0);
+    }
+
+    #[test]
+    fn halts_at_height_or_time() {
+        assert!(!should_halt(10, 1_000, 0, 0));
+        assert!(!should_halt(9, 1_000, 10, 0));
+        assert!(should_halt(10, 1_000, 10, 0));
+        assert!(should_halt(11, 1_000, 10, 0));
+        assert!(!should_halt(10, 999, 0, 1_000));
+        assert!(should_halt(10, 1_000, 0, 1_000));
+        assert!(should_halt(10, 1_000, 20, 1_000));
     }
 }
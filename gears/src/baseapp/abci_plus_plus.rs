@@ -0,0 +1,169 @@
+use database::Database;
+use extensions::lock::AcquireRwLock;
+use tendermint::{
+    application::{ABCIApplication, ABCIPlusPlusApplication},
+    types::{
+        proto::header::Header,
+        request::{
+            begin_block::RequestBeginBlock,
+            deliver_tx::RequestDeliverTx,
+            end_block::RequestEndBlock,
+            finalize_block::RequestFinalizeBlock,
+            proposal::{RequestPrepareProposal, RequestProcessProposal},
+            vote_extension::{RequestExtendVote, RequestVerifyVoteExtension},
+        },
+        response::{
+            begin_block::ResponseBeginBlock,
+            end_block::ResponseEndBlock,
+            finalize_block::ResponseFinalizeBlock,
+            proposal::{ResponsePrepareProposal, ResponseProcessProposal},
+            vote_extension::{ResponseExtendVote, ResponseVerifyVoteExtension},
+        },
+    },
+};
+use tracing::error;
+
+use super::BaseApp;
+use crate::{
+    application::{handlers::node::ABCIHandler, ApplicationInfo},
+    context::simple::SimpleContext,
+    params::ParamsSubspaceKey,
+};
+
+impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
+    ABCIPlusPlusApplication<H::Genesis> for BaseApp<DB, PSK, H, AI>
+{
+    fn prepare_proposal(&self, request: RequestPrepareProposal) -> ResponsePrepareProposal {
+        let txs = request.txs_within_limit();
+
+        // changes made by the handler are discarded with the fork
+        let mut multi_store = self.state.acquire_read().check_mode.multi_store.fork();
+        let ctx = SimpleContext::new(
+            (&mut multi_store).into(),
+            request.height,
+            self.get_block_header().chain_id,
+        );
+
+        let proposed = self
+            .abci_handler
+            .prepare_proposal(&ctx, &request, txs.clone());
+
+        let proposed_bytes = proposed.iter().map(|tx| tx.len() as i64).sum::<i64>();
+        if proposed_bytes > request.max_tx_bytes {
+            error!(
+                "Prepared proposal of {proposed_bytes} bytes exceeds the limit of {} bytes, proposing mempool transactions instead",
+                request.max_tx_bytes
+            );
+
+            return ResponsePrepareProposal { txs };
+        }
+
+        ResponsePrepareProposal { txs: proposed }
+    }
+
+    fn process_proposal(&self, request: RequestProcessProposal) -> ResponseProcessProposal {
+        let mut multi_store = self.state.acquire_read().check_mode.multi_store.fork();
+        let ctx = SimpleContext::new(
+            (&mut multi_store).into(),
+            request.height,
+            self.get_block_header().chain_id,
+        );
+
+        ResponseProcessProposal {
+            status: self.abci_handler.process_proposal(&ctx, &request).into(),
+        }
+    }
+
+    fn extend_vote(&self, request: RequestExtendVote) -> ResponseExtendVote {
+        let mut multi_store = self.state.acquire_read().check_mode.multi_store.fork();
+        let ctx = SimpleContext::new(
+            (&mut multi_store).into(),
+            request.height,
+            self.get_block_header().chain_id,
+        );
+
+        ResponseExtendVote {
+            vote_extension: self.abci_handler.extend_vote(&ctx, request),
+        }
+    }
+
+    fn verify_vote_extension(
+        &self,
+        request: RequestVerifyVoteExtension,
+    ) -> ResponseVerifyVoteExtension {
+        let mut multi_store = self.state.acquire_read().check_mode.multi_store.fork();
+        let ctx = SimpleContext::new(
+            (&mut multi_store).into(),
+            request.height,
+            self.get_block_header().chain_id,
+        );
+
+        ResponseVerifyVoteExtension {
+            status: self
+                .abci_handler
+                .verify_vote_extension(&ctx, request)
+                .into(),
+        }
+    }
+
+    fn finalize_block(
+        &self,
+        RequestFinalizeBlock {
+            txs,
+            decided_last_commit,
+            misbehavior,
+            hash,
+            height,
+            time,
+            next_validators_hash,
+            proposer_address,
+        }: RequestFinalizeBlock,
+    ) -> ResponseFinalizeBlock {
+        // FinalizeBlock doesn't carry the full header. The chain id is taken from the
+        // previous block, from InitChain or from the genesis file of the node.
+        let header = Header {
+            chain_id: self.get_block_header().chain_id,
+            height,
+            time,
+            next_validators_hash: next_validators_hash.to_vec(),
+            app_hash: self.state.acquire_read().head_hash.to_vec(),
+            proposer_address: proposer_address.to_vec(),
+            ..Default::default()
+        };
+
+        let ResponseBeginBlock { mut events } = self.begin_block(RequestBeginBlock {
+            hash,
+            header,
+            last_commit_info: decided_last_commit,
+            byzantine_validators: misbehavior,
+        });
+
+        let tx_results = txs
+            .into_iter()
+            .map(|tx| self.deliver_tx(RequestDeliverTx { tx }))
+            .collect();
+
+        let ResponseEndBlock {
+            validator_updates,
+            consensus_param_updates,
+            events: end_block_events,
+        } = self.end_block(RequestEndBlock {
+            height: height.into(),
+        });
+
+        events.extend(end_block_events);
+
+        ResponseFinalizeBlock {
+            events,
+            tx_results,
+            validator_updates,
+            consensus_param_updates,
+            app_hash: self
+                .multi_store
+                .acquire_read()
+                .working_hash()
+                .to_vec()
+                .into(),
+        }
+    }
+}
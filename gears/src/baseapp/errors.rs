@@ -1,10 +1,17 @@
-use crate::{application::handlers::node::TxError, types::gas::GasMeteringErrors};
+use crate::{
+    application::handlers::node::TxError,
+    types::gas::{FiniteGas, Gas, GasMeteringErrors},
+};
 
-// We start at u16::MAX + 1 to ensure that the error codes don't collide with the Application codes
-const OUT_OF_GAS_CODE: u32 = u16::MAX as u32 + 1;
+// Same code and codespace as `ErrOutOfGas` of the cosmos sdk so that clients can recognise it
+const OUT_OF_GAS_CODE: u32 = 11;
+const SDK_CODESPACE: &str = "sdk";
+// We start above u16::MAX to ensure that the error codes don't collide with the Application codes
 const INVALID_TRANSACTION_CODE: u32 = u16::MAX as u32 + 2;
 const INVALID_MESSAGE_CODE: u32 = u16::MAX as u32 + 3;
 const GAS_ERRORS_CODE: u32 = u16::MAX as u32 + 4;
+const INVALID_REQUEST_CODE: u32 = u16::MAX as u32 + 5;
+const PANIC_CODE: u32 = u16::MAX as u32 + 6;
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum RunTxError {
@@ -18,6 +25,19 @@ pub enum RunTxError {
     GasErrors(#[from] GasMeteringErrors),
     #[error(transparent)]
     Application(#[from] TxError),
+    #[error("unknown CheckTx type: {0}")]
+    UnknownCheckTxType(i32),
+    #[error("out of gas; gasWanted: {}, gasUsed: {gas_used}", i64::from(*gas_wanted))]
+    OutOfGas {
+        gas_wanted: Gas,
+        gas_used: FiniteGas,
+    },
+    #[error("panic while running transaction: {message}")]
+    Panic {
+        message: String,
+        gas_wanted: Gas,
+        gas_used: FiniteGas,
+    },
 }
 
 impl RunTxError {
@@ -28,12 +48,32 @@ impl RunTxError {
             RunTxError::InvalidMessage(_) => INVALID_MESSAGE_CODE,
             RunTxError::GasErrors(_) => GAS_ERRORS_CODE,
             RunTxError::Application(e) => e.code.get() as u32,
+            RunTxError::UnknownCheckTxType(_) => INVALID_REQUEST_CODE,
+            RunTxError::OutOfGas { .. } => OUT_OF_GAS_CODE,
+            RunTxError::Panic { .. } => PANIC_CODE,
+        }
+    }
+
+    /// Gas wanted and used by the failed transaction if they are known
+    pub fn gas_info(&self) -> Option<(Gas, FiniteGas)> {
+        match self {
+            RunTxError::OutOfGas {
+                gas_wanted,
+                gas_used,
+            }
+            | RunTxError::Panic {
+                gas_wanted,
+                gas_used,
+                ..
+            } => Some((*gas_wanted, *gas_used)),
+            _ => None,
         }
     }
 
     pub fn codespace(&self) -> &'static str {
         match self {
             RunTxError::Application(e) => e.codespace,
+            RunTxError::OutOfBlockGas | RunTxError::OutOfGas { .. } => SDK_CODESPACE,
             _ => "base",
         }
     }
@@ -43,12 +83,16 @@ impl RunTxError {
 pub enum QueryError {
     #[error("query path not found")]
     PathNotFound,
+    #[error("peer rejected: {0}")]
+    PeerRejected(String),
     #[error("Block height must be greater than or equal to zero")]
     InvalidHeight,
     #[error(transparent)]
     Store(#[from] kv_store::error::KVStoreError),
     #[error("error decoding query: {0}")]
     Proto(String),
+    #[error("simulation failed: {0}")]
+    Simulate(#[from] RunTxError),
     #[error("TODO: {0}")]
     TODO(#[from] anyhow::Error),
 }
@@ -77,3 +121,15 @@ pub enum TxValidation {
     #[error("{0}")]
     Validation(String),
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("error decoding snapshot: {0}")]
+    Decode(#[from] prost::DecodeError),
+    #[error("invalid snapshot: {0}")]
+    Invalid(String),
+    #[error("{0}")]
+    Store(String),
+}
@@ -0,0 +1,167 @@
+//! ICS23 encoding of store proofs returned by ABCI queries.
+
+use kv_store::{
+    proof::{CommitmentProof, ExistenceProof, InnerOp, LeafOp, NonExistenceProof},
+    query::MultiStoreProof,
+};
+use prost::Message;
+use tendermint::types::proto::crypto::{ProofOp, ProofOps};
+
+/// Proof op type of a proof of a key in an IAVL store
+pub const PROOF_OP_IAVL_COMMITMENT: &str = "ics23:iavl";
+/// Proof op type of a proof of a store root hash in the multistore commit hash
+pub const PROOF_OP_SIMPLE_MERKLE_COMMITMENT: &str = "ics23:simple";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+enum HashOp {
+    NoHash = 0,
+    Sha256 = 1,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+enum LengthOp {
+    NoPrefix = 0,
+    VarProto = 1,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct RawLeafOp {
+    #[prost(enumeration = "HashOp", tag = "1")]
+    hash: i32,
+    #[prost(enumeration = "HashOp", tag = "2")]
+    prehash_key: i32,
+    #[prost(enumeration = "HashOp", tag = "3")]
+    prehash_value: i32,
+    #[prost(enumeration = "LengthOp", tag = "4")]
+    length: i32,
+    #[prost(bytes = "vec", tag = "5")]
+    prefix: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct RawInnerOp {
+    #[prost(enumeration = "HashOp", tag = "1")]
+    hash: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    prefix: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    suffix: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct RawExistenceProof {
+    #[prost(bytes = "vec", tag = "1")]
+    key: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    leaf: Option<RawLeafOp>,
+    #[prost(message, repeated, tag = "4")]
+    path: Vec<RawInnerOp>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct RawNonExistenceProof {
+    #[prost(bytes = "vec", tag = "1")]
+    key: Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    left: Option<RawExistenceProof>,
+    #[prost(message, optional, tag = "3")]
+    right: Option<RawExistenceProof>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct RawCommitmentProof {
+    #[prost(oneof = "Proof", tags = "1, 2")]
+    proof: Option<Proof>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+enum Proof {
+    #[prost(message, tag = "1")]
+    Exist(RawExistenceProof),
+    #[prost(message, tag = "2")]
+    Nonexist(RawNonExistenceProof),
+}
+
+impl From<LeafOp> for RawLeafOp {
+    fn from(LeafOp { prefix }: LeafOp) -> Self {
+        // IAVL and simple merkle leaves are hashed the same way
+        Self {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix,
+        }
+    }
+}
+
+impl From<InnerOp> for RawInnerOp {
+    fn from(InnerOp { prefix, suffix }: InnerOp) -> Self {
+        Self {
+            hash: HashOp::Sha256.into(),
+            prefix,
+            suffix,
+        }
+    }
+}
+
+impl From<ExistenceProof> for RawExistenceProof {
+    fn from(
+        ExistenceProof {
+            key,
+            value,
+            leaf,
+            path,
+        }: ExistenceProof,
+    ) -> Self {
+        Self {
+            key,
+            value,
+            leaf: Some(leaf.into()),
+            path: path.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<NonExistenceProof> for RawNonExistenceProof {
+    fn from(NonExistenceProof { key, left, right }: NonExistenceProof) -> Self {
+        Self {
+            key,
+            left: left.map(Into::into),
+            right: right.map(Into::into),
+        }
+    }
+}
+
+impl From<CommitmentProof> for RawCommitmentProof {
+    fn from(proof: CommitmentProof) -> Self {
+        let proof = match proof {
+            CommitmentProof::Exist(proof) => Proof::Exist(proof.into()),
+            CommitmentProof::Nonexist(proof) => Proof::Nonexist(proof.into()),
+        };
+
+        Self { proof: Some(proof) }
+    }
+}
+
+/// Proof ops of a key in a store, verified from the key up to the app hash
+pub fn proof_ops(key: Vec<u8>, MultiStoreProof { store, commit }: MultiStoreProof) -> ProofOps {
+    ProofOps {
+        ops: vec![
+            ProofOp {
+                r#type: PROOF_OP_IAVL_COMMITMENT.to_owned(),
+                key,
+                data: RawCommitmentProof::from(store).encode_to_vec(),
+            },
+            ProofOp {
+                r#type: PROOF_OP_SIMPLE_MERKLE_COMMITMENT.to_owned(),
+                key: commit.key.clone(),
+                data: RawCommitmentProof::from(CommitmentProof::Exist(commit)).encode_to_vec(),
+            },
+        ],
+    }
+}
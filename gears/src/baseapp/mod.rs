@@ -1,8 +1,10 @@
 pub mod options;
 use std::{
+    any::Any as PanicPayload,
     fmt::Debug,
     marker::PhantomData,
-    sync::{Arc, RwLock},
+    panic::AssertUnwindSafe,
+    sync::{Arc, Condvar, Mutex, RwLock},
 };
 
 use crate::{
@@ -12,48 +14,101 @@ use crate::{
     params::ParamsSubspaceKey,
     types::{
         gas::{descriptor::BLOCK_GAS_DESCRIPTOR, kind::BlockKind, FiniteGas, Gas, GasMeter},
-        tx::raw::TxWithRaw,
+        response::simulate::SimulateResponse,
+        tx::{msg_data::TxMsgData, raw::TxWithRaw},
     },
 };
 use bytes::Bytes;
+use core_types::any::google::Any;
 use database::Database;
 use errors::QueryError;
+use extensions::lock::AcquireRwLock;
 use kv_store::{
-    bank::multi::{ApplicationMultiBank, TransactionMultiBank},
+    bank::multi::{application::MultiBankImporter, ApplicationMultiBank, TransactionMultiBank},
     query::QueryMultiStore,
+    StoreKey,
 };
-use mode::build_tx_gas_meter;
+use prost::Message;
+use strum::IntoEnumIterator;
 use tendermint::types::{
     chain_id::ChainId,
-    proto::{event::Event, header::Header},
+    proto::{crypto::ProofOps, event::Event, header::Header},
     request::query::RequestQuery,
 };
+use tracing::error;
 
 use self::{
-    errors::RunTxError, mode::ExecutionMode, options::NodeOptions, state::ApplicationState,
+    errors::RunTxError,
+    mode::{simulate::SimulateMode, ExecutionMode},
+    options::NodeOptions,
+    routes::{Pair, Pairs, QueryRoute},
+    snapshot::SnapshotManager,
+    state::ApplicationState,
 };
 
 mod abci;
+mod abci_plus_plus;
 pub mod errors;
 pub mod genesis;
+mod ics23;
 pub mod mode;
 mod params;
 mod query;
+mod routes;
+pub mod snapshot;
 pub mod state;
 pub use params::{
     BaseAppParamsKeeper, BlockParams, ConsensusParams, EvidenceParams, ValidatorParams,
 };
 
 pub use query::*;
+pub use routes::{PeerFilter, PeerFilters};
+
+/// Exit status of a node which stopped because it reached its halt height or halt time
+pub const HALT_EXIT_CODE: i32 = 2;
+
+/// Raised by commit when the node reached its halt height or halt time. Commit still returns
+/// its response, the node is stopped afterwards with [`BaseApp::shutdown`].
+#[derive(Debug, Clone, Default)]
+pub struct HaltSignal(Arc<(Mutex<bool>, Condvar)>);
+
+impl HaltSignal {
+    fn raise(&self) {
+        let (raised, condvar) = &*self.0;
+        *raised.lock().expect(POISONED_LOCK) = true;
+        condvar.notify_all();
+    }
+
+    pub fn is_raised(&self) -> bool {
+        *self.0 .0.lock().expect(POISONED_LOCK)
+    }
+
+    /// Blocks until the signal is raised
+    pub fn wait(&self) {
+        let (raised, condvar) = &*self.0;
+        let _raised = condvar
+            .wait_while(raised.lock().expect(POISONED_LOCK), |raised| !*raised)
+            .expect(POISONED_LOCK);
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct BaseApp<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo> {
+    db: DB,
     state: Arc<RwLock<ApplicationState<DB, H>>>,
     multi_store: Arc<RwLock<ApplicationMultiBank<DB, H::StoreKey>>>,
     abci_handler: H,
     block_header: Arc<RwLock<Header>>, // passed by Tendermint in call to begin_block
     baseapp_params_keeper: BaseAppParamsKeeper<PSK>,
     options: NodeOptions,
+    snapshot_manager: Option<SnapshotManager>,
+    /// Stores of the snapshot which is being restored
+    snapshot_import: Arc<Mutex<Option<MultiBankImporter<DB, H::StoreKey>>>>,
+    min_retain_blocks: u64,
+    halt_height: u32,
+    halt_time: u64,
+    halt: HaltSignal,
+    peer_filters: PeerFilters,
     _info_marker: PhantomData<AI>,
 }
 
@@ -61,7 +116,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     BaseApp<DB, PSK, H, AI>
 {
     pub fn new(db: DB, params_subspace_key: PSK, abci_handler: H, options: NodeOptions) -> Self {
-        let multi_store = ApplicationMultiBank::new(Arc::new(db));
+        let multi_store = ApplicationMultiBank::new(Arc::new(db.clone()));
         let mut multi_store = match multi_store {
             Ok(ms) => ms,
             Err(err) => panic!("Failed to init MultiStore with err: {err}"),
@@ -80,6 +135,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             .unwrap_or_default();
 
         Self {
+            db,
             abci_handler,
             block_header: Arc::new(RwLock::new(Default::default())),
             baseapp_params_keeper,
@@ -89,10 +145,89 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             ))),
             multi_store: Arc::new(RwLock::new(multi_store)),
             options,
+            snapshot_manager: None,
+            snapshot_import: Default::default(),
+            min_retain_blocks: 0,
+            halt_height: 0,
+            halt_time: 0,
+            halt: HaltSignal::default(),
+            peer_filters: PeerFilters::default(),
             _info_marker: PhantomData,
         }
     }
 
+    /// Enable taking and restoring state sync snapshots
+    pub fn with_snapshot_manager(mut self, snapshot_manager: SnapshotManager) -> Self {
+        self.snapshot_manager = Some(snapshot_manager);
+        self
+    }
+
+    /// Let tendermint prune blocks older than `min_retain_blocks`, 0 keeps all blocks
+    pub fn with_min_retain_blocks(mut self, min_retain_blocks: u64) -> Self {
+        self.min_retain_blocks = min_retain_blocks;
+        self
+    }
+
+    /// Stop the node after committing the block at `halt_height`, 0 disables halting
+    pub fn with_halt_height(mut self, halt_height: u32) -> Self {
+        self.halt_height = halt_height;
+        self
+    }
+
+    /// Stop the node after committing the first block with a time of at least
+    /// `halt_time` unix seconds, 0 disables halting
+    pub fn with_halt_time(mut self, halt_time: u64) -> Self {
+        self.halt_time = halt_time;
+        self
+    }
+
+    /// Signal which is raised once the node reached its halt height or halt time
+    pub fn halt_signal(&self) -> HaltSignal {
+        self.halt.clone()
+    }
+
+    /// Prepares the node to exit after it halted. Waits for the ABCI call in progress, e.g.
+    /// the commit which raised the halt signal, and for the snapshot which is being written,
+    /// then flushes the database. Later ABCI calls block, so the state can't change anymore.
+    pub fn shutdown(&self) {
+        let _multi_store = self.multi_store.write().expect(POISONED_LOCK);
+        let _state = self.state.write().expect(POISONED_LOCK);
+
+        if let Some(snapshot_manager) = &self.snapshot_manager {
+            snapshot_manager.wait();
+        }
+
+        self.db.flush();
+    }
+
+    /// Filter the peers tendermint connects to, all peers are accepted by default
+    pub fn with_peer_filters(mut self, peer_filters: PeerFilters) -> Self {
+        self.peer_filters = peer_filters;
+        self
+    }
+
+    /// Filter peers by address on `/p2p/filter/addr/<address>` queries, all peers are accepted by default
+    pub fn with_addr_peer_filter(mut self, filter: PeerFilter) -> Self {
+        self.peer_filters.addr = Some(filter);
+        self
+    }
+
+    /// Filter peers by node id on `/p2p/filter/id/<id>` queries, all peers are accepted by default
+    pub fn with_id_peer_filter(mut self, filter: PeerFilter) -> Self {
+        self.peer_filters.id = Some(filter);
+        self
+    }
+
+    /// Chain id for FinalizeBlock requests, which don't carry it, until the next block header
+    /// is known. Needed when an ABCI++ node restarts after InitChain.
+    pub fn with_chain_id(self, chain_id: ChainId) -> Self {
+        self.set_block_header(Header {
+            chain_id,
+            ..self.get_block_header()
+        });
+        self
+    }
+
     fn get_block_header(&self) -> Header {
         self.block_header.read().expect(POISONED_LOCK).clone()
     }
@@ -102,7 +237,24 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         *current_header = header;
     }
 
-    fn run_query(&self, request: &RequestQuery) -> Result<Bytes, QueryError> {
+    fn run_query(&self, request: &RequestQuery) -> Result<(Bytes, Option<ProofOps>), QueryError> {
+        let route = QueryRoute::parse(&request.path)?;
+
+        match route {
+            QueryRoute::Simulate => {
+                let response = SimulateResponse::from(self.simulate(request.data.clone())?);
+                return Ok((response.encode_to_vec().into(), None));
+            }
+            QueryRoute::Version => {
+                return Ok((Bytes::from_static(AI::APP_VERSION.as_bytes()), None))
+            }
+            QueryRoute::PeerAddrFilter(address) => {
+                return Self::filter_peer(self.peer_filters.addr, address)
+            }
+            QueryRoute::PeerIdFilter(id) => return Self::filter_peer(self.peer_filters.id, id),
+            QueryRoute::StoreKey(_) | QueryRoute::StoreSubspace(_) | QueryRoute::Custom => (),
+        }
+
         //TODO: request height u32
         let version: u32 = request
             .height
@@ -110,11 +262,94 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             .map_err(|_| QueryError::InvalidHeight)?;
 
         let store = self.multi_store.read().expect(POISONED_LOCK);
-        let ctx = QueryContext::new(QueryMultiStore::new(&*store, version)?, version)?;
+        let multi_store = QueryMultiStore::new(&*store, version)?;
+
+        match route {
+            QueryRoute::StoreKey(name) => Self::query_store_key(&multi_store, name, request),
+            QueryRoute::StoreSubspace(name) => {
+                Self::query_store_subspace(&multi_store, name, request)
+            }
+            _ => {
+                let ctx = QueryContext::new(multi_store, version)?;
+
+                self.abci_handler
+                    .query(&ctx, request.clone())
+                    .map(|res| (res.into(), None))
+            }
+        }
+    }
+
+    fn filter_peer(
+        filter: Option<PeerFilter>,
+        peer: &str,
+    ) -> Result<(Bytes, Option<ProofOps>), QueryError> {
+        if let Some(filter) = filter {
+            filter(peer).map_err(QueryError::PeerRejected)?;
+        }
+
+        Ok((Bytes::new(), None))
+    }
 
-        self.abci_handler
-            .query(&ctx, request.clone())
-            .map(Into::into)
+    fn store_key(name: &str) -> Result<H::StoreKey, QueryError> {
+        H::StoreKey::iter()
+            .find(|store_key| store_key.name() == name)
+            .ok_or(QueryError::PathNotFound)
+    }
+
+    /// Raw read of a single key, with a proof if requested
+    fn query_store_key(
+        multi_store: &QueryMultiStore<DB, H::StoreKey>,
+        name: &str,
+        request: &RequestQuery,
+    ) -> Result<(Bytes, Option<ProofOps>), QueryError> {
+        let store_key = Self::store_key(name)?;
+
+        let value = multi_store
+            .kv_store(&store_key)
+            .get(&request.data)
+            .unwrap_or_default();
+
+        let proof_ops = request.prove.then(|| {
+            ics23::proof_ops(
+                request.data.to_vec(),
+                multi_store.proof(&store_key, &request.data),
+            )
+        });
+
+        Ok((value.into(), proof_ops))
+    }
+
+    /// Raw read of all pairs whose key starts with the request data
+    fn query_store_subspace(
+        multi_store: &QueryMultiStore<DB, H::StoreKey>,
+        name: &str,
+        request: &RequestQuery,
+    ) -> Result<(Bytes, Option<ProofOps>), QueryError> {
+        let store_key = Self::store_key(name)?;
+
+        let pairs = multi_store
+            .kv_store(&store_key)
+            .prefix_store(request.data.clone())
+            .into_range(..)
+            .map(|(key, value)| Pair {
+                key: [request.data.as_ref(), key.as_slice()].concat(),
+                value: value.into_owned(),
+            })
+            .collect();
+
+        Ok((Pairs { pairs }.encode_to_vec().into(), None))
+    }
+
+    /// Run a transaction against a fork of the check state without persisting any changes
+    pub fn simulate(&self, raw: Bytes) -> Result<RunTxInfo, RunTxError> {
+        let multi_store = self.state.acquire_read().check_mode.multi_store.fork();
+
+        let SimulateMode {
+            mut block_gas_meter,
+            mut multi_store,
+        } = SimulateMode::<DB, H>::new(multi_store);
+
+        self.run_tx::<SimulateMode<_, _>>(raw, &mut multi_store, &mut block_gas_meter)
     }
 
     fn run_tx<MD: ExecutionMode<DB, H>>(
@@ -145,25 +380,25 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             height,
             header,
             consensus_params,
-            build_tx_gas_meter(height, Some(&tx_with_raw.tx.auth_info.fee)),
+            MD::build_tx_gas_meter(height, &tx_with_raw.tx.auth_info.fee),
             gas_meter,
             self.options.clone(),
         );
 
         MD::runnable(&mut ctx)?;
-        MD::run_ante_checks(&mut ctx, &self.abci_handler, &tx_with_raw)?;
+        recover_tx_panic(&mut ctx, |ctx| {
+            MD::run_ante_checks(ctx, &self.abci_handler, &tx_with_raw)
+        })?;
 
         ctx.multi_store_mut().upgrade_cache();
 
+        let (events, msg_responses) = recover_tx_panic(&mut ctx, |ctx| {
+            MD::run_msg(ctx, &self.abci_handler, &tx_with_raw)
+        })?;
+
         let gas_wanted = ctx.gas_meter.borrow().limit();
         let gas_used = ctx.gas_meter.borrow().consumed_or_limit();
 
-        let events = MD::run_msg(
-            &mut ctx,
-            &self.abci_handler,
-            tx_with_raw.tx.get_msgs().iter(),
-        )?;
-
         ctx.block_gas_meter
             .consume_gas(gas_used, BLOCK_GAS_DESCRIPTOR)?;
 
@@ -171,9 +406,82 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
 
         Ok(RunTxInfo {
             events,
+            msg_responses,
+            gas_wanted,
+            gas_used,
+            priority: self.abci_handler.tx_priority(&tx_with_raw),
+            sender: tx_with_raw
+                .tx
+                .get_signers()
+                .first()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Run part of a transaction, turning a panic into an error instead of crashing the node.
+/// Changes which weren't committed to the transaction cache are discarded and the gas
+/// consumed so far is still charged to the block.
+fn recover_tx_panic<DB: Database, SK: StoreKey, T>(
+    ctx: &mut TxContext<'_, DB, SK>,
+    run: impl FnOnce(&mut TxContext<'_, DB, SK>) -> Result<T, RunTxError>,
+) -> Result<T, RunTxError> {
+    let payload = match std::panic::catch_unwind(AssertUnwindSafe(|| run(&mut *ctx))) {
+        Ok(result) => return result,
+        Err(payload) => payload,
+    };
+
+    ctx.multi_store_mut().clear_cache();
+
+    let (gas_wanted, gas_used, out_of_gas) = {
+        let gas_meter = ctx.gas_meter.borrow();
+        (
+            gas_meter.limit(),
+            gas_meter.consumed_or_limit(),
+            gas_meter.is_out_of_gas(),
+        )
+    };
+    // the transaction failed anyway, so running out of block gas here doesn't change the result
+    let _ = ctx
+        .block_gas_meter
+        .consume_gas(gas_used, BLOCK_GAS_DESCRIPTOR);
+
+    if out_of_gas {
+        Err(RunTxError::OutOfGas {
             gas_wanted,
             gas_used,
         })
+    } else {
+        let message = panic_message(payload.as_ref());
+        error!("recovered from panic while running transaction: {message}");
+
+        Err(RunTxError::Panic {
+            message,
+            gas_wanted,
+            gas_used,
+        })
+    }
+}
+
+fn panic_message(payload: &(dyn PanicPayload + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+impl RunTxInfo {
+    /// Message responses encoded as `TxMsgData`, returned as the data of CheckTx and DeliverTx
+    pub fn data(&self) -> Bytes {
+        TxMsgData {
+            msg_responses: self.msg_responses.clone(),
+        }
+        .encode_to_vec()
+        .into()
     }
 }
 
@@ -187,6 +495,31 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
 #[derive(Debug, Clone)]
 pub struct RunTxInfo {
     pub events: Vec<Event>,
+    /// Response of each message of the transaction
+    pub msg_responses: Vec<Any>,
     pub gas_wanted: Gas,
     pub gas_used: FiniteGas,
+    /// Mempool priority of the transaction
+    pub priority: i64,
+    /// Address of the first signer of the transaction
+    pub sender: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panic_message_from_payload() {
+        let payload = std::panic::catch_unwind(|| panic!("static message")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "static message");
+
+        let height = 5;
+        let payload =
+            std::panic::catch_unwind(|| panic!("formatted message at {height}")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "formatted message at 5");
+
+        let payload = std::panic::catch_unwind(|| std::panic::panic_any(5_u8)).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "unknown panic");
+    }
 }
@@ -1,8 +1,11 @@
+use std::marker::PhantomData;
+
+use core_types::any::google::Any;
 use database::Database;
 use kv_store::bank::multi::TransactionMultiBank;
 use tendermint::types::proto::event::Event;
 
-use super::ExecutionMode;
+use super::{ExecutionMode, RunTxMode};
 use crate::{
     application::handlers::node::ABCIHandler,
     baseapp::errors::RunTxError,
@@ -34,13 +37,45 @@ impl<DB, AH: ABCIHandler> CheckTxMode<DB, AH> {
     }
 }
 
+/// Revalidates a transaction which stayed in the mempool after a block was committed.
+/// Runs against the state of [`CheckTxMode`], but skips checks which can't be affected
+/// by the new block such as signature verification.
+#[derive(Debug)]
+pub struct ReCheckTxMode<DB, AH>(PhantomData<(DB, AH)>);
+
 impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for CheckTxMode<DB, AH> {
-    fn run_msg<'m>(
+    fn run_msg(
+        ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+        handler: &AH,
+        tx_with_raw: &TxWithRaw<AH::Message>,
+    ) -> Result<(Vec<Event>, Vec<Any>), RunTxError> {
+        run_post_handlers(ctx, handler, tx_with_raw, RunTxMode::Check)?;
+
+        Ok((ctx.events_drain(), Vec::new()))
+    }
+
+    fn run_ante_checks(
+        ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+        handler: &AH,
+        tx_with_raw: &TxWithRaw<AH::Message>,
+    ) -> Result<(), RunTxError> {
+        run_ante_checks(ctx, handler, tx_with_raw, RunTxMode::Check)
+    }
+
+    fn runnable(_: &mut TxContext<'_, DB, AH::StoreKey>) -> Result<(), RunTxError> {
+        Ok(())
+    }
+}
+
+impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for ReCheckTxMode<DB, AH> {
+    fn run_msg(
         ctx: &mut TxContext<'_, DB, AH::StoreKey>,
-        _handler: &AH,
-        _msgs: impl Iterator<Item = &'m AH::Message>,
-    ) -> Result<Vec<Event>, RunTxError> {
-        Ok(ctx.events_drain())
+        handler: &AH,
+        tx_with_raw: &TxWithRaw<AH::Message>,
+    ) -> Result<(Vec<Event>, Vec<Any>), RunTxError> {
+        run_post_handlers(ctx, handler, tx_with_raw, RunTxMode::ReCheck)?;
+
+        Ok((ctx.events_drain(), Vec::new()))
     }
 
     fn run_ante_checks(
@@ -48,13 +83,37 @@ impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for CheckTxMode<DB, AH
         handler: &AH,
         tx_with_raw: &TxWithRaw<AH::Message>,
     ) -> Result<(), RunTxError> {
-        handler
-            .run_ante_checks(ctx, tx_with_raw, true)
-            .inspect_err(|_| ctx.multi_store_mut().clear_cache())
-            .map_err(RunTxError::from)
+        run_ante_checks(ctx, handler, tx_with_raw, RunTxMode::ReCheck)
     }
 
     fn runnable(_: &mut TxContext<'_, DB, AH::StoreKey>) -> Result<(), RunTxError> {
         Ok(())
     }
 }
+
+fn run_ante_checks<DB: Database, AH: ABCIHandler>(
+    ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+    handler: &AH,
+    tx_with_raw: &TxWithRaw<AH::Message>,
+    mode: RunTxMode,
+) -> Result<(), RunTxError> {
+    handler
+        .run_ante_checks(ctx, tx_with_raw, mode)
+        .inspect_err(|_| ctx.multi_store_mut().clear_cache())
+        .map_err(RunTxError::from)
+}
+
+/// Messages don't run when checking a tx, but post handlers do like in the cosmos sdk. Tips
+/// are paid on the check state, so a tipper who can't afford the tip is kept out of the
+/// mempool and can't promise the same coins in several txs.
+fn run_post_handlers<DB: Database, AH: ABCIHandler>(
+    ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+    handler: &AH,
+    tx_with_raw: &TxWithRaw<AH::Message>,
+    mode: RunTxMode,
+) -> Result<(), RunTxError> {
+    handler
+        .run_post_handlers(ctx, tx_with_raw, mode)
+        .inspect_err(|_| ctx.multi_store_mut().clear_cache())
+        .map_err(RunTxError::from)
+}
@@ -1,8 +1,9 @@
+use core_types::any::google::Any;
 use database::Database;
 use kv_store::bank::multi::TransactionMultiBank;
 use tendermint::types::proto::event::Event;
 
-use super::ExecutionMode;
+use super::{ExecutionMode, RunTxMode};
 use crate::types::gas::basic_meter::BasicGasMeter;
 use crate::types::gas::infinite_meter::InfiniteGasMeter;
 use crate::types::gas::kind::BlockKind;
@@ -33,18 +34,25 @@ impl<DB, AH: ABCIHandler> DeliverTxMode<DB, AH> {
 }
 
 impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for DeliverTxMode<DB, AH> {
-    fn run_msg<'m>(
+    fn run_msg(
         ctx: &mut TxContext<'_, DB, AH::StoreKey>,
         handler: &AH,
-        msgs: impl Iterator<Item = &'m AH::Message>,
-    ) -> Result<Vec<Event>, RunTxError> {
-        for msg in msgs {
-            handler
-                .msg(ctx, msg)
-                .inspect_err(|_| ctx.multi_store_mut().clear_cache())?
+        tx_with_raw: &TxWithRaw<AH::Message>,
+    ) -> Result<(Vec<Event>, Vec<Any>), RunTxError> {
+        let mut msg_responses = Vec::new();
+        for msg in tx_with_raw.tx.get_msgs().iter() {
+            msg_responses.push(
+                handler
+                    .msg(ctx, msg)
+                    .inspect_err(|_| ctx.multi_store_mut().clear_cache())?,
+            );
         }
 
-        Ok(ctx.events_drain())
+        handler
+            .run_post_handlers(ctx, tx_with_raw, RunTxMode::Deliver)
+            .inspect_err(|_| ctx.multi_store_mut().clear_cache())?;
+
+        Ok((ctx.events_drain(), msg_responses))
     }
 
     fn run_ante_checks(
@@ -53,7 +61,7 @@ impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for DeliverTxMode<DB,
         tx_with_raw: &TxWithRaw<AH::Message>,
     ) -> Result<(), RunTxError> {
         handler
-            .run_ante_checks(ctx, tx_with_raw, false)
+            .run_ante_checks(ctx, tx_with_raw, RunTxMode::Deliver)
             .inspect_err(|_| ctx.multi_store_mut().clear_cache())
             .map_err(RunTxError::from)
     }
@@ -1,3 +1,4 @@
+use core_types::any::google::Any;
 use tendermint::types::proto::event::Event;
 
 use self::sealed::Sealed;
@@ -16,8 +17,45 @@ use crate::{
 
 pub mod check;
 pub mod deliver;
+pub mod simulate;
+
+/// Kind of execution a transaction goes through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunTxMode {
+    /// Validation of a new transaction before it's added to the mempool
+    Check,
+    /// Revalidation of a transaction left in the mempool after a block was committed
+    ReCheck,
+    /// Execution against a throwaway copy of the check state to estimate gas
+    Simulate,
+    /// Execution of a transaction included in a block
+    Deliver,
+}
+
+impl RunTxMode {
+    /// Returns true for both new and rechecked mempool transactions
+    pub fn is_check(&self) -> bool {
+        matches!(self, Self::Check | Self::ReCheck)
+    }
+
+    pub fn is_recheck(&self) -> bool {
+        matches!(self, Self::ReCheck)
+    }
+
+    pub fn is_simulate(&self) -> bool {
+        matches!(self, Self::Simulate)
+    }
+
+    pub fn is_deliver(&self) -> bool {
+        matches!(self, Self::Deliver)
+    }
+}
 
 pub trait ExecutionMode<DB, AH: ABCIHandler>: Sealed {
+    fn build_tx_gas_meter(block_height: u32, fee: &Fee) -> GasMeter<TxKind> {
+        build_tx_gas_meter(block_height, Some(fee))
+    }
+
     fn runnable(ctx: &mut TxContext<'_, DB, AH::StoreKey>) -> Result<(), RunTxError>;
 
     fn run_ante_checks(
@@ -26,22 +64,30 @@ pub trait ExecutionMode<DB, AH: ABCIHandler>: Sealed {
         tx_with_raw: &TxWithRaw<AH::Message>,
     ) -> Result<(), RunTxError>;
 
-    fn run_msg<'m>(
+    /// Run the messages of a transaction followed by the post handlers, returning the emitted
+    /// events and the response of each message
+    fn run_msg(
         ctx: &mut TxContext<'_, DB, AH::StoreKey>,
         handler: &AH,
-        msgs: impl Iterator<Item = &'m AH::Message>,
-    ) -> Result<Vec<Event>, RunTxError>;
+        tx_with_raw: &TxWithRaw<AH::Message>,
+    ) -> Result<(Vec<Event>, Vec<Any>), RunTxError>;
 }
 
 mod sealed {
     use crate::application::handlers::node::ABCIHandler;
 
-    use super::{check::CheckTxMode, deliver::DeliverTxMode};
+    use super::{
+        check::{CheckTxMode, ReCheckTxMode},
+        deliver::DeliverTxMode,
+        simulate::SimulateMode,
+    };
 
     pub trait Sealed {}
 
     impl<DB, AH: ABCIHandler> Sealed for CheckTxMode<DB, AH> {}
+    impl<DB, AH: ABCIHandler> Sealed for ReCheckTxMode<DB, AH> {}
     impl<DB, AH: ABCIHandler> Sealed for DeliverTxMode<DB, AH> {}
+    impl<DB, AH: ABCIHandler> Sealed for SimulateMode<DB, AH> {}
 }
 
 pub(crate) fn build_tx_gas_meter(block_height: u32, fee: Option<&Fee>) -> GasMeter<TxKind> {
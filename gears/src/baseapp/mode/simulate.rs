@@ -0,0 +1,73 @@
+use core_types::any::google::Any;
+use database::Database;
+use kv_store::bank::multi::TransactionMultiBank;
+use tendermint::types::proto::event::Event;
+
+use super::{ExecutionMode, RunTxMode};
+use crate::{
+    application::handlers::node::ABCIHandler,
+    baseapp::errors::RunTxError,
+    context::{tx::TxContext, TransactionalContext},
+    types::{
+        auth::fee::Fee,
+        gas::{
+            kind::{BlockKind, TxKind},
+            GasMeter,
+        },
+        tx::raw::TxWithRaw,
+    },
+};
+
+/// Runs a transaction on a fork of the check state. Nothing is written back,
+/// so the mode is only useful to estimate gas and preview events.
+#[derive(Debug)]
+pub struct SimulateMode<DB, AH: ABCIHandler> {
+    pub(crate) block_gas_meter: GasMeter<BlockKind>,
+    pub(crate) multi_store: TransactionMultiBank<DB, AH::StoreKey>,
+}
+
+impl<DB, AH: ABCIHandler> SimulateMode<DB, AH> {
+    pub fn new(multi_store: TransactionMultiBank<DB, AH::StoreKey>) -> Self {
+        Self {
+            block_gas_meter: GasMeter::infinite(),
+            multi_store,
+        }
+    }
+}
+
+impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for SimulateMode<DB, AH> {
+    fn build_tx_gas_meter(_block_height: u32, _fee: &Fee) -> GasMeter<TxKind> {
+        GasMeter::infinite()
+    }
+
+    fn run_msg(
+        ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+        handler: &AH,
+        tx_with_raw: &TxWithRaw<AH::Message>,
+    ) -> Result<(Vec<Event>, Vec<Any>), RunTxError> {
+        let msg_responses = tx_with_raw
+            .tx
+            .get_msgs()
+            .iter()
+            .map(|msg| handler.msg(ctx, msg))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        handler.run_post_handlers(ctx, tx_with_raw, RunTxMode::Simulate)?;
+
+        Ok((ctx.events_drain(), msg_responses))
+    }
+
+    fn run_ante_checks(
+        ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+        handler: &AH,
+        tx_with_raw: &TxWithRaw<AH::Message>,
+    ) -> Result<(), RunTxError> {
+        handler
+            .run_ante_checks(ctx, tx_with_raw, RunTxMode::Simulate)
+            .map_err(RunTxError::from)
+    }
+
+    fn runnable(_: &mut TxContext<'_, DB, AH::StoreKey>) -> Result<(), RunTxError> {
+        Ok(())
+    }
+}
@@ -8,11 +8,35 @@ pub struct NodeOptions(Arc<RwLock<InnerOptions>>);
 #[derive(Debug, Default)]
 struct InnerOptions {
     pub min_gas_prices: MinGasPrices,
+    pub bypass_min_fee_msg_types: Vec<String>,
+    pub max_total_bypass_min_fee_msg_gas_usage: u64,
 }
 
 impl NodeOptions {
     pub fn new(min_gas_prices: MinGasPrices) -> Self {
-        Self(Arc::new(RwLock::new(InnerOptions { min_gas_prices })))
+        Self(Arc::new(RwLock::new(InnerOptions {
+            min_gas_prices,
+            ..Default::default()
+        })))
+    }
+
+    /// Exempt txs which only contain messages of `msg_types` and use at most `max_gas`
+    /// from the minimum gas prices
+    pub fn with_bypass_min_fee_msg_types(self, msg_types: Vec<String>, max_gas: u64) -> Self {
+        self.set_bypass_min_fee_msg_types(msg_types, max_gas);
+        self
+    }
+
+    /// Changes the minimum gas prices of a running node, all clones of the options see the change
+    pub fn set_min_gas_prices(&self, min_gas_prices: MinGasPrices) {
+        self.0.write().expect(POISONED_LOCK).min_gas_prices = min_gas_prices;
+    }
+
+    /// Changes the bypass messages of a running node, see [`Self::with_bypass_min_fee_msg_types`]
+    pub fn set_bypass_min_fee_msg_types(&self, msg_types: Vec<String>, max_gas: u64) {
+        let mut inner = self.0.write().expect(POISONED_LOCK);
+        inner.bypass_min_fee_msg_types = msg_types;
+        inner.max_total_bypass_min_fee_msg_gas_usage = max_gas;
     }
 
     pub fn min_gas_prices(&self) -> MinGasPrices {
@@ -22,4 +46,19 @@ impl NodeOptions {
             .min_gas_prices
             .to_owned()
     }
+
+    pub fn bypass_min_fee_msg_types(&self) -> Vec<String> {
+        self.0
+            .read()
+            .expect(POISONED_LOCK)
+            .bypass_min_fee_msg_types
+            .to_owned()
+    }
+
+    pub fn max_total_bypass_min_fee_msg_gas_usage(&self) -> u64 {
+        self.0
+            .read()
+            .expect(POISONED_LOCK)
+            .max_total_bypass_min_fee_msg_gas_usage
+    }
 }
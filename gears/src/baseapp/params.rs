@@ -43,7 +43,7 @@ const _SUBSPACE_NAME: &str = "baseapp/";
 //##################################################################################
 
 /// A domain ConsensusParams type that wraps domain consensus params types.
-#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ConsensusParams {
     pub block: BlockParams,
     pub evidence: EvidenceParams,
@@ -69,6 +69,23 @@ impl From<inner::ConsensusParams> for ConsensusParams {
     }
 }
 
+impl From<ConsensusParams> for inner::ConsensusParams {
+    fn from(
+        ConsensusParams {
+            block,
+            evidence,
+            validator,
+        }: ConsensusParams,
+    ) -> Self {
+        Self {
+            block: block.into(),
+            evidence: evidence.into(),
+            validator: validator.into(),
+            version: None,
+        }
+    }
+}
+
 impl ParamsSerialize for ConsensusParams {
     fn keys() -> HashSet<&'static str> {
         [KEY_BLOCK_PARAMS, KEY_EVIDENCE_PARAMS, KEY_VALIDATOR_PARAMS]
@@ -127,6 +144,13 @@ impl Default for BlockParams {
     }
 }
 
+impl BlockParams {
+    /// Checks the bounds enforced by tendermint on block params
+    pub fn is_valid(&self) -> bool {
+        self.max_bytes > 0 && self.max_gas >= -1
+    }
+}
+
 impl From<inner::BlockParams> for BlockParams {
     fn from(params: inner::BlockParams) -> BlockParams {
         BlockParams {
@@ -136,6 +160,12 @@ impl From<inner::BlockParams> for BlockParams {
     }
 }
 
+impl From<BlockParams> for inner::BlockParams {
+    fn from(BlockParams { max_bytes, max_gas }: BlockParams) -> Self {
+        Self { max_bytes, max_gas }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ValidatorParams {
     pub pub_key_types: Vec<String>,
@@ -150,6 +180,13 @@ impl Default for ValidatorParams {
     }
 }
 
+impl ValidatorParams {
+    /// Checks the bounds enforced by tendermint on validator params
+    pub fn is_valid(&self) -> bool {
+        !self.pub_key_types.is_empty()
+    }
+}
+
 impl From<inner::ValidatorParams> for ValidatorParams {
     fn from(params: inner::ValidatorParams) -> ValidatorParams {
         ValidatorParams {
@@ -158,8 +195,14 @@ impl From<inner::ValidatorParams> for ValidatorParams {
     }
 }
 
+impl From<ValidatorParams> for inner::ValidatorParams {
+    fn from(ValidatorParams { pub_key_types }: ValidatorParams) -> Self {
+        Self { pub_key_types }
+    }
+}
+
 #[serde_as]
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct EvidenceParams {
     #[serde_as(as = "serde_with::DisplayFromStr")]
     pub max_age_num_blocks: i64,
@@ -182,6 +225,13 @@ impl Default for EvidenceParams {
     }
 }
 
+impl EvidenceParams {
+    /// Checks the bounds enforced by tendermint on evidence params
+    pub fn is_valid(&self) -> bool {
+        self.max_age_num_blocks > 0 && self.max_bytes >= 0
+    }
+}
+
 impl From<inner::EvidenceParams> for EvidenceParams {
     fn from(params: inner::EvidenceParams) -> EvidenceParams {
         EvidenceParams {
@@ -192,6 +242,22 @@ impl From<inner::EvidenceParams> for EvidenceParams {
     }
 }
 
+impl From<EvidenceParams> for inner::EvidenceParams {
+    fn from(
+        EvidenceParams {
+            max_age_num_blocks,
+            max_age_duration,
+            max_bytes,
+        }: EvidenceParams,
+    ) -> Self {
+        Self {
+            max_age_num_blocks,
+            max_age_duration,
+            max_bytes,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BaseAppParamsKeeper<PSK: ParamsSubspaceKey> {
     pub params_subspace_key: PSK,
@@ -207,11 +273,12 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for BaseAppParamsKeeper<PSK> {
     #[cfg(feature = "governance")]
     fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
         match String::from_utf8_lossy(key.as_ref()).as_ref() {
-            KEY_BLOCK_PARAMS => serde_json::from_slice::<BlockParams>(value.as_ref()).is_ok(),
-            KEY_EVIDENCE_PARAMS => serde_json::from_slice::<EvidenceParams>(value.as_ref()).is_ok(),
-            KEY_VALIDATOR_PARAMS => {
-                serde_json::from_slice::<ValidatorParams>(value.as_ref()).is_ok()
-            }
+            KEY_BLOCK_PARAMS => serde_json::from_slice::<BlockParams>(value.as_ref())
+                .is_ok_and(|params| params.is_valid()),
+            KEY_EVIDENCE_PARAMS => serde_json::from_slice::<EvidenceParams>(value.as_ref())
+                .is_ok_and(|params| params.is_valid()),
+            KEY_VALIDATOR_PARAMS => serde_json::from_slice::<ValidatorParams>(value.as_ref())
+                .is_ok_and(|params| params.is_valid()),
             _ => false,
         }
     }
@@ -373,4 +440,26 @@ mod tests {
 
         assert_eq!(ConsensusParams::default(), params);
     }
+
+    #[test]
+    fn invalid_params_are_rejected() {
+        assert!(ConsensusParams::default().block.is_valid());
+        assert!(ConsensusParams::default().evidence.is_valid());
+        assert!(ConsensusParams::default().validator.is_valid());
+
+        assert!(!BlockParams {
+            max_bytes: 0,
+            max_gas: -1
+        }
+        .is_valid());
+        assert!(!BlockParams {
+            max_bytes: 1,
+            max_gas: -2
+        }
+        .is_valid());
+        assert!(!ValidatorParams {
+            pub_key_types: vec![]
+        }
+        .is_valid());
+    }
 }
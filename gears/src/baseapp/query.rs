@@ -1,3 +1,4 @@
+use bytes::Bytes;
 use database::Database;
 use kv_store::query::QueryMultiStore;
 use serde::Serialize;
@@ -7,6 +8,7 @@ use crate::{
     context::query::QueryContext,
     error::POISONED_LOCK,
     params::ParamsSubspaceKey,
+    types::response::simulate::SimulateResponse,
 };
 
 use super::{errors::QueryError, BaseApp};
@@ -27,6 +29,9 @@ pub trait QueryResponse: Clone + Send + Sync + 'static + Serialize {
 
 pub trait NodeQueryHandler<QReq, QRes>: Clone + Send + Sync + 'static {
     fn typed_query<Q: Into<QReq>>(&self, request: Q) -> Result<QRes, QueryError>;
+
+    /// Estimate the gas and preview the events of an encoded transaction
+    fn simulate(&self, tx_bytes: Bytes) -> Result<SimulateResponse, QueryError>;
 }
 
 impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
@@ -40,6 +45,10 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         let ctx = QueryContext::new(QueryMultiStore::new(&*store, version)?, version)?;
         Ok(self.abci_handler.typed_query(&ctx, request))
     }
+
+    fn simulate(&self, tx_bytes: Bytes) -> Result<SimulateResponse, QueryError> {
+        Ok(BaseApp::simulate(self, tx_bytes)?.into())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
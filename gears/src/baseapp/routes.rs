@@ -0,0 +1,108 @@
+//! Routing of the ABCI queries which are answered by the base application
+//! before anything is delegated to the application query handler.
+
+use super::errors::QueryError;
+
+/// Filter applied to a peer which tendermint is about to connect to. Returning an
+/// error rejects the peer.
+pub type PeerFilter = fn(&str) -> Result<(), String>;
+
+/// Filters of the peers tendermint connects to, all peers are accepted by default
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PeerFilters {
+    /// Applied on `/p2p/filter/addr/<address>` queries
+    pub addr: Option<PeerFilter>,
+    /// Applied on `/p2p/filter/id/<id>` queries
+    pub id: Option<PeerFilter>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum QueryRoute<'a> {
+    /// `/app/simulate`
+    Simulate,
+    /// `/app/version`
+    Version,
+    /// `/store/<store>/key`
+    StoreKey(&'a str),
+    /// `/store/<store>/subspace`
+    StoreSubspace(&'a str),
+    /// `/p2p/filter/addr/<address>`
+    PeerAddrFilter(&'a str),
+    /// `/p2p/filter/id/<id>`
+    PeerIdFilter(&'a str),
+    /// Any other path is handled by the application
+    Custom,
+}
+
+impl<'a> QueryRoute<'a> {
+    pub fn parse(path: &'a str) -> Result<Self, QueryError> {
+        let segments = path.trim_start_matches('/').split('/').collect::<Vec<_>>();
+
+        match segments.as_slice() {
+            ["app", "simulate"] => Ok(Self::Simulate),
+            ["app", "version"] => Ok(Self::Version),
+            ["store", store, "key"] => Ok(Self::StoreKey(*store)),
+            ["store", store, "subspace"] => Ok(Self::StoreSubspace(*store)),
+            ["p2p", "filter", "addr", address] => Ok(Self::PeerAddrFilter(*address)),
+            ["p2p", "filter", "id", id] => Ok(Self::PeerIdFilter(*id)),
+            ["app" | "store" | "p2p", ..] => Err(QueryError::PathNotFound),
+            _ => Ok(Self::Custom),
+        }
+    }
+}
+
+/// Key value pairs returned by subspace queries, encoded as `cosmos.base.kv.v1beta1.Pairs`
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Pairs {
+    #[prost(message, repeated, tag = "1")]
+    pub pairs: Vec<Pair>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Pair {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_routes() {
+        assert_eq!(
+            QueryRoute::parse("/app/simulate").ok(),
+            Some(QueryRoute::Simulate)
+        );
+        assert_eq!(
+            QueryRoute::parse("app/version").ok(),
+            Some(QueryRoute::Version)
+        );
+        assert_eq!(
+            QueryRoute::parse("/store/bank/key").ok(),
+            Some(QueryRoute::StoreKey("bank"))
+        );
+        assert_eq!(
+            QueryRoute::parse("/store/acc/subspace").ok(),
+            Some(QueryRoute::StoreSubspace("acc"))
+        );
+        assert_eq!(
+            QueryRoute::parse("/p2p/filter/addr/127.0.0.1:26656").ok(),
+            Some(QueryRoute::PeerAddrFilter("127.0.0.1:26656"))
+        );
+        assert_eq!(
+            QueryRoute::parse("/p2p/filter/id/abcd").ok(),
+            Some(QueryRoute::PeerIdFilter("abcd"))
+        );
+        assert_eq!(
+            QueryRoute::parse("/cosmos.bank.v1beta1.Query/Balance").ok(),
+            Some(QueryRoute::Custom)
+        );
+
+        assert!(QueryRoute::parse("/app/unknown").is_err());
+        assert!(QueryRoute::parse("/store/bank").is_err());
+        assert!(QueryRoute::parse("/p2p/filter/unknown/abcd").is_err());
+    }
+}
@@ -0,0 +1,430 @@
+//! Binary format of state sync snapshots.
+//!
+//! A snapshot is a stream of length delimited [`SnapshotItem`]s, each store item followed by the
+//! exported nodes of that store. The stream is zlib compressed and split into fixed size chunks.
+//! Snapshots are encoded and decoded incrementally, so neither side holds a whole snapshot in memory.
+
+use std::io::Write;
+
+use flate2::{write::ZlibEncoder, Compression, Decompress, FlushDecompress, Status};
+use kv_store::ExportedNode;
+use prost::Message;
+
+use crate::baseapp::errors::SnapshotError;
+
+/// The only snapshot format supported by the application
+pub const SNAPSHOT_FORMAT: u32 = 1;
+/// Maximum size of a single snapshot chunk in bytes
+pub const SNAPSHOT_CHUNK_SIZE: usize = 10_000_000;
+/// Maximum size of a single decompressed snapshot item in bytes
+pub const MAX_ITEM_SIZE: usize = SNAPSHOT_CHUNK_SIZE;
+/// Maximum ratio of the decompressed to the compressed size of a snapshot. Exported trees
+/// compress far less than this, so only decompression bombs are rejected.
+pub const MAX_COMPRESSION_RATIO: u64 = 100;
+
+/// Size of the buffer chunks are decompressed into
+const DECOMPRESS_BUFFER_SIZE: usize = 64 * 1024;
+/// Maximum length of the varint length prefix of an item
+const MAX_VARINT_LEN: usize = 10;
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotItem {
+    #[prost(oneof = "Item", tags = "1, 2")]
+    pub item: Option<Item>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+pub enum Item {
+    #[prost(message, tag = "1")]
+    Store(SnapshotStoreItem),
+    #[prost(message, tag = "2")]
+    Iavl(SnapshotIavlItem),
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotStoreItem {
+    #[prost(string, tag = "1")]
+    pub name: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotIavlItem {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: Vec<u8>,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub value: Option<Vec<u8>>,
+    #[prost(uint32, tag = "3")]
+    pub version: u32,
+    #[prost(uint32, tag = "4")]
+    pub height: u32,
+}
+
+/// Application metadata attached to every snapshot
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotMetadata {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub chunk_hashes: Vec<Vec<u8>>,
+}
+
+impl From<ExportedNode> for SnapshotIavlItem {
+    fn from(
+        ExportedNode {
+            key,
+            value,
+            version,
+            height,
+        }: ExportedNode,
+    ) -> Self {
+        Self {
+            key,
+            value,
+            version,
+            height: height.into(),
+        }
+    }
+}
+
+impl TryFrom<SnapshotIavlItem> for ExportedNode {
+    type Error = SnapshotError;
+
+    fn try_from(
+        SnapshotIavlItem {
+            key,
+            value,
+            version,
+            height,
+        }: SnapshotIavlItem,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            key,
+            value,
+            version,
+            height: height
+                .try_into()
+                .map_err(|_| SnapshotError::Invalid(format!("invalid node height {height}")))?,
+        })
+    }
+}
+
+/// Encode exported stores into compressed snapshot chunks, which are passed to
+/// `write_chunk` in order as soon as they are full
+pub fn encode<'a, N: IntoIterator<Item = ExportedNode>>(
+    stores: impl IntoIterator<Item = (&'a str, N)>,
+    mut write_chunk: impl FnMut(Vec<u8>) -> Result<(), SnapshotError>,
+) -> Result<(), SnapshotError> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+
+    for (name, nodes) in stores {
+        write_item(
+            &mut encoder,
+            Item::Store(SnapshotStoreItem {
+                name: name.to_owned(),
+            }),
+        )?;
+
+        for node in nodes {
+            write_item(&mut encoder, Item::Iavl(node.into()))?;
+            write_full_chunks(encoder.get_mut(), &mut write_chunk)?;
+        }
+    }
+
+    let mut compressed = encoder.finish()?;
+    write_full_chunks(&mut compressed, &mut write_chunk)?;
+
+    if !compressed.is_empty() {
+        write_chunk(compressed)?;
+    }
+
+    Ok(())
+}
+
+fn write_item(writer: &mut impl Write, item: Item) -> std::io::Result<()> {
+    writer.write_all(&SnapshotItem { item: Some(item) }.encode_length_delimited_to_vec())
+}
+
+/// Move all full chunks out of the compressed data
+fn write_full_chunks(
+    compressed: &mut Vec<u8>,
+    write_chunk: &mut impl FnMut(Vec<u8>) -> Result<(), SnapshotError>,
+) -> Result<(), SnapshotError> {
+    while compressed.len() >= SNAPSHOT_CHUNK_SIZE {
+        let rest = compressed.split_off(SNAPSHOT_CHUNK_SIZE);
+        write_chunk(std::mem::replace(compressed, rest))?;
+    }
+
+    Ok(())
+}
+
+/// Decodes the ordered chunks of a snapshot as they arrive.
+///
+/// Chunks are decompressed into a fixed size buffer and only the item which is currently
+/// being decoded is kept, so memory use is bounded by [`MAX_ITEM_SIZE`] no matter how
+/// much a chunk decompresses to.
+#[derive(Debug)]
+pub struct SnapshotDecoder {
+    inflater: Decompress,
+    /// Decompressed bytes which don't form a complete item yet
+    buf: Vec<u8>,
+    finished: bool,
+}
+
+impl Default for SnapshotDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SnapshotDecoder {
+    pub fn new() -> Self {
+        Self {
+            inflater: Decompress::new(true),
+            buf: Vec::new(),
+            finished: false,
+        }
+    }
+
+    /// Decode the next chunk, passing every complete item to `on_item` in order
+    pub fn decode_chunk(
+        &mut self,
+        chunk: &[u8],
+        mut on_item: impl FnMut(Item) -> Result<(), SnapshotError>,
+    ) -> Result<(), SnapshotError> {
+        if self.finished {
+            return Err(SnapshotError::Invalid(
+                "chunk after the end of the snapshot".to_owned(),
+            ));
+        }
+
+        let mut input = chunk;
+        let mut output = vec![0; DECOMPRESS_BUFFER_SIZE];
+        loop {
+            let (total_in, total_out) = (self.inflater.total_in(), self.inflater.total_out());
+
+            let status = self
+                .inflater
+                .decompress(input, &mut output, FlushDecompress::None)
+                .map_err(|err| SnapshotError::Invalid(err.to_string()))?;
+
+            let consumed = (self.inflater.total_in() - total_in) as usize;
+            let produced = (self.inflater.total_out() - total_out) as usize;
+            input = &input[consumed..];
+
+            if self.inflater.total_out() > self.inflater.total_in() * MAX_COMPRESSION_RATIO {
+                return Err(SnapshotError::Invalid(format!(
+                    "snapshot decompresses to more than {MAX_COMPRESSION_RATIO} times its size"
+                )));
+            }
+
+            self.buf.extend_from_slice(&output[..produced]);
+            self.decode_items(&mut on_item)?;
+
+            if status == Status::StreamEnd {
+                self.finished = true;
+
+                return match input.is_empty() {
+                    true => Ok(()),
+                    false => Err(SnapshotError::Invalid(
+                        "data after the end of the snapshot".to_owned(),
+                    )),
+                };
+            }
+
+            if consumed == 0 && produced == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Check that the snapshot ended after the last decoded item
+    pub fn finish(self) -> Result<(), SnapshotError> {
+        match self.finished && self.buf.is_empty() {
+            true => Ok(()),
+            false => Err(SnapshotError::Invalid(
+                "snapshot ended unexpectedly".to_owned(),
+            )),
+        }
+    }
+
+    fn decode_items(
+        &mut self,
+        on_item: &mut impl FnMut(Item) -> Result<(), SnapshotError>,
+    ) -> Result<(), SnapshotError> {
+        let mut buf = self.buf.as_slice();
+
+        loop {
+            // every byte of a varint but the last one has the high bit set
+            if !buf.iter().take(MAX_VARINT_LEN).any(|byte| byte & 0x80 == 0) {
+                if buf.len() >= MAX_VARINT_LEN {
+                    return Err(SnapshotError::Invalid("invalid item length".to_owned()));
+                }
+
+                break;
+            }
+
+            let mut item = buf;
+            let len = prost::decode_length_delimiter(&mut item)?;
+            if len > MAX_ITEM_SIZE {
+                return Err(SnapshotError::Invalid(format!(
+                    "item of {len} bytes is larger than {MAX_ITEM_SIZE} bytes"
+                )));
+            }
+
+            if item.len() < len {
+                break;
+            }
+
+            let SnapshotItem { item: decoded } = SnapshotItem::decode(&item[..len])?;
+            buf = &item[len..];
+
+            on_item(decoded.ok_or(SnapshotError::Invalid("empty snapshot item".to_owned()))?)?;
+        }
+
+        let decoded = self.buf.len() - buf.len();
+        self.buf.drain(..decoded);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exported_stores() -> Vec<(&'static str, Vec<ExportedNode>)> {
+        vec![
+            (
+                "acc",
+                vec![
+                    ExportedNode {
+                        key: vec![1],
+                        value: Some(vec![2]),
+                        version: 1,
+                        height: 0,
+                    },
+                    ExportedNode {
+                        key: vec![2],
+                        value: Some(vec![]),
+                        version: 2,
+                        height: 0,
+                    },
+                    ExportedNode {
+                        key: vec![2],
+                        value: None,
+                        version: 2,
+                        height: 1,
+                    },
+                ],
+            ),
+            ("bank", vec![]),
+        ]
+    }
+
+    fn encode_to_chunks(stores: Vec<(&str, Vec<ExportedNode>)>) -> Vec<Vec<u8>> {
+        let mut chunks = Vec::new();
+        encode(stores, |chunk| {
+            chunks.push(chunk);
+            Ok(())
+        })
+        .expect("encoding to memory won't fail");
+
+        chunks
+    }
+
+    fn decode_chunks<T: AsRef<[u8]>>(
+        chunks: impl IntoIterator<Item = T>,
+    ) -> Result<Vec<(String, Vec<ExportedNode>)>, SnapshotError> {
+        let mut decoder = SnapshotDecoder::new();
+        let mut stores: Vec<(String, Vec<ExportedNode>)> = Vec::new();
+
+        for chunk in chunks {
+            decoder.decode_chunk(chunk.as_ref(), |item| {
+                match item {
+                    Item::Store(SnapshotStoreItem { name }) => stores.push((name, Vec::new())),
+                    Item::Iavl(node) => stores
+                        .last_mut()
+                        .expect("store item comes first")
+                        .1
+                        .push(node.try_into()?),
+                }
+
+                Ok(())
+            })?;
+        }
+
+        decoder.finish()?;
+
+        Ok(stores)
+    }
+
+    fn expected_stores() -> Vec<(String, Vec<ExportedNode>)> {
+        exported_stores()
+            .into_iter()
+            .map(|(name, nodes)| (name.to_owned(), nodes))
+            .collect()
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let chunks = encode_to_chunks(exported_stores());
+        let decoded = decode_chunks(chunks).expect("valid snapshot");
+
+        assert_eq!(decoded, expected_stores());
+    }
+
+    #[test]
+    fn items_split_across_chunks_are_decoded() {
+        let compressed = encode_to_chunks(exported_stores()).concat();
+        let decoded = decode_chunks(compressed.chunks(3)).expect("valid snapshot");
+
+        assert_eq!(decoded, expected_stores());
+    }
+
+    #[test]
+    fn truncated_snapshot_is_rejected() {
+        let compressed = encode_to_chunks(exported_stores()).concat();
+
+        assert!(matches!(
+            decode_chunks([&compressed[..compressed.len() - 1]]),
+            Err(SnapshotError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn decompression_bomb_is_rejected() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+        write_item(
+            &mut encoder,
+            Item::Iavl(SnapshotIavlItem {
+                key: vec![1],
+                value: Some(vec![0; 1_000_000]),
+                version: 1,
+                height: 0,
+            }),
+        )
+        .expect("writing to memory won't fail");
+        let compressed = encoder.finish().expect("writing to memory won't fail");
+
+        assert!(matches!(
+            decode_chunks([compressed]),
+            Err(SnapshotError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn oversized_item_is_rejected() {
+        let mut prefix = Vec::new();
+        prost::encode_length_delimiter(MAX_ITEM_SIZE + 1, &mut prefix)
+            .expect("vec grows as needed");
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&prefix)
+            .expect("writing to memory won't fail");
+        let compressed = encoder.finish().expect("writing to memory won't fail");
+
+        let mut decoder = SnapshotDecoder::new();
+        let res = decoder.decode_chunk(&compressed, |_| Ok(()));
+
+        assert!(matches!(res, Err(SnapshotError::Invalid(_))));
+    }
+}
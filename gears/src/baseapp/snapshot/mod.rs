@@ -0,0 +1,486 @@
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::JoinHandle,
+};
+
+use bytes::Bytes;
+use database::Database;
+use kv_store::{
+    bank::multi::{
+        application::{MultiBankExporter, MultiBankImporter},
+        ApplicationMultiBank,
+    },
+    StoreKey,
+};
+use prost::Message;
+use sha2::{Digest, Sha256};
+use tendermint::types::{
+    proto::info::Snapshot, request::snapshot::RequestApplySnapshotChunk,
+    response::snapshot::OfferResult,
+};
+use tracing::{error, info, warn};
+
+use crate::error::POISONED_LOCK;
+
+use self::{
+    format::{Item, SnapshotDecoder, SnapshotMetadata, SnapshotStoreItem, SNAPSHOT_FORMAT},
+    store::SnapshotStore,
+};
+
+use super::errors::SnapshotError;
+
+pub mod format;
+pub mod store;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SnapshotOptions {
+    /// Block interval at which snapshots are taken, 0 disables snapshots
+    pub interval: u32,
+    /// Number of recent snapshots to keep, 0 keeps all of them
+    pub keep_recent: u32,
+}
+
+impl SnapshotOptions {
+    /// Number of recent blocks which must be kept so that nodes restored from
+    /// any of the kept snapshots can catch up using blocks
+    pub fn retention_blocks(&self) -> u64 {
+        u64::from(self.interval) * u64::from(self.keep_recent)
+    }
+}
+
+/// Takes periodic snapshots of the application state and restores state from
+/// snapshots received from peers during state sync.
+#[derive(Debug, Clone)]
+pub struct SnapshotManager {
+    store: SnapshotStore,
+    options: SnapshotOptions,
+    in_progress: Arc<AtomicBool>,
+    /// Thread which writes the snapshot in progress
+    worker: Arc<Mutex<Option<JoinHandle<()>>>>,
+    restore: Arc<Mutex<Option<PendingRestore>>>,
+}
+
+#[derive(Debug)]
+struct PendingRestore {
+    snapshot: Snapshot,
+    app_hash: Bytes,
+    chunk_hashes: Vec<Vec<u8>>,
+    /// Index of the next chunk, chunks are decoded in order as they arrive
+    next_chunk: u32,
+    hasher: Sha256,
+    decoder: SnapshotDecoder,
+}
+
+/// State of an in progress restore after applying a chunk
+#[derive(Debug)]
+pub enum ChunkApplied {
+    /// Chunk was accepted, more chunks are needed
+    Pending,
+    /// Chunk didn't match the snapshot metadata and should be fetched again
+    Retry { index: u32, sender: String },
+    /// The restore can't continue
+    Abort,
+    /// The snapshot is invalid, another snapshot may be restored instead
+    Reject,
+    /// All chunks were received and decoded
+    Complete(RestoredSnapshot),
+}
+
+/// Snapshot whose items were all decoded
+#[derive(Debug)]
+pub struct RestoredSnapshot {
+    pub height: u32,
+    /// Light client verified app hash at `height`
+    pub app_hash: Bytes,
+}
+
+impl SnapshotManager {
+    pub fn new(dir: impl Into<PathBuf>, options: SnapshotOptions) -> Self {
+        Self {
+            store: SnapshotStore::new(dir),
+            options,
+            in_progress: Default::default(),
+            worker: Default::default(),
+            restore: Default::default(),
+        }
+    }
+
+    pub fn options(&self) -> SnapshotOptions {
+        self.options
+    }
+
+    /// Take a snapshot in the background if `height` is a multiple of the snapshot interval
+    pub fn maybe_snapshot<DB: Database, SK: StoreKey>(
+        &self,
+        multi_store: &ApplicationMultiBank<DB, SK>,
+        height: u32,
+    ) {
+        if self.options.interval == 0 || height == 0 || height % self.options.interval != 0 {
+            return;
+        }
+
+        if self.in_progress.swap(true, Ordering::AcqRel) {
+            warn!("Skipping snapshot at height {height}, previous snapshot is still in progress");
+            return;
+        }
+
+        let exporter = match multi_store.exporter(height) {
+            Ok(exporter) => exporter,
+            Err(err) => {
+                error!("Failed to create snapshot at height {height}: {err}");
+                self.in_progress.store(false, Ordering::Release);
+                return;
+            }
+        };
+
+        let manager = self.clone();
+        let worker = std::thread::spawn(move || {
+            match manager.create(exporter) {
+                Ok(snapshot) => info!(
+                    "Created snapshot at height {} with {} chunks",
+                    snapshot.height, snapshot.chunks
+                ),
+                Err(err) => error!("Failed to create snapshot at height {height}: {err}"),
+            }
+
+            manager.in_progress.store(false, Ordering::Release);
+        });
+        // the previous worker finished, as no snapshot was in progress
+        *self.worker.lock().expect(POISONED_LOCK) = Some(worker);
+    }
+
+    /// Blocks until the snapshot which is being written in the background, if any, is done
+    pub fn wait(&self) {
+        let worker = self.worker.lock().expect(POISONED_LOCK).take();
+        if let Some(worker) = worker {
+            if worker.join().is_err() {
+                error!("Snapshot worker panicked");
+            }
+        }
+    }
+
+    fn create<DB: Database>(
+        &self,
+        exporter: MultiBankExporter<DB>,
+    ) -> Result<Snapshot, SnapshotError> {
+        let mut writer = self.store.writer(exporter.version().into())?;
+        format::encode(exporter.export(), |chunk| writer.write_chunk(&chunk))?;
+        let snapshot = writer.finish()?;
+        self.store.prune(self.options.keep_recent)?;
+
+        Ok(snapshot)
+    }
+
+    pub fn list(&self) -> Vec<Snapshot> {
+        self.store.list().unwrap_or_else(|err| {
+            error!("Failed to list snapshots: {err}");
+            Vec::new()
+        })
+    }
+
+    pub fn load_chunk(&self, height: u64, format: u32, chunk: u32) -> Option<Vec<u8>> {
+        self.store
+            .load_chunk(height, format, chunk)
+            .unwrap_or_else(|err| {
+                error!("Failed to load chunk {chunk} of snapshot at height {height}: {err}");
+                None
+            })
+    }
+
+    /// Start restoring from a snapshot offered by a peer
+    pub fn offer(&self, snapshot: Option<Snapshot>, app_hash: Bytes) -> OfferResult {
+        let Some(snapshot) = snapshot else {
+            return OfferResult::Reject;
+        };
+
+        if snapshot.format != SNAPSHOT_FORMAT {
+            return OfferResult::RejectFormat;
+        }
+
+        if snapshot.chunks == 0 || u32::try_from(snapshot.height).is_err() {
+            return OfferResult::Reject;
+        }
+
+        let chunk_hashes = match SnapshotMetadata::decode(snapshot.metadata.clone()) {
+            Ok(SnapshotMetadata { chunk_hashes })
+                if chunk_hashes.len() == snapshot.chunks as usize =>
+            {
+                chunk_hashes
+            }
+            _ => return OfferResult::Reject,
+        };
+
+        info!("Restoring snapshot at height {}", snapshot.height);
+
+        *self.restore.lock().expect(POISONED_LOCK) = Some(PendingRestore {
+            snapshot,
+            app_hash,
+            chunk_hashes,
+            next_chunk: 0,
+            hasher: Sha256::new(),
+            decoder: SnapshotDecoder::new(),
+        });
+
+        OfferResult::Accept
+    }
+
+    /// Apply a chunk of the snapshot which is currently being restored, passing the
+    /// items it completes to `on_item`. Chunks must be applied in order.
+    pub fn apply_chunk(
+        &self,
+        RequestApplySnapshotChunk {
+            index,
+            chunk,
+            sender,
+        }: RequestApplySnapshotChunk,
+        on_item: impl FnMut(Item) -> Result<(), SnapshotError>,
+    ) -> ChunkApplied {
+        let mut restore = self.restore.lock().expect(POISONED_LOCK);
+
+        let Some(pending) = restore.as_mut() else {
+            error!("Received snapshot chunk {index} without an accepted snapshot offer");
+            return ChunkApplied::Abort;
+        };
+
+        let Some(expected_hash) = pending.chunk_hashes.get(index as usize) else {
+            error!("Received snapshot chunk {index} out of range");
+            return ChunkApplied::Abort;
+        };
+
+        if index != pending.next_chunk {
+            error!(
+                "Received snapshot chunk {index}, expected chunk {}",
+                pending.next_chunk
+            );
+            return ChunkApplied::Abort;
+        }
+
+        if Sha256::digest(&chunk).as_slice() != expected_hash.as_slice() {
+            warn!("Snapshot chunk {index} from {sender} doesn't match its hash");
+            return ChunkApplied::Retry { index, sender };
+        }
+
+        pending.hasher.update(&chunk);
+        pending.next_chunk += 1;
+
+        if let Err(err) = pending.decoder.decode_chunk(&chunk, on_item) {
+            error!(
+                "Failed to decode snapshot at height {}: {err}",
+                pending.snapshot.height
+            );
+            *restore = None;
+            return ChunkApplied::Reject;
+        }
+
+        if (pending.next_chunk as usize) < pending.chunk_hashes.len() {
+            return ChunkApplied::Pending;
+        }
+
+        let PendingRestore {
+            snapshot,
+            app_hash,
+            hasher,
+            decoder,
+            ..
+        } = restore
+            .take()
+            .expect("checked above that restore is pending");
+
+        if hasher.finalize().as_slice() != snapshot.hash.as_ref() {
+            error!(
+                "Snapshot at height {} doesn't match its hash",
+                snapshot.height
+            );
+            return ChunkApplied::Reject;
+        }
+
+        if let Err(err) = decoder.finish() {
+            error!(
+                "Failed to decode snapshot at height {}: {err}",
+                snapshot.height
+            );
+            return ChunkApplied::Reject;
+        }
+
+        ChunkApplied::Complete(RestoredSnapshot {
+            height: snapshot
+                .height
+                .try_into()
+                .expect("height is checked when the snapshot is offered"),
+            app_hash,
+        })
+    }
+}
+
+/// Import a decoded snapshot item into the stores which are being restored
+pub fn import_item<DB: Database, SK: StoreKey>(
+    importer: &mut MultiBankImporter<DB, SK>,
+    item: Item,
+) -> Result<(), SnapshotError> {
+    let res = match item {
+        Item::Store(SnapshotStoreItem { name }) => importer.store(&name),
+        Item::Iavl(node) => importer.node(node.try_into()?),
+    };
+
+    res.map_err(|err| SnapshotError::Store(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::ZlibEncoder, Compression};
+    use kv_store::ExportedNode;
+
+    use super::*;
+
+    fn chunk_request(index: u32, chunk: Vec<u8>) -> RequestApplySnapshotChunk {
+        RequestApplySnapshotChunk {
+            index,
+            chunk: chunk.into(),
+            sender: "peer".to_owned(),
+        }
+    }
+
+    fn encode_to_chunks(stores: Vec<(&str, Vec<ExportedNode>)>) -> Vec<Vec<u8>> {
+        let mut chunks = Vec::new();
+        format::encode(stores, |chunk| {
+            chunks.push(chunk);
+            Ok(())
+        })
+        .expect("encoding to memory won't fail");
+
+        chunks
+    }
+
+    /// Names of the stores in the decoded items
+    fn store_names(items: &[Item]) -> Vec<&str> {
+        items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Store(SnapshotStoreItem { name }) => Some(name.as_str()),
+                Item::Iavl(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn offer_and_apply_saved_snapshot() {
+        let dir = std::env::temp_dir().join(format!("gears-snapshot-{}", std::process::id()));
+        let manager = SnapshotManager::new(
+            &dir,
+            SnapshotOptions {
+                interval: 1,
+                keep_recent: 1,
+            },
+        );
+
+        let stores = vec![(
+            "bank",
+            vec![ExportedNode {
+                key: vec![1],
+                value: Some(vec![2]),
+                version: 5,
+                height: 0,
+            }],
+        )];
+        let chunks = encode_to_chunks(stores);
+        let snapshot = manager
+            .store
+            .save(5, chunks.clone())
+            .expect("temp dir is writable");
+        manager.store.save(6, chunks).expect("temp dir is writable");
+        manager.store.prune(1).expect("temp dir is writable");
+
+        assert_eq!(manager.list().len(), 1);
+        assert_eq!(manager.load_chunk(5, SNAPSHOT_FORMAT, 0), None);
+
+        let chunk = manager
+            .load_chunk(6, SNAPSHOT_FORMAT, 0)
+            .expect("chunk was saved");
+
+        assert_eq!(
+            manager.offer(Some(snapshot.clone()), Bytes::new()),
+            OfferResult::Accept
+        );
+
+        let mut items = Vec::new();
+        assert!(matches!(
+            manager.apply_chunk(chunk_request(0, vec![0]), |item| {
+                items.push(item);
+                Ok(())
+            }),
+            ChunkApplied::Retry { index: 0, .. }
+        ));
+        assert!(items.is_empty());
+
+        match manager.apply_chunk(chunk_request(0, chunk), |item| {
+            items.push(item);
+            Ok(())
+        }) {
+            ChunkApplied::Complete(restored) => assert_eq!(restored.height, 5),
+            outcome => panic!("unexpected outcome {outcome:?}"),
+        }
+        assert_eq!(items.len(), 2);
+        assert_eq!(store_names(&items), vec!["bank"]);
+
+        std::fs::remove_dir_all(dir).expect("temp dir is writable");
+    }
+
+    #[test]
+    fn invalid_snapshot_is_rejected_and_another_can_be_restored() {
+        let dir =
+            std::env::temp_dir().join(format!("gears-snapshot-rejected-{}", std::process::id()));
+        let manager = SnapshotManager::new(&dir, SnapshotOptions::default());
+
+        // chunk which decompresses to an item that can't be decoded
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&[3, 0xff, 0xff, 0xff])
+            .expect("writing to memory won't fail");
+        let invalid_chunk = encoder.finish().expect("writing to memory won't fail");
+        let invalid = manager
+            .store
+            .save(5, [invalid_chunk.clone()])
+            .expect("temp dir is writable");
+
+        let valid_chunks = encode_to_chunks(vec![("bank", vec![])]);
+        let valid = manager
+            .store
+            .save(4, valid_chunks.clone())
+            .expect("temp dir is writable");
+
+        assert_eq!(
+            manager.offer(Some(invalid), Bytes::new()),
+            OfferResult::Accept
+        );
+        assert!(matches!(
+            manager.apply_chunk(chunk_request(0, invalid_chunk), |_| Ok(())),
+            ChunkApplied::Reject
+        ));
+
+        assert_eq!(
+            manager.offer(Some(valid), Bytes::new()),
+            OfferResult::Accept
+        );
+
+        // items which the caller fails to import reject the snapshot too
+        assert!(matches!(
+            manager.apply_chunk(chunk_request(0, valid_chunks[0].clone()), |_| Err(
+                SnapshotError::Store("import failed".to_owned())
+            )),
+            ChunkApplied::Reject
+        ));
+        // and there is no snapshot left to apply chunks to
+        assert!(matches!(
+            manager.apply_chunk(chunk_request(0, valid_chunks[0].clone()), |_| Ok(())),
+            ChunkApplied::Abort
+        ));
+
+        std::fs::remove_dir_all(dir).expect("temp dir is writable");
+    }
+}
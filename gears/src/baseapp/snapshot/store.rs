@@ -0,0 +1,184 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use prost::Message;
+use sha2::{Digest, Sha256};
+use tendermint::types::proto::info::Snapshot;
+
+use crate::baseapp::errors::SnapshotError;
+
+use super::format::{SnapshotMetadata, SNAPSHOT_FORMAT};
+
+const METADATA_FILE_NAME: &str = "metadata";
+
+/// On disk storage of snapshots laid out as `<dir>/<height>/<format>/<chunk>`
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+}
+
+impl SnapshotStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn snapshot_dir(&self, height: u64, format: u32) -> PathBuf {
+        self.dir.join(height.to_string()).join(format.to_string())
+    }
+
+    /// Start saving a snapshot taken at `height` chunk by chunk
+    pub fn writer(&self, height: u64) -> Result<SnapshotWriter, SnapshotError> {
+        let dir = self.snapshot_dir(height, SNAPSHOT_FORMAT);
+        fs::create_dir_all(&dir)?;
+
+        Ok(SnapshotWriter {
+            dir,
+            height,
+            hasher: Sha256::new(),
+            chunk_hashes: Vec::new(),
+        })
+    }
+
+    /// Save the chunks of a snapshot taken at `height`
+    pub fn save(
+        &self,
+        height: u64,
+        chunks: impl IntoIterator<Item = Vec<u8>>,
+    ) -> Result<Snapshot, SnapshotError> {
+        let mut writer = self.writer(height)?;
+        for chunk in chunks {
+            writer.write_chunk(&chunk)?;
+        }
+
+        writer.finish()
+    }
+
+    /// List all saved snapshots, most recent first
+    pub fn list(&self) -> Result<Vec<Snapshot>, SnapshotError> {
+        let mut snapshots = Vec::new();
+
+        for height in self.heights()? {
+            for format_dir in fs::read_dir(self.dir.join(height.to_string()))? {
+                let metadata_path = format_dir?.path().join(METADATA_FILE_NAME);
+
+                if metadata_path.is_file() {
+                    snapshots.push(Snapshot::decode(fs::read(metadata_path)?.as_slice())?);
+                }
+            }
+        }
+
+        snapshots.sort_by(|a, b| b.height.cmp(&a.height).then(b.format.cmp(&a.format)));
+
+        Ok(snapshots)
+    }
+
+    /// Load a chunk of a saved snapshot, returns `None` if it doesn't exist
+    pub fn load_chunk(
+        &self,
+        height: u64,
+        format: u32,
+        chunk: u32,
+    ) -> Result<Option<Vec<u8>>, SnapshotError> {
+        let dir = self.snapshot_dir(height, format);
+
+        if !dir.join(METADATA_FILE_NAME).is_file() {
+            return Ok(None);
+        }
+
+        let path = dir.join(chunk.to_string());
+        match path.is_file() {
+            true => Ok(Some(fs::read(path)?)),
+            false => Ok(None),
+        }
+    }
+
+    /// Delete all but the `keep_recent` most recent snapshots. Zero keeps every snapshot.
+    pub fn prune(&self, keep_recent: u32) -> Result<(), SnapshotError> {
+        if keep_recent == 0 {
+            return Ok(());
+        }
+
+        let mut heights = self.heights()?;
+        heights.sort_unstable_by(|a, b| b.cmp(a));
+
+        for height in heights.into_iter().skip(keep_recent as usize) {
+            fs::remove_dir_all(self.dir.join(height.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    fn heights(&self) -> Result<Vec<u64>, SnapshotError> {
+        if !self.dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut heights = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+
+            if let Some(height) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u64>().ok())
+            {
+                heights.push(height);
+            }
+        }
+
+        Ok(heights)
+    }
+}
+
+/// Snapshot which is being saved, see [`SnapshotStore::writer`]
+#[derive(Debug)]
+pub struct SnapshotWriter {
+    dir: PathBuf,
+    height: u64,
+    hasher: Sha256,
+    chunk_hashes: Vec<Vec<u8>>,
+}
+
+impl SnapshotWriter {
+    /// Save the next chunk of the snapshot
+    pub fn write_chunk(&mut self, chunk: &[u8]) -> Result<(), SnapshotError> {
+        fs::write(self.dir.join(self.chunk_hashes.len().to_string()), chunk)?;
+
+        self.hasher.update(chunk);
+        self.chunk_hashes.push(Sha256::digest(chunk).to_vec());
+
+        Ok(())
+    }
+
+    /// Save the snapshot metadata. Snapshots are only listed once this succeeds.
+    pub fn finish(self) -> Result<Snapshot, SnapshotError> {
+        let Self {
+            dir,
+            height,
+            hasher,
+            chunk_hashes,
+        } = self;
+
+        let snapshot = Snapshot {
+            height,
+            format: SNAPSHOT_FORMAT,
+            chunks: chunk_hashes
+                .len()
+                .try_into()
+                .map_err(|_| SnapshotError::Invalid("too many chunks".to_owned()))?,
+            hash: hasher.finalize().to_vec().into(),
+            metadata: SnapshotMetadata { chunk_hashes }.encode_to_vec().into(),
+        };
+
+        // metadata is written last so that partially saved snapshots are never listed
+        fs::write(dir.join(METADATA_FILE_NAME), snapshot.encode_to_vec())?;
+
+        Ok(snapshot)
+    }
+}
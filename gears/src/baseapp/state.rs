@@ -6,7 +6,10 @@ use crate::{
     types::gas::{basic_meter::BasicGasMeter, infinite_meter::InfiniteGasMeter, Gas, GasMeter},
 };
 
-use super::mode::{check::CheckTxMode, deliver::DeliverTxMode};
+use super::{
+    mode::{check::CheckTxMode, deliver::DeliverTxMode},
+    ConsensusParams,
+};
 
 #[derive(Debug)]
 pub struct ApplicationState<DB, AH: ABCIHandler> {
@@ -14,6 +17,8 @@ pub struct ApplicationState<DB, AH: ABCIHandler> {
     pub(super) deliver_mode: DeliverTxMode<DB, AH>,
     pub head_hash: [u8; 32],
     pub last_height: u32,
+    /// Consensus params at the start of the current block, used to detect updates in EndBlock
+    pub(super) block_consensus_params: ConsensusParams,
 }
 
 impl<DB: Database, AH: ABCIHandler> ApplicationState<DB, AH> {
@@ -23,6 +28,7 @@ impl<DB: Database, AH: ABCIHandler> ApplicationState<DB, AH> {
             deliver_mode: DeliverTxMode::new(max_gas, multi_store.to_tx_kind()),
             head_hash: multi_store.head_commit_hash(),
             last_height: multi_store.head_version(),
+            block_consensus_params: ConsensusParams::default(),
         }
     }
 
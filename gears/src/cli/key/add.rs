@@ -5,7 +5,7 @@ use clap::{ArgAction, ValueHint};
 use crate::{
     application::ApplicationInfo,
     cli::config::client_config,
-    commands::client::keys::{AddKeyCommand, KeyringBackend},
+    commands::client::keys::{AddKeyCommand, KeyringBackend, MultisigKeyOptions},
 };
 
 #[derive(Debug, Clone, ::clap::Args)]
@@ -22,6 +22,15 @@ pub struct CliAddKeyCommand<T: ApplicationInfo> {
     /// select keyring's backend
     #[arg(long = "keyring-backend",  action = ArgAction::Set, default_value_t = client_config(&T::home_dir()).keyring_backend())]
     keyring_backend: KeyringBackend,
+    /// List of key names stored in keyring to construct a public legacy multisig key
+    #[arg(long, action = ArgAction::Set, value_delimiter = ',', conflicts_with = "recover")]
+    multisig: Option<Vec<String>>,
+    /// K out of N required signatures. For use in conjunction with --multisig
+    #[arg(long = "multisig-threshold", action = ArgAction::Set, default_value_t = 1, requires = "multisig")]
+    multisig_threshold: u32,
+    /// Keys passed to --multisig are taken in the order they're supplied
+    #[arg(long, action = ArgAction::SetTrue, requires = "multisig")]
+    nosort: bool,
 
     #[arg(skip)]
     _marker: PhantomData<T>,
@@ -34,6 +43,9 @@ impl<T: ApplicationInfo> From<CliAddKeyCommand<T>> for AddKeyCommand {
             recover,
             home,
             keyring_backend,
+            multisig,
+            multisig_threshold,
+            nosort,
             _marker,
         } = value;
 
@@ -43,6 +55,11 @@ impl<T: ApplicationInfo> From<CliAddKeyCommand<T>> for AddKeyCommand {
             home,
             keyring_backend,
             bip39_mnemonic: None,
+            multisig: multisig.map(|keys| MultisigKeyOptions {
+                keys,
+                threshold: multisig_threshold,
+                nosort,
+            }),
         }
     }
 }
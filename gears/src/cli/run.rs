@@ -4,7 +4,7 @@ use clap::{ArgAction, ValueHint};
 
 use crate::{
     application::ApplicationInfo,
-    commands::node::run::{LogLevel, RunCommand},
+    commands::node::run::{AbciVersion, LogLevel, RunCommand},
     config::{
         DEFAULT_ADDRESS, DEFAULT_GRPC_LISTEN_ADDR, DEFAULT_REST_LISTEN_ADDR,
         DEFAULT_TENDERMINT_RPC_ADDRESS,
@@ -33,6 +33,15 @@ pub struct CliRunCommand<T: ApplicationInfo> {
     /// Minimum gas prices to accept for transactions; Any fee in a tx must meet this minimum (e.g. 0.01photino,0.0001stake)
     #[arg(long, action = ArgAction::Set)]
     pub min_gas_prices: Option<MinGasPrices>,
+    /// Block height at which to gracefully halt the node after committing (0 to disable). Overrides the value in the config
+    #[arg(long, action = ArgAction::Set)]
+    pub halt_height: Option<u32>,
+    /// Unix time in seconds after which to gracefully halt the node on the next commit (0 to disable). Overrides the value in the config
+    #[arg(long, action = ArgAction::Set)]
+    pub halt_time: Option<u64>,
+    /// Version of the ABCI protocol of the tendermint node, v0.38 for CometBFT 0.38 (ABCI++). Overrides the value in the config
+    #[arg(long, action = ArgAction::Set)]
+    pub abci_version: Option<AbciVersion>,
 
     #[arg(skip)]
     pub _marker: PhantomData<T>,
@@ -50,6 +59,9 @@ impl<T: ApplicationInfo> From<CliRunCommand<T>> for RunCommand {
             min_gas_prices,
             grpc_listen_addr,
             rpc_addr,
+            halt_height,
+            halt_time,
+            abci_version,
         }: CliRunCommand<T>,
     ) -> Self {
         Self {
@@ -61,6 +73,9 @@ impl<T: ApplicationInfo> From<CliRunCommand<T>> for RunCommand {
             log_level,
             min_gas_prices,
             tendermint_rpc_addr: rpc_addr,
+            halt_height,
+            halt_time,
+            abci_version,
         }
     }
 }
@@ -10,7 +10,10 @@ use crate::{
     cli::config::client_config,
     commands::client::{
         keys::KeyringBackend,
-        tx::{AccountProvider, ClientTxContext, Keyring as TxKeyring, LocalInfo, TxCommand},
+        tx::{
+            AccountProvider, AuxSigning, ClientTxContext, Keyring as TxKeyring, LocalInfo,
+            MultisignInfo, TxCommand,
+        },
     },
     types::{
         auth::{fee::Fee, gas::Gas},
@@ -52,6 +55,10 @@ pub struct CliTxCommand<T: ApplicationInfo, C: Args> {
     #[arg(long, global = true, action = ArgAction::Set, required = false )]
     pub timeout_height: Option<u32>,
 
+    #[command(flatten)]
+    #[group(id = "Aux signing", global = true)]
+    pub aux: AuxCli,
+
     #[command(flatten)]
     pub command: C,
 
@@ -105,6 +112,42 @@ impl TryFrom<FeeCli> for Fee {
     }
 }
 
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct AuxCli {
+    /// Sign in direct aux mode and print the signature, which the fee payer adds to the tx
+    #[arg(
+        long,
+        global = true,
+        default_value_t = false,
+        help_heading = "Aux signing"
+    )]
+    pub aux: bool,
+    /// Tip for the fee payer, paid by the aux signer; eg: 10uatom
+    #[arg(long, global = true, action = ArgAction::Set, requires = "aux", help_heading = "Aux signing")]
+    pub tip: Option<UnsignedCoins>,
+    /// File with the signature of an aux signer, the tx is signed by the fee payer and broadcast
+    #[arg(long = "aux-signature", global = true, action = ArgAction::Append, value_hint = ValueHint::FilePath, conflicts_with = "aux", help_heading = "Aux signing")]
+    pub aux_signatures: Vec<PathBuf>,
+}
+
+impl From<AuxCli> for AuxSigning {
+    fn from(
+        AuxCli {
+            aux,
+            tip,
+            aux_signatures,
+        }: AuxCli,
+    ) -> Self {
+        if aux {
+            AuxSigning::Sign { tip }
+        } else if !aux_signatures.is_empty() {
+            AuxSigning::Combine(aux_signatures)
+        } else {
+            AuxSigning::None
+        }
+    }
+}
+
 #[derive(Debug, Clone, ::clap::Args)]
 pub struct Mode {
     /// makes sure that the client will not reach out to full node.
@@ -129,6 +172,9 @@ pub enum Keyring {
     /// Use a local keyring to source the signing key
     #[strum(to_string = "local")]
     Local,
+    /// Combine signatures of the keys of the multisig key set by `from-key`
+    #[strum(to_string = "multisign")]
+    Multisign,
 }
 
 #[derive(Debug, Clone, ::clap::Args)]
@@ -143,6 +189,16 @@ pub struct Local<T: ApplicationInfo> {
     #[arg(help_heading = "Local signing options")]
     keyring_backend: KeyringBackend,
 
+    /// Sign on behalf of the multisig key with this name and print the signature
+    #[arg(long, global = true, action = ArgAction::Set, required = false)]
+    #[arg(help_heading = "Local signing options")]
+    multisig: Option<String>,
+
+    /// File with a signature of a key of the multisig key, used with `--keyring multisign`
+    #[arg(long = "signature", global = true, action = ArgAction::Append, value_hint = ValueHint::FilePath)]
+    #[arg(help_heading = "Local signing options")]
+    signatures: Vec<PathBuf>,
+
     #[arg(skip)]
     _marker: PhantomData<T>,
 }
@@ -176,6 +232,7 @@ where
             mode,
             note,
             timeout_height,
+            aux,
             fee,
             command,
         } = value;
@@ -186,6 +243,7 @@ where
                 let Local {
                     from_key,
                     keyring_backend,
+                    multisig,
                     ..
                 } = local.ok_or(MissingCliOptions(
                     "local signing options: from-key".to_owned(),
@@ -194,6 +252,27 @@ where
                 TxKeyring::Local(LocalInfo {
                     keyring_backend,
                     from_key,
+                    multisig,
+                })
+            }
+            Keyring::Multisign => {
+                let Local {
+                    from_key,
+                    keyring_backend,
+                    signatures,
+                    ..
+                } = local.ok_or(MissingCliOptions(
+                    "local signing options: from-key".to_owned(),
+                ))?;
+
+                if signatures.is_empty() {
+                    Err(MissingCliOptions("signature".to_owned()))?
+                }
+
+                TxKeyring::Multisign(MultisignInfo {
+                    keyring_backend,
+                    multisig_key: from_key,
+                    signatures,
                 })
             }
         };
@@ -236,6 +315,7 @@ where
                 account,
                 memo: note,
                 timeout_height,
+                aux: aux.into(),
                 fee: fee.try_into()?,
             },
         })
@@ -1,14 +1,19 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use bip32::Mnemonic;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use strum::Display;
 use text_io::read;
 
-use crate::crypto::keys::ReadAccAddress;
+use crate::crypto::{
+    keys::{GearsPublicKey, ReadAccAddress},
+    multisig::LegacyAminoPubKey,
+    public::PublicKey,
+};
 
 const KEYRING_SUB_DIR_FILE: &str = "keyring-file";
 const KEYRING_SUB_DIR_TEST: &str = "keyring-test";
+const MULTISIG_SUB_DIR: &str = "multisig";
 
 #[derive(Clone, Default, Debug, Display, Deserialize, Serialize)]
 #[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
@@ -48,6 +53,95 @@ pub struct AddKeyCommand {
     pub home: PathBuf,
     pub keyring_backend: KeyringBackend,
     pub bip39_mnemonic: Option<String>,
+    /// Create a multisig key of existing keys instead of a private key
+    pub multisig: Option<MultisigKeyOptions>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MultisigKeyOptions {
+    /// Names of the keys which make up the multisig key
+    pub keys: Vec<String>,
+    /// Number of signatures required to sign on behalf of the multisig key
+    pub threshold: u32,
+    /// Keep the keys in the given order instead of sorting them by address
+    pub nosort: bool,
+}
+
+/// Returns the multisig key stored with `name` in `keyring_home`.
+///
+/// Multisig keys hold only public keys, so they're stored unencrypted
+/// next to the keys of the keyring.
+pub fn multisig_key_by_name(name: &str, keyring_home: &Path) -> Result<LegacyAminoPubKey> {
+    let path = multisig_key_path(name, keyring_home);
+    let file = std::fs::File::open(&path)
+        .map_err(|e| anyhow!("failed to read multisig key {name} from {path:?}: {e}"))?;
+
+    match serde_json::from_reader(file)? {
+        PublicKey::Multisig(key) => Ok(key),
+        _ => Err(anyhow!("key {name} isn't a multisig key")),
+    }
+}
+
+fn multisig_key_path(name: &str, keyring_home: &Path) -> PathBuf {
+    keyring_home
+        .join(MULTISIG_SUB_DIR)
+        .join(format!("{name}.json"))
+}
+
+/// Returns the public key stored with `name`, either a multisig key or the key of the keyring
+fn public_key_by_name(
+    name: &str,
+    keyring_home: &PathBuf,
+    keyring_backend: &KeyringBackend,
+) -> Result<PublicKey> {
+    if multisig_key_path(name, keyring_home).exists() {
+        return multisig_key_by_name(name, keyring_home).map(PublicKey::Multisig);
+    }
+
+    let key = keyring::key_by_name(name, keyring_backend.to_keyring_backend(keyring_home))?;
+
+    Ok(key.get_gears_public_key())
+}
+
+fn add_multisig_key(
+    name: &str,
+    MultisigKeyOptions {
+        keys,
+        threshold,
+        nosort,
+    }: MultisigKeyOptions,
+    keyring_home: &PathBuf,
+    keyring_backend: &KeyringBackend,
+) -> Result<LegacyAminoPubKey> {
+    let path = multisig_key_path(name, keyring_home);
+    if path.exists() {
+        Err(anyhow!("multisig key {name} already exists"))?
+    }
+
+    let mut public_keys = keys
+        .iter()
+        .map(|key| public_key_by_name(key, keyring_home, keyring_backend))
+        .collect::<Result<Vec<_>>>()?;
+
+    for (i, key) in public_keys.iter().enumerate() {
+        if public_keys[..i].contains(key) {
+            Err(anyhow!("duplicate key {} in the multisig key", keys[i]))?
+        }
+    }
+
+    if !nosort {
+        public_keys.sort_by_key(PublicKey::get_address);
+    }
+
+    let key = LegacyAminoPubKey::new(threshold, public_keys)?;
+
+    std::fs::create_dir_all(keyring_home.join(MULTISIG_SUB_DIR))?;
+    std::fs::write(
+        path,
+        serde_json::to_vec_pretty(&PublicKey::Multisig(key.clone()))?,
+    )?;
+
+    Ok(key)
 }
 
 // TODO: remove this cli code
@@ -60,10 +154,23 @@ pub fn keys(command: KeyCommand) -> Result<()> {
                 home,
                 keyring_backend,
                 bip39_mnemonic,
+                multisig,
             } = cmd;
 
             let keyring_home = home.join(keyring_backend.get_sub_dir());
 
+            if let Some(multisig) = multisig {
+                let key = add_multisig_key(&name, multisig, &keyring_home, &keyring_backend)?;
+
+                println!(
+                    "Created multisig key {}\nAddress: {}",
+                    name,
+                    key.get_address()
+                );
+
+                return Ok(());
+            }
+
             let backend = keyring_backend.to_keyring_backend(&keyring_home);
 
             if recover {
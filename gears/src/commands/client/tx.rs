@@ -1,7 +1,12 @@
 use std::path::PathBuf;
 
-use core_types::tx::mode_info::SignMode;
+use address::AccAddress;
+use anyhow::anyhow;
+use core_types::signing::{SignDoc, SignDocDirectAux};
+use core_types::tx::mode_info::{ModeInfo, SignMode};
+use core_types::Protobuf;
 use prost::Message;
+use serde::{Deserialize, Serialize};
 use tendermint::rpc::client::{Client, HttpClient};
 use tendermint::rpc::response::tx::broadcast::Response;
 use tendermint::types::chain_id::ChainId;
@@ -9,14 +14,23 @@ use tendermint::types::chain_id::ChainId;
 use crate::application::handlers::client::{NodeFetcher, TxExecutionResult, TxHandler};
 use crate::commands::client::query::execute_query;
 use crate::crypto::any_key::AnyKey;
-use crate::crypto::keys::GearsPublicKey;
+use crate::crypto::keys::{GearsPublicKey, ReadAccAddress, SigningKey};
 use crate::crypto::ledger::LedgerProxyKey;
+use crate::crypto::multisig::{combine_signatures, LegacyAminoPubKey};
+use crate::crypto::public::PublicKey;
 use crate::runtime::runtime;
+use crate::signing::std_sign_doc::StdSignDoc;
 use crate::types::auth::fee::Fee;
 use crate::types::auth::gas::Gas;
+use crate::types::auth::info::AuthInfo;
+use crate::types::auth::tip::Tip;
+use crate::types::base::coins::UnsignedCoins;
+use crate::types::signing::SignerInfo;
+use crate::types::tx::body::TxBody;
 use crate::types::tx::raw::TxRaw;
+use crate::types::tx::{Messages, Tx, TxMessage};
 
-use super::keys::KeyringBackend;
+use super::keys::{multisig_key_by_name, KeyringBackend};
 
 #[derive(Debug, Clone)]
 pub enum AccountProvider {
@@ -39,10 +53,23 @@ pub struct ClientTxContext {
     pub account: AccountProvider,
     pub chain_id: ChainId,
     pub timeout_height: Option<u32>,
+    pub aux: AuxSigning,
 
     pub fee: Fee,
 }
 
+/// Signing of txs in direct aux mode, which lets a signer tip the fee payer
+/// instead of paying the fee itself
+#[derive(Debug, Clone, Default)]
+pub enum AuxSigning {
+    #[default]
+    None,
+    /// Sign in direct aux mode and print the signature, see [`AuxSignerData`]
+    Sign { tip: Option<UnsignedCoins> },
+    /// Sign as the fee payer and add the signatures of aux signers from these files
+    Combine(Vec<PathBuf>),
+}
+
 impl ClientTxContext {
     pub fn query<Response: TryFrom<Raw>, Raw: Message + Default + std::convert::From<Response>>(
         &self,
@@ -68,11 +95,13 @@ impl ClientTxContext {
             keyring: Keyring::Local(LocalInfo {
                 keyring_backend: KeyringBackend::Test,
                 from_key: from_key.to_owned(),
+                multisig: None,
             }),
             node,
             chain_id,
             memo: None,
             timeout_height: None,
+            aux: AuxSigning::None,
             fee: Fee {
                 amount: None,
                 gas_limit,
@@ -87,12 +116,50 @@ impl ClientTxContext {
 pub enum Keyring {
     Ledger,
     Local(LocalInfo),
+    /// Combine signatures of the keys of a multisig key and broadcast the tx
+    Multisign(MultisignInfo),
 }
 
 #[derive(Debug, Clone)]
 pub struct LocalInfo {
     pub keyring_backend: KeyringBackend,
     pub from_key: String,
+    /// Sign on behalf of the multisig key with this name, the signature is printed
+    /// instead of broadcasting the tx
+    pub multisig: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MultisignInfo {
+    pub keyring_backend: KeyringBackend,
+    pub multisig_key: String,
+    /// Files with signatures of the keys of the multisig key, see [`MultisigSignature`]
+    pub signatures: Vec<PathBuf>,
+}
+
+/// Signature of a signer which signed in direct aux mode along with the signed tx body,
+/// see `AuxSignerData` of the cosmos sdk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuxSignerData {
+    pub address: AccAddress,
+    pub public_key: PublicKey,
+    #[serde(with = "core_types::serializers::Base64Standard")]
+    pub body_bytes: Vec<u8>,
+    pub chain_id: String,
+    pub account_number: u64,
+    pub sequence: u64,
+    pub tip: Option<Tip>,
+    #[serde(with = "core_types::serializers::Base64Standard")]
+    pub signature: Vec<u8>,
+}
+
+/// Signature of one of the keys of a multisig key over a tx of the multisig account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultisigSignature {
+    pub public_key: PublicKey,
+    #[serde(with = "core_types::serializers::Base64Standard")]
+    pub signature: Vec<u8>,
+    pub sequence: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -144,14 +211,421 @@ fn handle_key(client_tx_context: &ClientTxContext) -> anyhow::Result<AnyKey> {
 
             Ok(AnyKey::Local(key))
         }
+        Keyring::Multisign(_) => Err(anyhow!(
+            "multisig keys can't sign, combine signatures of their keys instead"
+        )),
+    }
+}
+
+fn multisig_key(
+    client_tx_context: &ClientTxContext,
+    name: &str,
+    keyring_backend: &KeyringBackend,
+) -> anyhow::Result<LegacyAminoPubKey> {
+    multisig_key_by_name(
+        name,
+        &client_tx_context.home.join(keyring_backend.get_sub_dir()),
+    )
+}
+
+/// Tx body of the multisig account along with its sequence and the bytes signed by its keys.
+/// Multisig signers sign in `LegacyAminoJson` mode like in the cosmos sdk.
+fn multisig_tx_body<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
+    ctx: &mut ClientTxContext,
+    inner: C,
+    key: &LegacyAminoPubKey,
+    handler: &H,
+    fetcher: &F,
+) -> anyhow::Result<(TxBody<H::Message>, u64, Vec<u8>)> {
+    let messages = handler.prepare_tx(ctx, inner, PublicKey::Multisig(key.clone()))?;
+    if messages.chunk_size() > 0 {
+        Err(anyhow!(
+            "messages of multisig accounts can't be split into several txs"
+        ))?
+    }
+
+    let address = key.get_address();
+    let account = handler
+        .account(address.clone(), ctx, fetcher)?
+        .ok_or_else(|| anyhow!("account not found: {}", address))?;
+
+    let body = tx_body(ctx, messages);
+
+    let sign_bytes = StdSignDoc::new(
+        &body,
+        ctx.fee.clone(),
+        &ctx.chain_id,
+        account.get_account_number(),
+        account.get_sequence(),
+    )?
+    .to_sign_bytes()?;
+
+    Ok((body, account.get_sequence(), sign_bytes))
+}
+
+fn sign_for_multisig<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
+    TxCommand { mut ctx, inner }: TxCommand<C>,
+    multisig: &str,
+    keyring_backend: &KeyringBackend,
+    handler: &H,
+    fetcher: &F,
+) -> anyhow::Result<RuntxResult> {
+    let key = handle_key(&ctx)?;
+    let multisig = multisig_key(&ctx, multisig, keyring_backend)?;
+    let public_key = key.get_gears_public_key();
+    if !multisig.public_keys().contains(&public_key) {
+        Err(anyhow!(
+            "key {} doesn't belong to the multisig key",
+            public_key.get_address()
+        ))?
+    }
+
+    let (_, sequence, sign_bytes) = multisig_tx_body(&mut ctx, inner, &multisig, handler, fetcher)?;
+    let signature = key.sign(&sign_bytes).map_err(|e| anyhow!(e.to_string()))?;
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&MultisigSignature {
+            public_key,
+            signature,
+            sequence,
+        })?
+    );
+
+    Ok(RuntxResult::None)
+}
+
+fn multisign<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
+    TxCommand { mut ctx, inner }: TxCommand<C>,
+    MultisignInfo {
+        keyring_backend,
+        multisig_key: name,
+        signatures,
+    }: MultisignInfo,
+    handler: &H,
+    fetcher: &F,
+) -> anyhow::Result<RuntxResult> {
+    let key = multisig_key(&ctx, &name, &keyring_backend)?;
+    let (body, sequence, _) = multisig_tx_body(&mut ctx, inner, &key, handler, fetcher)?;
+
+    let mut key_signatures = Vec::with_capacity(signatures.len());
+    for path in signatures {
+        let file = std::fs::File::open(&path)
+            .map_err(|e| anyhow!("failed to read signature from {path:?}: {e}"))?;
+        let MultisigSignature {
+            public_key,
+            signature,
+            sequence: signature_sequence,
+        } = serde_json::from_reader(file)?;
+
+        if signature_sequence != sequence {
+            Err(anyhow!(
+                "signature in {path:?} is for sequence {signature_sequence}, expected {sequence}"
+            ))?
+        }
+
+        key_signatures.push((
+            public_key,
+            ModeInfo::Single(SignMode::LegacyAminoJson),
+            signature,
+        ));
+    }
+
+    let (mode_info, signature) = combine_signatures(&key, key_signatures)?;
+
+    let tx = Tx {
+        body,
+        auth_info: AuthInfo {
+            signer_infos: vec![SignerInfo {
+                public_key: Some(PublicKey::Multisig(key)),
+                mode_info,
+                sequence,
+            }],
+            fee: ctx.fee.clone(),
+            tip: None,
+        },
+        signatures: vec![signature],
+        signatures_data: Vec::new(),
+    };
+
+    handler.handle_tx(tx, &mut ctx).map(Into::into)
+}
+
+fn tx_body<M: TxMessage>(ctx: &ClientTxContext, messages: Messages<M>) -> TxBody<M> {
+    TxBody {
+        messages: messages.into_msgs(),
+        memo: ctx.memo.clone().unwrap_or_default(),
+        timeout_height: ctx.timeout_height.unwrap_or_default(),
+        extension_options: vec![],
+        non_critical_extension_options: vec![],
     }
 }
 
+/// Sign a tx body in direct aux mode. The signature doesn't cover the fee, the returned
+/// data is handed to the fee payer which completes the tx with [`combine_aux_signatures`].
+pub fn sign_aux_body<K: SigningKey + GearsPublicKey + ReadAccAddress, M: TxMessage>(
+    key: &K,
+    body: &TxBody<M>,
+    chain_id: &ChainId,
+    account_number: u64,
+    sequence: u64,
+    tip: Option<UnsignedCoins>,
+) -> anyhow::Result<AuxSignerData> {
+    let public_key = key.get_gears_public_key();
+    let address = key.get_address();
+
+    let body_bytes = body.encode_vec();
+    let tip = tip.map(|amount| Tip {
+        amount: Some(amount),
+        tipper: address.clone(),
+    });
+
+    let sign_doc = SignDocDirectAux {
+        body_bytes: body_bytes.clone(),
+        public_key: Some(public_key.clone().into()),
+        chain_id: chain_id.to_string(),
+        account_number,
+        sequence,
+        tip: tip.clone().map(Into::into),
+    };
+    let signature = key
+        .sign(&sign_doc.encode_to_vec())
+        .map_err(|e| anyhow!(e.to_string()))?;
+
+    Ok(AuxSignerData {
+        address,
+        public_key,
+        body_bytes,
+        chain_id: chain_id.to_string(),
+        account_number,
+        sequence,
+        tip,
+        signature,
+    })
+}
+
+/// Build a tx from the signatures of aux signers over `body` and sign it as the fee payer.
+/// Signers of the messages which didn't sign in direct aux mode must be the fee payer.
+pub fn combine_aux_signatures<K: SigningKey + GearsPublicKey + ReadAccAddress, M: TxMessage>(
+    key: &K,
+    body: TxBody<M>,
+    aux_signers: &[AuxSignerData],
+    fee: Fee,
+    chain_id: &ChainId,
+    account_number: u64,
+    sequence: u64,
+) -> anyhow::Result<Tx<M>> {
+    if aux_signers.is_empty() {
+        Err(anyhow!("no aux signatures to combine"))?
+    }
+
+    let body_bytes = body.encode_vec();
+
+    let mut tip = None;
+    for aux_signer in aux_signers {
+        if aux_signer.body_bytes != body_bytes {
+            Err(anyhow!(
+                "tx doesn't match the tx signed by {}",
+                aux_signer.address
+            ))?
+        }
+
+        if aux_signer.chain_id != chain_id.to_string() {
+            Err(anyhow!(
+                "{} signed for chain {}, expected {}",
+                aux_signer.address,
+                aux_signer.chain_id,
+                chain_id
+            ))?
+        }
+
+        if aux_signer.tip.is_some() {
+            if tip.is_some() {
+                Err(anyhow!("only one aux signer can tip the fee payer"))?
+            }
+            tip.clone_from(&aux_signer.tip);
+        }
+    }
+
+    let fee_payer = key.get_address();
+    let mut fee = fee;
+    fee.payer.get_or_insert(fee_payer.clone());
+
+    let mut tx = Tx {
+        body,
+        auth_info: AuthInfo {
+            signer_infos: vec![],
+            fee,
+            tip,
+        },
+        signatures: vec![],
+        signatures_data: Vec::new(),
+    };
+
+    let mut signatures = vec![];
+    for signer in tx.get_signers() {
+        if let Some(aux_signer) = aux_signers.iter().find(|this| &this.address == signer) {
+            signatures.push((
+                SignerInfo {
+                    public_key: Some(aux_signer.public_key.clone()),
+                    mode_info: ModeInfo::Single(SignMode::DirectAux),
+                    sequence: aux_signer.sequence,
+                },
+                Some(aux_signer.signature.clone()),
+            ));
+        } else if signer == &fee_payer {
+            signatures.push((
+                SignerInfo {
+                    public_key: Some(key.get_gears_public_key()),
+                    mode_info: ModeInfo::Single(SignMode::Direct),
+                    sequence,
+                },
+                None,
+            ));
+        } else {
+            Err(anyhow!("signature of {signer} is missing"))?
+        }
+    }
+
+    let (signer_infos, signatures): (Vec<_>, Vec<_>) = signatures.into_iter().unzip();
+    tx.auth_info.signer_infos = signer_infos;
+
+    // the fee payer signs over the signer infos of all signers, so it signs last
+    let fee_payer_signature = key
+        .sign(
+            &SignDoc {
+                body_bytes,
+                auth_info_bytes: tx.auth_info.encode_vec(),
+                chain_id: chain_id.to_string(),
+                account_number,
+            }
+            .encode_to_vec(),
+        )
+        .map_err(|e| anyhow!(e.to_string()))?;
+
+    tx.signatures = signatures
+        .into_iter()
+        .map(|signature| signature.unwrap_or_else(|| fee_payer_signature.clone()))
+        .collect();
+
+    Ok(tx)
+}
+
+fn sign_aux<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
+    TxCommand { mut ctx, inner }: TxCommand<C>,
+    tip: Option<UnsignedCoins>,
+    handler: &H,
+    fetcher: &F,
+) -> anyhow::Result<RuntxResult> {
+    let key = handle_key(&ctx)?;
+    let address = key.get_address();
+
+    let messages = handler.prepare_tx(&mut ctx, inner, key.get_gears_public_key())?;
+    if messages.chunk_size() > 0 {
+        Err(anyhow!(
+            "messages signed in direct aux mode can't be split into several txs"
+        ))?
+    }
+
+    let account = handler
+        .account(address.clone(), &mut ctx, fetcher)?
+        .ok_or_else(|| anyhow!("account not found: {}", address))?;
+
+    let aux_signer_data = sign_aux_body(
+        &key,
+        &tx_body(&ctx, messages),
+        &ctx.chain_id,
+        account.get_account_number(),
+        account.get_sequence(),
+        tip,
+    )?;
+
+    println!("{}", serde_json::to_string_pretty(&aux_signer_data)?);
+
+    Ok(RuntxResult::None)
+}
+
+fn combine_aux<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
+    TxCommand { mut ctx, inner }: TxCommand<C>,
+    files: Vec<PathBuf>,
+    handler: &H,
+    fetcher: &F,
+) -> anyhow::Result<RuntxResult> {
+    let aux_signers = files
+        .iter()
+        .map(|path| {
+            let file = std::fs::File::open(path)
+                .map_err(|e| anyhow!("failed to read aux signature from {path:?}: {e}"))?;
+            Ok(serde_json::from_reader(file)?)
+        })
+        .collect::<anyhow::Result<Vec<AuxSignerData>>>()?;
+    let first = aux_signers
+        .first()
+        .ok_or_else(|| anyhow!("no aux signatures to combine"))?;
+
+    // the messages are recreated as the aux signer created them and checked against the signed ones
+    let messages = handler.prepare_tx(&mut ctx, inner, first.public_key.clone())?;
+    if messages.chunk_size() > 0 {
+        Err(anyhow!(
+            "messages signed in direct aux mode can't be split into several txs"
+        ))?
+    }
+    let body = tx_body(&ctx, messages);
+
+    let key = handle_key(&ctx)?;
+    let fee_payer = key.get_address();
+    let account = handler
+        .account(fee_payer.clone(), &mut ctx, fetcher)?
+        .ok_or_else(|| anyhow!("account not found: {}", fee_payer))?;
+
+    let tx = combine_aux_signatures(
+        &key,
+        body,
+        &aux_signers,
+        ctx.fee.clone(),
+        &ctx.chain_id,
+        account.get_account_number(),
+        account.get_sequence(),
+    )?;
+
+    handler.handle_tx(tx, &mut ctx).map(Into::into)
+}
+
 pub fn run_tx<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
     TxCommand { mut ctx, inner }: TxCommand<C>,
     handler: &H,
     fetcher: &F,
 ) -> anyhow::Result<RuntxResult> {
+    match std::mem::take(&mut ctx.aux) {
+        AuxSigning::Sign { tip } => {
+            return sign_aux(TxCommand { ctx, inner }, tip, handler, fetcher)
+        }
+        AuxSigning::Combine(files) => {
+            return combine_aux(TxCommand { ctx, inner }, files, handler, fetcher)
+        }
+        AuxSigning::None => (),
+    }
+
+    match ctx.keyring.clone() {
+        Keyring::Local(LocalInfo {
+            keyring_backend,
+            multisig: Some(multisig),
+            ..
+        }) => {
+            return sign_for_multisig(
+                TxCommand { ctx, inner },
+                &multisig,
+                &keyring_backend,
+                handler,
+                fetcher,
+            )
+        }
+        Keyring::Multisign(info) => {
+            return multisign(TxCommand { ctx, inner }, info, handler, fetcher)
+        }
+        Keyring::Ledger | Keyring::Local(_) => (),
+    }
+
     let key = handle_key(&mut ctx)?;
 
     let messages = handler.prepare_tx(&mut ctx, inner, key.get_gears_public_key())?;
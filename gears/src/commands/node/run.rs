@@ -1,7 +1,8 @@
 use crate::application::handlers::node::ABCIHandler;
 use crate::application::ApplicationInfo;
 use crate::baseapp::options::NodeOptions;
-use crate::baseapp::{BaseApp, NodeQueryHandler};
+use crate::baseapp::snapshot::{SnapshotManager, SnapshotOptions};
+use crate::baseapp::{BaseApp, NodeQueryHandler, PeerFilters, HALT_EXIT_CODE};
 use crate::config::{ApplicationConfig, Config, ConfigDirectory};
 use crate::grpc::run_grpc_server;
 use crate::params::ParamsSubspaceKey;
@@ -10,12 +11,18 @@ use crate::types::base::min_gas::MinGasPrices;
 use axum::Router;
 use database::{Database, DatabaseBuilder};
 use std::net::SocketAddr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tendermint::abci::v0_38::ABCIPlusPlus;
 use tendermint::abci::ServerBuilder;
 use tendermint::application::ABCI;
+use tendermint::types::chain_id::ChainId;
 use tower_layer::Identity;
 use tracing::metadata::LevelFilter;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+
+/// Interval at which the config file is checked for changes of the mempool options
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(5);
 
 #[derive(Debug, Clone)]
 pub struct RunCommand {
@@ -27,6 +34,9 @@ pub struct RunCommand {
     pub read_buf_size: usize,
     pub log_level: LogLevel,
     pub min_gas_prices: Option<MinGasPrices>,
+    pub halt_height: Option<u32>,
+    pub halt_time: Option<u64>,
+    pub abci_version: Option<AbciVersion>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -38,6 +48,8 @@ pub enum RunError {
     #[error("{0}")]
     TendermintServer(#[from] tendermint::abci::errors::Error),
     #[error("{0}")]
+    TendermintServerV0_38(#[from] tendermint::abci::v0_38::Error),
+    #[error("{0}")]
     Custom(String),
     #[error("{0}")]
     TendermintRPC(#[from] tendermint::rpc::error::Error),
@@ -59,6 +71,25 @@ pub enum LogLevel {
     Off,
 }
 
+/// Version of the ABCI protocol spoken by the tendermint node
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, strum::Display, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum AbciVersion {
+    /// Tendermint 0.34, blocks are delivered with BeginBlock, DeliverTx and EndBlock
+    #[default]
+    #[strum(to_string = "v0.34")]
+    #[serde(rename = "v0.34")]
+    #[cfg_attr(feature = "cli", value(name = "v0.34"))]
+    V0_34,
+    /// CometBFT 0.38 (ABCI++), blocks are delivered with FinalizeBlock
+    #[strum(to_string = "v0.38")]
+    #[serde(rename = "v0.38")]
+    #[cfg_attr(feature = "cli", value(name = "v0.38"))]
+    V0_38,
+}
+
 impl From<LogLevel> for LevelFilter {
     fn from(value: LogLevel) -> Self {
         match value {
@@ -95,6 +126,7 @@ pub fn run<
     params_subspace_key: PSK,
     abci_handler_builder: impl FnOnce(Config<AC>) -> H,
     router_builder: RB,
+    peer_filters: PeerFilters,
 ) -> Result<(), RunError> {
     let RunCommand {
         home,
@@ -105,6 +137,9 @@ pub fn run<
         log_level,
         min_gas_prices,
         tendermint_rpc_addr: tendermint_addr,
+        halt_height,
+        halt_time,
+        abci_version,
     } = cmd;
 
     tracing_subscriber::fmt()
@@ -121,18 +156,47 @@ pub fn run<
 
     let cfg_file_path = ConfigDirectory::ConfigFile.path_from_home(&home);
 
-    let config: Config<AC> = Config::from_file(cfg_file_path)
+    let config: Config<AC> = Config::from_file(cfg_file_path.clone())
         .map_err(|e| RunError::Custom(format!("Error reading config file: {:?}", e)))?;
 
     let abci_handler = abci_handler_builder(config.clone());
 
+    let min_gas_prices_overridden = min_gas_prices.is_some();
     let options = NodeOptions::new(min_gas_prices.or(config.min_gas_prices).ok_or(
         RunError::HomeDirectory(
             "Failed to get `min_gas_prices` set it via cli or in config file".to_owned(),
         ),
-    )?);
+    )?)
+    .with_bypass_min_fee_msg_types(
+        config.app_config.bypass_min_fee_msg_types(),
+        config.app_config.max_total_bypass_min_fee_msg_gas_usage(),
+    );
+
+    watch_mempool_options(MempoolOptionsReloader::new(
+        cfg_file_path,
+        options.clone(),
+        min_gas_prices_overridden,
+        |contents| {
+            toml::from_str::<Config<AC>>(contents)
+                .map(|config| MempoolOptions::from_config(&config))
+                .map_err(|e| e.to_string())
+        },
+    ));
+
+    let snapshot_manager = SnapshotManager::new(
+        db_dir.join("snapshots"),
+        SnapshotOptions {
+            interval: config.snapshot_interval,
+            keep_recent: config.snapshot_keep_recent,
+        },
+    );
 
-    let app: BaseApp<DB, PSK, H, AI> = BaseApp::new(db, params_subspace_key, abci_handler, options);
+    let app: BaseApp<DB, PSK, H, AI> = BaseApp::new(db, params_subspace_key, abci_handler, options)
+        .with_snapshot_manager(snapshot_manager)
+        .with_min_retain_blocks(config.min_retain_blocks)
+        .with_halt_height(halt_height.unwrap_or(config.halt_height))
+        .with_halt_time(halt_time.unwrap_or(config.halt_time))
+        .with_peer_filters(peer_filters);
 
     run_rest_server::<H::Message, H::QReq, H::QRes, _>(
         app.clone(),
@@ -148,11 +212,327 @@ pub fn run<
         grpc_listen_addr.unwrap_or(config.grpc_listen_addr),
     );
 
+    watch_halt(app.clone());
+
     let addr = address.unwrap_or(config.address);
 
-    let server = ServerBuilder::new(read_buf_size).bind(addr, ABCI::from(app))?;
+    match abci_version.unwrap_or(config.abci_version) {
+        AbciVersion::V0_34 => {
+            let server = ServerBuilder::new(read_buf_size).bind(addr, ABCI::from(app))?;
+
+            info!("Starting proxy server at: {}", addr.to_string());
+
+            server.listen().map_err(|e| e.into())
+        }
+        AbciVersion::V0_38 => {
+            let app = app.with_chain_id(genesis_chain_id(&home)?);
+            let server = tendermint::abci::v0_38::ServerBuilder::new(read_buf_size)
+                .bind(addr, ABCIPlusPlus::from(app))?;
+
+            info!("Starting ABCI++ proxy server at: {}", addr.to_string());
+
+            server.listen().map_err(|e| e.into())
+        }
+    }
+}
+
+/// Stops the node once it reached its halt height or halt time, like the SIGINT the
+/// Cosmos SDK sends itself. The commit which raised the halt signal returns its response
+/// first and the snapshot in progress is finished before the process exits.
+fn watch_halt<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>(
+    app: BaseApp<DB, PSK, H, AI>,
+) {
+    std::thread::spawn(move || {
+        app.halt_signal().wait();
+        app.shutdown();
+
+        info!("Node halted, exiting");
+        std::process::exit(HALT_EXIT_CODE);
+    });
+}
+
+/// Chain id of the genesis file. FinalizeBlock requests don't carry it and InitChain
+/// isn't called again when the node restarts.
+fn genesis_chain_id(home: &Path) -> Result<ChainId, RunError> {
+    #[derive(serde::Deserialize)]
+    struct Genesis {
+        chain_id: ChainId,
+    }
 
-    info!("Starting proxy server at: {}", addr.to_string());
+    let path = ConfigDirectory::GenesisFile.path_from_home(home);
+    let genesis = std::fs::read_to_string(&path)
+        .map_err(|e| RunError::Custom(format!("Error reading genesis file: {e}")))?;
+    let Genesis { chain_id } = serde_json::from_str(&genesis)
+        .map_err(|e| RunError::Custom(format!("Error parsing genesis file: {e}")))?;
 
-    server.listen().map_err(|e| e.into())
+    Ok(chain_id)
+}
+
+/// Options of the node which are reloaded when the config file changes
+struct MempoolOptions {
+    min_gas_prices: Option<MinGasPrices>,
+    bypass_min_fee_msg_types: Vec<String>,
+    max_total_bypass_min_fee_msg_gas_usage: u64,
+}
+
+impl MempoolOptions {
+    fn from_config<AC: ApplicationConfig>(config: &Config<AC>) -> Self {
+        Self {
+            min_gas_prices: config.min_gas_prices.clone(),
+            bypass_min_fee_msg_types: config.app_config.bypass_min_fee_msg_types(),
+            max_total_bypass_min_fee_msg_gas_usage: config
+                .app_config
+                .max_total_bypass_min_fee_msg_gas_usage(),
+        }
+    }
+}
+
+/// Applies changes of the mempool options in the config file to the options of the node.
+/// Minimum gas prices set on the command line take precedence over the config file.
+struct MempoolOptionsReloader {
+    path: PathBuf,
+    options: NodeOptions,
+    min_gas_prices_overridden: bool,
+    load: fn(&str) -> Result<MempoolOptions, String>,
+    /// Contents of the config file when it was last applied. Unlike its modification time,
+    /// they also change when the file is rewritten within the same second.
+    applied: Option<String>,
+    /// Contents of the config file which failed to load, so they are reported only once
+    rejected: Option<String>,
+}
+
+impl MempoolOptionsReloader {
+    /// The current contents of the config file are considered applied, as the options of
+    /// the node are built from them
+    fn new(
+        path: PathBuf,
+        options: NodeOptions,
+        min_gas_prices_overridden: bool,
+        load: fn(&str) -> Result<MempoolOptions, String>,
+    ) -> Self {
+        let applied = std::fs::read_to_string(&path).ok();
+
+        Self {
+            path,
+            options,
+            min_gas_prices_overridden,
+            load,
+            applied,
+            rejected: None,
+        }
+    }
+
+    /// Applies the mempool options of the config file if it changed since they were last
+    /// applied, returns whether it did. A file which fails to load, e.g. because it's only
+    /// partially written, is loaded again once it changes.
+    fn reload(&mut self) -> Result<bool, String> {
+        let contents = std::fs::read_to_string(&self.path).map_err(|e| e.to_string())?;
+        if self.applied.as_ref() == Some(&contents) || self.rejected.as_ref() == Some(&contents) {
+            return Ok(false);
+        }
+
+        let MempoolOptions {
+            min_gas_prices,
+            bypass_min_fee_msg_types,
+            max_total_bypass_min_fee_msg_gas_usage,
+        } = match (self.load)(&contents) {
+            Ok(options) => options,
+            Err(e) => {
+                self.rejected = Some(contents);
+                return Err(e);
+            }
+        };
+
+        match min_gas_prices {
+            Some(_) if self.min_gas_prices_overridden => {
+                warn!("Ignoring `min_gas_prices` of the config file, it's set on the command line")
+            }
+            Some(min_gas_prices) => {
+                info!("Setting minimum gas prices to {min_gas_prices}");
+                self.options.set_min_gas_prices(min_gas_prices);
+            }
+            None => (),
+        }
+
+        self.options.set_bypass_min_fee_msg_types(
+            bypass_min_fee_msg_types,
+            max_total_bypass_min_fee_msg_gas_usage,
+        );
+
+        self.applied = Some(contents);
+        self.rejected = None;
+
+        Ok(true)
+    }
+}
+
+/// Polls the config file and applies changes of the mempool options, so validators can
+/// react to fee spikes without a restart
+fn watch_mempool_options(mut reloader: MempoolOptionsReloader) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(CONFIG_POLL_INTERVAL);
+
+        match reloader.reload() {
+            Ok(true) => info!("Reloaded mempool options from {}", reloader.path.display()),
+            Ok(false) => (),
+            Err(e) => error!(
+                "Error reading changed config file {}, keeping the current options: {e}",
+                reloader.path.display()
+            ),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Clone, Default)]
+    #[serde(default)]
+    struct TestAppConfig {
+        bypass_min_fee_msg_types: Vec<String>,
+        max_total_bypass_min_fee_msg_gas_usage: u64,
+    }
+
+    impl ApplicationConfig for TestAppConfig {
+        fn bypass_min_fee_msg_types(&self) -> Vec<String> {
+            self.bypass_min_fee_msg_types.clone()
+        }
+
+        fn max_total_bypass_min_fee_msg_gas_usage(&self) -> u64 {
+            self.max_total_bypass_min_fee_msg_gas_usage
+        }
+    }
+
+    const CONFIG: &str = r#"
+min_gas_prices = "1uatom"
+
+[app_config]
+bypass_min_fee_msg_types = ["/cosmos.bank.v1beta1.MsgSend"]
+max_total_bypass_min_fee_msg_gas_usage = 200000
+"#;
+
+    const CHANGED_CONFIG: &str = r#"
+min_gas_prices = "2uatom"
+
+[app_config]
+bypass_min_fee_msg_types = ["/ibc.core.client.v1.MsgUpdateClient"]
+max_total_bypass_min_fee_msg_gas_usage = 300000
+"#;
+
+    /// Config file in the temp dir which is removed when the test ends
+    struct ConfigFile(PathBuf);
+
+    impl ConfigFile {
+        fn new(name: &str, contents: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("gears-run-{}-{name}.toml", std::process::id()));
+            std::fs::write(&path, contents).expect("temp dir is writable");
+            Self(path)
+        }
+
+        fn write(&self, contents: &str) {
+            std::fs::write(&self.0, contents).expect("temp dir is writable");
+        }
+    }
+
+    impl Drop for ConfigFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn reloader(file: &ConfigFile, min_gas_prices_overridden: bool) -> MempoolOptionsReloader {
+        MempoolOptionsReloader::new(
+            file.0.clone(),
+            NodeOptions::new("1uatom".parse().expect("hard coded gas price is valid"))
+                .with_bypass_min_fee_msg_types(
+                    vec!["/cosmos.bank.v1beta1.MsgSend".to_owned()],
+                    200_000,
+                ),
+            min_gas_prices_overridden,
+            |contents| {
+                toml::from_str::<Config<TestAppConfig>>(contents)
+                    .map(|config| MempoolOptions::from_config(&config))
+                    .map_err(|e| e.to_string())
+            },
+        )
+    }
+
+    fn assert_changed_options_applied(options: &NodeOptions) {
+        assert_eq!(
+            options.bypass_min_fee_msg_types(),
+            vec!["/ibc.core.client.v1.MsgUpdateClient".to_owned()]
+        );
+        assert_eq!(options.max_total_bypass_min_fee_msg_gas_usage(), 300_000);
+    }
+
+    #[test]
+    fn unchanged_config_is_not_reloaded() {
+        let file = ConfigFile::new("unchanged", CONFIG);
+        let mut reloader = reloader(&file, false);
+
+        assert_eq!(reloader.reload(), Ok(false));
+    }
+
+    #[test]
+    fn changed_options_are_applied() {
+        let file = ConfigFile::new("changed", CONFIG);
+        let mut reloader = reloader(&file, false);
+
+        // rewritten right away, most likely within the same second
+        file.write(CHANGED_CONFIG);
+        assert_eq!(reloader.reload(), Ok(true));
+
+        assert_eq!(
+            reloader.options.min_gas_prices(),
+            "2uatom".parse().expect("hard coded gas price is valid")
+        );
+        assert_changed_options_applied(&reloader.options);
+        assert_eq!(reloader.reload(), Ok(false));
+
+        file.write(CONFIG);
+        assert_eq!(reloader.reload(), Ok(true));
+        assert_eq!(
+            reloader.options.max_total_bypass_min_fee_msg_gas_usage(),
+            200_000
+        );
+    }
+
+    #[test]
+    fn min_gas_prices_of_command_line_are_kept() {
+        let file = ConfigFile::new("overridden", CONFIG);
+        let mut reloader = reloader(&file, true);
+
+        file.write(CHANGED_CONFIG);
+        assert_eq!(reloader.reload(), Ok(true));
+
+        assert_eq!(
+            reloader.options.min_gas_prices(),
+            "1uatom".parse().expect("hard coded gas price is valid")
+        );
+        assert_changed_options_applied(&reloader.options);
+    }
+
+    #[test]
+    fn partially_written_config_is_loaded_once_complete() {
+        let file = ConfigFile::new("partial", CONFIG);
+        let mut reloader = reloader(&file, false);
+
+        let partial = &CHANGED_CONFIG[..CHANGED_CONFIG.len() / 2];
+        file.write(partial);
+        assert!(reloader.reload().is_err());
+        // reported once, the options are kept
+        assert_eq!(reloader.reload(), Ok(false));
+        assert_eq!(
+            reloader.options.max_total_bypass_min_fee_msg_gas_usage(),
+            200_000
+        );
+
+        file.write(CHANGED_CONFIG);
+        assert_eq!(reloader.reload(), Ok(true));
+        assert_changed_options_applied(&reloader.options);
+    }
 }
@@ -9,6 +9,7 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tendermint::rpc::url::Url;
 
+use crate::commands::node::run::AbciVersion;
 use crate::defaults::{CLIENT_CONFIG_FILE_NAME, CONFIG_DIR, CONFIG_FILE_NAME, GENESIS_FILE_NAME};
 use crate::types::base::min_gas::MinGasPrices;
 
@@ -18,6 +19,7 @@ pub const DEFAULT_REST_LISTEN_ADDR: SocketAddr =
 pub const DEFAULT_ADDRESS: SocketAddr =
     SocketAddr::new(std::net::IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 26658);
 pub const DEFAULT_TENDERMINT_RPC_ADDRESS: &str = "http://localhost:26657";
+pub const DEFAULT_SNAPSHOT_KEEP_RECENT: u32 = 2;
 
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub enum ConfigDirectory {
@@ -40,7 +42,17 @@ impl ConfigDirectory {
     }
 }
 
-pub trait ApplicationConfig: Serialize + DeserializeOwned + Default + Clone {}
+pub trait ApplicationConfig: Serialize + DeserializeOwned + Default + Clone {
+    /// Type URLs of messages which are exempt from the minimum gas prices in CheckTx
+    fn bypass_min_fee_msg_types(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Maximum gas limit of txs whose messages are exempt from the minimum gas prices
+    fn max_total_bypass_min_fee_msg_gas_usage(&self) -> u64 {
+        0
+    }
+}
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
 #[serde(deny_unknown_fields)]
@@ -51,6 +63,18 @@ pub struct Config<AC: Default + Clone> {
     pub grpc_listen_addr: SocketAddr,
     pub address: SocketAddr,
     pub min_gas_prices: Option<MinGasPrices>,
+    /// Block interval at which state sync snapshots are taken, 0 disables snapshots
+    pub snapshot_interval: u32,
+    /// Number of recent snapshots to keep on disk
+    pub snapshot_keep_recent: u32,
+    /// Minimum number of recent blocks tendermint should keep, 0 keeps all blocks
+    pub min_retain_blocks: u64,
+    /// Block height at which the node commits and then stops, 0 disables halting
+    pub halt_height: u32,
+    /// Unix time in seconds after which the node stops on the next commit, 0 disables halting
+    pub halt_time: u64,
+    /// Version of the ABCI protocol of the tendermint node
+    pub abci_version: AbciVersion,
     pub app_config: AC,
 }
 
@@ -92,6 +116,12 @@ impl<AC: ApplicationConfig> Config<AC> {
             grpc_listen_addr: self.grpc_listen_addr.to_owned(),
             address: self.address.to_owned(),
             min_gas_prices: self.min_gas_prices.to_owned(),
+            snapshot_interval: self.snapshot_interval,
+            snapshot_keep_recent: self.snapshot_keep_recent,
+            min_retain_blocks: self.min_retain_blocks,
+            halt_height: self.halt_height,
+            halt_time: self.halt_time,
+            abci_version: self.abci_version,
             app_config: AC::default(),
         }
     }
@@ -108,6 +138,12 @@ impl<AC: ApplicationConfig> Default for Config<AC> {
             app_config: AC::default(),
             min_gas_prices: None,
             grpc_listen_addr: DEFAULT_GRPC_LISTEN_ADDR,
+            snapshot_interval: 0,
+            snapshot_keep_recent: DEFAULT_SNAPSHOT_KEEP_RECENT,
+            min_retain_blocks: 0,
+            halt_height: 0,
+            halt_time: 0,
+            abci_version: AbciVersion::default(),
         }
     }
 }
@@ -131,5 +167,30 @@ grpc_listen_addr = "{{grpc_listen_addr}}"
 # Tendermint node RPC proxy address
 tendermint_rpc_address = "{{tendermint_rpc_address}}"
 
+# Minimum gas prices a validator accepts for processing a transaction, e.g. "0.01uatom".
+# Changes are applied while the node runs unless the value is set on the command line.
 min_gas_prices = "{{min_gas_prices}}"
+
+# Minimum number of recent blocks to keep, older blocks are pruned by tendermint (0 to keep all).
+# Blocks still needed for evidence and state sync snapshots are always kept.
+min_retain_blocks = {{min_retain_blocks}}
+
+# Block height at which to gracefully halt the node after committing (0 to disable)
+halt_height = {{halt_height}}
+
+# Unix time in seconds after which to gracefully halt the node on the next commit (0 to disable)
+halt_time = {{halt_time}}
+
+# Version of the ABCI protocol of the tendermint node, "v0.38" for CometBFT 0.38 (ABCI++)
+abci_version = "{{abci_version}}"
+
+#######################################################################
+###                        State Sync Configuration                 ###
+#######################################################################
+
+# Block interval at which local state sync snapshots are taken (0 to disable)
+snapshot_interval = {{snapshot_interval}}
+
+# Number of recent snapshots to keep and serve (0 to keep all)
+snapshot_keep_recent = {{snapshot_keep_recent}}
 "#;
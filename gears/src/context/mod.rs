@@ -2,7 +2,11 @@ use database::prefix::PrefixDB;
 use kv_store::store::kv::{immutable::KVStore, mutable::KVStoreMut};
 use tendermint::types::{chain_id::ChainId, proto::event::Event, time::timestamp::Timestamp};
 
-use crate::types::store::kv::{mutable::StoreMut, Store};
+use crate::types::{
+    errors::TypedEventError,
+    event::TypedEvent,
+    store::kv::{mutable::StoreMut, Store},
+};
 
 pub mod block;
 pub mod init;
@@ -28,6 +32,13 @@ pub trait TransactionalContext<DB, SK>: QueryableContext<DB, SK> {
     fn append_events(&mut self, events: Vec<Event>);
     fn events_drain(&mut self) -> Vec<Event>;
 
+    /// Emit a typed event with JSON encoded attributes, see [`TypedEvent`]
+    fn emit_typed<E: TypedEvent>(&mut self, event: &E) -> Result<(), TypedEventError> {
+        self.push_event(event.to_event()?);
+
+        Ok(())
+    }
+
     /// Public interface for getting context timestamp. Default implementation returns `None`.
     fn get_time(&self) -> Timestamp;
     ///  Fetches an mutable ref to a KVStore from the MultiStore.
@@ -40,3 +51,100 @@ pub trait InfallibleContextMut<DB, SK>:
     ///  Fetches an mutable ref to a KVStore from the MultiStore.
     fn infallible_store_mut(&mut self, store_key: &SK) -> KVStoreMut<'_, PrefixDB<DB>>;
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+    use key_derive::{ParamsKeys, StoreKeys};
+    use kv_store::bank::multi::ApplicationMultiBank;
+    use serde::Serialize;
+    use tendermint::types::proto::{event::EventAttribute, header::Header};
+
+    use super::*;
+    use crate::{
+        baseapp::{options::NodeOptions, ConsensusParams},
+        context::{init::InitContext, tx::TxContext},
+        derive::TypedEvent,
+        types::gas::{infinite_meter::InfiniteGasMeter, GasMeter},
+    };
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys, ParamsKeys)]
+    #[skey(params = Params, gears)]
+    #[pkey(gears)]
+    enum SubspaceKey {
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "params")]
+        Params,
+    }
+
+    #[derive(Serialize, TypedEvent)]
+    #[event(gears, kind = "cosmos.gov.v1beta1.EventVote")]
+    struct EventVote {
+        proposal_id: u64,
+        #[event(no_index)]
+        voter: String,
+    }
+
+    fn event_vote() -> EventVote {
+        EventVote {
+            proposal_id: 7,
+            voter: "cosmos1voter".to_owned(),
+        }
+    }
+
+    fn expected_event() -> Event {
+        Event::new(
+            "cosmos.gov.v1beta1.EventVote",
+            [
+                EventAttribute::new("proposal_id".into(), "7".into(), true),
+                EventAttribute::new("voter".into(), "\"cosmos1voter\"".into(), false),
+            ],
+        )
+    }
+
+    #[test]
+    fn emit_typed_in_init_context() {
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = InitContext::new(
+            &mut multi_store,
+            0,
+            Timestamp::UNIX_EPOCH,
+            ChainId::default(),
+            ConsensusParams::default(),
+        );
+
+        ctx.emit_typed(&event_vote()).unwrap_test();
+
+        assert_eq!(ctx.events_drain(), vec![expected_event()]);
+    }
+
+    #[test]
+    fn emit_typed_in_tx_context() {
+        let multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut tx_multi_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::new(Box::<InfiniteGasMeter>::default());
+        let mut ctx = TxContext::new(
+            &mut tx_multi_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::new(Box::<InfiniteGasMeter>::default()),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        let message = Event::new(
+            "message",
+            [EventAttribute::new("module".into(), "gov".into(), false)],
+        );
+        ctx.push_event(message.clone());
+        ctx.emit_typed(&event_vote()).unwrap_test();
+
+        assert_eq!(ctx.events_drain(), vec![message, expected_event()]);
+    }
+}
@@ -1,12 +1,11 @@
 use address::AccAddress;
 use core_types::Protobuf;
+use ed25519_consensus::{Signature, VerificationKey};
 use keyring::error::DecodeError;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
-use super::public::SigningError;
-
-//TODO: this module is not a full implementation
+use super::errors::SigningError;
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Ed25519PubKey {
@@ -17,10 +16,12 @@ pub struct Ed25519PubKey {
 impl Ed25519PubKey {
     pub fn verify_signature(
         &self,
-        _message: impl AsRef<[u8]>,
-        _signature: impl AsRef<[u8]>,
+        message: impl AsRef<[u8]>,
+        signature: impl AsRef<[u8]>,
     ) -> Result<(), SigningError> {
-        todo!()
+        let key = VerificationKey::try_from(self.key.key.as_slice())?;
+        let signature = Signature::try_from(signature.as_ref())?;
+        Ok(key.verify(&signature, message.as_ref())?)
     }
 
     pub fn get_address(&self) -> AccAddress {
@@ -42,6 +43,9 @@ impl TryFrom<Vec<u8>> for Ed25519PubKey {
     type Error = DecodeError;
 
     fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        VerificationKey::try_from(value.as_slice())
+            .map_err(|e| DecodeError(format!("invalid key: {e}")))?;
+
         Ok(Ed25519PubKey {
             key: inner::Ed25519PubKey { key: value },
         })
@@ -55,7 +59,6 @@ impl From<Ed25519PubKey> for Vec<u8> {
 }
 
 mod inner {
-    // TODO: this isn't needed yet, but it probably will be once we have a proper implementation
     #[derive(Clone, PartialEq, ::prost::Message, ::serde::Serialize)]
     pub struct Ed25519PubKey {
         #[prost(bytes = "vec", tag = "1")]
@@ -67,7 +70,7 @@ impl TryFrom<inner::Ed25519PubKey> for Ed25519PubKey {
     type Error = DecodeError;
 
     fn try_from(raw: inner::Ed25519PubKey) -> Result<Self, Self::Error> {
-        Ok(Ed25519PubKey { key: raw })
+        raw.key.try_into()
     }
 }
 
@@ -79,7 +82,6 @@ impl From<Ed25519PubKey> for inner::Ed25519PubKey {
 
 impl Protobuf<inner::Ed25519PubKey> for Ed25519PubKey {}
 
-// TODO: these will be needed once we have a proper implementation:
 fn serialize_key<S>(key: &inner::Ed25519PubKey, s: S) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
@@ -111,6 +113,37 @@ impl<'de> serde::de::Visitor<'de> for Ed25519Visitor {
             .decode(v.as_bytes())
             .map_err(|e| E::custom(format!("Error parsing public key '{}': {}", v, e)))?;
 
+        VerificationKey::try_from(key.as_slice())
+            .map_err(|e| E::custom(format!("Error parsing public key '{}': {}", v, e)))?;
+
         Ok(inner::Ed25519PubKey { key })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ed25519_consensus::SigningKey;
+
+    use super::*;
+
+    #[test]
+    fn verify_signature_works() -> Result<(), SigningError> {
+        let signing_key = SigningKey::from([7; 32]);
+        let key: Ed25519PubKey = signing_key
+            .verification_key()
+            .to_bytes()
+            .to_vec()
+            .try_into()
+            .expect("key is valid");
+
+        let message = b"hello world";
+        let signature = signing_key.sign(message);
+
+        key.verify_signature(message, signature.to_bytes())?;
+        assert!(key
+            .verify_signature(b"goodbye world", signature.to_bytes())
+            .is_err());
+
+        Ok(())
+    }
+}
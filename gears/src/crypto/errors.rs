@@ -5,3 +5,17 @@ pub enum VerifyError {
     #[error("{0}")]
     K256(#[from] k256::ecdsa::Error),
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum SigningError {
+    #[error("{0}")]
+    Secp256k1(#[from] secp256k1::Error),
+    #[error("{0}")]
+    Ed25519(#[from] ed25519_consensus::Error),
+    #[error("{0}")]
+    Secp256r1(#[from] p256::ecdsa::Error),
+    #[error("mode info doesn't match the public key")]
+    ModeInfoMismatch,
+    #[error("invalid multisignature: {0}")]
+    Multisig(String),
+}
@@ -4,5 +4,7 @@ pub mod errors;
 pub mod info;
 pub mod keys;
 pub mod ledger;
+pub mod multisig;
 pub mod public;
 pub mod secp256k1;
+pub mod secp256r1;
@@ -0,0 +1,477 @@
+//! Threshold multisig keys, see `LegacyAminoPubKey` of the cosmos sdk.
+
+use address::AccAddress;
+use bytes::Bytes;
+use core_types::any::google::Any;
+use core_types::tx::mode_info::{CompactBitArray, ModeInfo, Multi, SignMode};
+use core_types::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{
+    errors::SigningError,
+    public::{DecodeError, PublicKey},
+};
+
+pub const LEGACY_AMINO_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.multisig.LegacyAminoPubKey";
+
+/// Multisig keys are decoded recursively, nesting is limited to keep the stack bounded
+const MAX_NESTING_DEPTH: usize = 10;
+
+/// Amino prefix of `tendermint/PubKeyMultisigThreshold`
+const AMINO_PREFIX: [u8; 4] = [0x22, 0xc1, 0xf7, 0xe2];
+
+/// Public key of a multisig account, which requires the signatures of at least
+/// `threshold` of its keys.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedLegacyAminoPubKey")]
+pub struct LegacyAminoPubKey {
+    threshold: u32,
+    public_keys: Vec<PublicKey>,
+    /// Amino binary encoding of the key, which is hashed into the address
+    #[serde(skip)]
+    amino_bytes: Vec<u8>,
+}
+
+impl LegacyAminoPubKey {
+    pub fn new(threshold: u32, public_keys: Vec<PublicKey>) -> Result<Self, DecodeError> {
+        if threshold == 0 {
+            return Err(DecodeError("threshold must be greater than 0".to_string()));
+        }
+
+        if public_keys.len() < threshold as usize {
+            return Err(DecodeError(format!(
+                "threshold k of n multisignature: len(pubkeys) < threshold; {} < {threshold}",
+                public_keys.len()
+            )));
+        }
+
+        // the address of multisig keys is derived from the amino encoding of their keys,
+        // which fails for keys without one
+        let mut amino_bytes = AMINO_PREFIX.to_vec();
+
+        amino_bytes.push(0x08); // field 1, varint
+        prost::encoding::encode_varint(threshold.into(), &mut amino_bytes);
+
+        for key in &public_keys {
+            let key_bytes = key.amino_bytes()?;
+            amino_bytes.push(0x12); // field 2, length delimited
+            prost::encoding::encode_varint(key_bytes.len() as u64, &mut amino_bytes);
+            amino_bytes.extend(key_bytes);
+        }
+
+        Ok(Self {
+            threshold,
+            public_keys,
+            amino_bytes,
+        })
+    }
+
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    pub fn public_keys(&self) -> &[PublicKey] {
+        &self.public_keys
+    }
+
+    /// Returns the keys which signed according to `bitarray`
+    pub fn signers<'a>(
+        &'a self,
+        bitarray: &'a CompactBitArray,
+    ) -> impl Iterator<Item = &'a PublicKey> + 'a {
+        self.public_keys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| bitarray.get_index(*i))
+            .map(|(_, key)| key)
+    }
+
+    pub fn get_address(&self) -> AccAddress {
+        let mut hasher = Sha256::new();
+        hasher.update(self.amino_bytes());
+        let hash = hasher.finalize();
+
+        hash[..20]
+            .try_into()
+            .expect("the slice is 20 bytes long which is less than AccAddress::MAX_ADDR_LEN")
+    }
+
+    /// Amino binary encoding of the key, which is hashed into the address
+    pub(crate) fn amino_bytes(&self) -> &[u8] {
+        &self.amino_bytes
+    }
+
+    /// Verifies a multisignature, which is made of the signatures of the keys set in the
+    /// bit array of `multi`. Signatures of the keys are verified with their own mode info
+    /// and sign bytes returned by `sign_bytes` for it.
+    pub fn verify_multisignature<E: From<SigningError>>(
+        &self,
+        multi: &Multi,
+        signature: &[u8],
+        sign_bytes: &mut impl FnMut(&SignMode) -> Result<Vec<u8>, E>,
+    ) -> Result<(), E> {
+        let bitarray = multi
+            .bitarray
+            .as_ref()
+            .ok_or(SigningError::Multisig("bit array is missing".to_string()))?;
+
+        if bitarray.count() != self.public_keys.len() {
+            Err(SigningError::Multisig(format!(
+                "bit array size is incorrect, expected {}, got {}",
+                self.public_keys.len(),
+                bitarray.count()
+            )))?
+        }
+
+        let MultiSignature { signatures } =
+            MultiSignature::decode(signature).map_err(|e| SigningError::Multisig(e.to_string()))?;
+
+        let signers = self.signers(bitarray).collect::<Vec<_>>();
+
+        if signatures.len() != signers.len() || multi.mode_infos.len() != signers.len() {
+            Err(SigningError::Multisig(format!(
+                "number of signatures and mode infos doesn't match the bit array; expected {}, got {} and {}",
+                signers.len(),
+                signatures.len(),
+                multi.mode_infos.len()
+            )))?
+        }
+
+        if signatures.len() < self.threshold as usize {
+            Err(SigningError::Multisig(format!(
+                "not enough signatures; expected at least {}, got {}",
+                self.threshold,
+                signatures.len()
+            )))?
+        }
+
+        for ((key, mode_info), signature) in
+            signers.into_iter().zip(&multi.mode_infos).zip(signatures)
+        {
+            key.verify_signature_data(mode_info, &signature, sign_bytes)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct UncheckedLegacyAminoPubKey {
+    threshold: u32,
+    public_keys: Vec<PublicKey>,
+}
+
+impl TryFrom<UncheckedLegacyAminoPubKey> for LegacyAminoPubKey {
+    type Error = DecodeError;
+
+    fn try_from(
+        UncheckedLegacyAminoPubKey {
+            threshold,
+            public_keys,
+        }: UncheckedLegacyAminoPubKey,
+    ) -> Result<Self, Self::Error> {
+        Self::new(threshold, public_keys)
+    }
+}
+
+/// Signatures of the keys of a multisig key, which are encoded as the signature of the
+/// multisig signer. Keys which signed are set in the bit array of [`ModeInfo::Multi`].
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultiSignature {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub signatures: Vec<Vec<u8>>,
+}
+
+/// Combines the signatures of keys of `key` into the mode info and signature of the
+/// multisig signer. Every signature is paired with the key which made it.
+pub fn combine_signatures(
+    key: &LegacyAminoPubKey,
+    signatures: Vec<(PublicKey, ModeInfo, Vec<u8>)>,
+) -> Result<(ModeInfo, Vec<u8>), SigningError> {
+    let mut indexed = Vec::with_capacity(signatures.len());
+    for (public_key, mode_info, signature) in signatures {
+        let index = key
+            .public_keys
+            .iter()
+            .position(|this| this == &public_key)
+            .ok_or(SigningError::Multisig(format!(
+                "key {} doesn't belong to the multisig key",
+                public_key.get_address()
+            )))?;
+
+        if indexed.iter().any(|(this, _, _)| *this == index) {
+            Err(SigningError::Multisig(format!(
+                "duplicate signature of key {}",
+                public_key.get_address()
+            )))?
+        }
+
+        indexed.push((index, mode_info, signature));
+    }
+
+    if indexed.len() < key.threshold as usize {
+        Err(SigningError::Multisig(format!(
+            "not enough signatures; expected at least {}, got {}",
+            key.threshold,
+            indexed.len()
+        )))?
+    }
+
+    indexed.sort_by_key(|(index, _, _)| *index);
+
+    let mut bitarray = CompactBitArray::new(key.public_keys.len());
+    let mut mode_infos = Vec::with_capacity(indexed.len());
+    let mut signatures = Vec::with_capacity(indexed.len());
+    for (index, mode_info, signature) in indexed {
+        bitarray.set_index(index, true);
+        mode_infos.push(mode_info);
+        signatures.push(signature);
+    }
+
+    Ok((
+        ModeInfo::Multi(Multi {
+            bitarray: Some(bitarray),
+            mode_infos,
+        }),
+        MultiSignature { signatures }.encode_to_vec(),
+    ))
+}
+
+mod inner {
+    use core_types::any::google::Any;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct LegacyAminoPubKey {
+        #[prost(uint32, tag = "1")]
+        pub threshold: u32,
+        #[prost(message, repeated, tag = "2")]
+        pub public_keys: Vec<Any>,
+    }
+}
+
+impl LegacyAminoPubKey {
+    fn from_raw(raw: inner::LegacyAminoPubKey, depth: usize) -> Result<Self, DecodeError> {
+        if depth > MAX_NESTING_DEPTH {
+            return Err(DecodeError(format!(
+                "multisig keys are nested deeper than {MAX_NESTING_DEPTH} levels"
+            )));
+        }
+
+        let public_keys = raw
+            .public_keys
+            .into_iter()
+            .map(|any| match any.type_url.as_str() {
+                LEGACY_AMINO_PUB_KEY_TYPE_URL => {
+                    let raw = inner::LegacyAminoPubKey::decode(any.value.as_slice())
+                        .map_err(|e| DecodeError(e.to_string()))?;
+                    Ok(PublicKey::Multisig(Self::from_raw(raw, depth + 1)?))
+                }
+                _ => PublicKey::try_from(any),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(raw.threshold, public_keys)
+    }
+}
+
+impl TryFrom<inner::LegacyAminoPubKey> for LegacyAminoPubKey {
+    type Error = DecodeError;
+
+    fn try_from(raw: inner::LegacyAminoPubKey) -> Result<Self, Self::Error> {
+        Self::from_raw(raw, 0)
+    }
+}
+
+impl From<LegacyAminoPubKey> for inner::LegacyAminoPubKey {
+    fn from(key: LegacyAminoPubKey) -> Self {
+        Self {
+            threshold: key.threshold,
+            public_keys: key.public_keys.into_iter().map(Any::from).collect(),
+        }
+    }
+}
+
+impl Protobuf<inner::LegacyAminoPubKey> for LegacyAminoPubKey {}
+
+impl TryFrom<Vec<u8>> for LegacyAminoPubKey {
+    type Error = DecodeError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::decode::<Bytes>(value.into()).map_err(|e| DecodeError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_consensus::SigningKey;
+
+    use super::*;
+
+    fn signing_keys() -> Vec<SigningKey> {
+        (1..=3).map(|i| SigningKey::from([i; 32])).collect()
+    }
+
+    fn multisig_key(keys: &[SigningKey]) -> LegacyAminoPubKey {
+        let public_keys = keys
+            .iter()
+            .map(|key| {
+                PublicKey::Ed25519(
+                    key.verification_key()
+                        .to_bytes()
+                        .to_vec()
+                        .try_into()
+                        .expect("key is valid"),
+                )
+            })
+            .collect();
+
+        LegacyAminoPubKey::new(2, public_keys).expect("threshold is valid")
+    }
+
+    fn sign(
+        multisig: &LegacyAminoPubKey,
+        keys: &[SigningKey],
+        signers: &[usize],
+        message: &[u8],
+    ) -> Result<(ModeInfo, Vec<u8>), SigningError> {
+        combine_signatures(
+            multisig,
+            signers
+                .iter()
+                .map(|i| {
+                    (
+                        multisig.public_keys()[*i].clone(),
+                        ModeInfo::Single(SignMode::LegacyAminoJson),
+                        keys[*i].sign(message).to_bytes().to_vec(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn new_checks_threshold() {
+        let keys = multisig_key(&signing_keys()).public_keys;
+
+        assert!(LegacyAminoPubKey::new(0, keys.clone()).is_err());
+        assert!(LegacyAminoPubKey::new(4, keys.clone()).is_err());
+        assert!(LegacyAminoPubKey::new(3, keys).is_ok());
+    }
+
+    #[test]
+    fn new_rejects_secp256r1_keys() {
+        let signing_key = p256::ecdsa::SigningKey::from_slice(&[7; 32]).expect("key is valid");
+        let r1_key = PublicKey::Secp256r1(
+            signing_key
+                .verifying_key()
+                .to_encoded_point(true)
+                .as_bytes()
+                .to_vec()
+                .try_into()
+                .expect("key is valid"),
+        );
+
+        let mut keys = multisig_key(&signing_keys()).public_keys;
+        keys.push(r1_key);
+        assert_eq!(
+            LegacyAminoPubKey::new(1, keys),
+            Err(DecodeError(
+                "secp256r1 keys can't be keys of multisig keys".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn count_sub_keys_counts_nested_keys() {
+        let keys = signing_keys();
+        let inner = multisig_key(&keys);
+        let mut public_keys = inner.public_keys.clone();
+        public_keys.push(PublicKey::Multisig(inner));
+
+        let outer = PublicKey::Multisig(
+            LegacyAminoPubKey::new(1, public_keys).expect("threshold is valid"),
+        );
+
+        assert_eq!(outer.count_sub_keys(), 6);
+    }
+
+    #[test]
+    fn protobuf_and_json_round_trip() {
+        let key = multisig_key(&signing_keys());
+
+        let decoded: LegacyAminoPubKey = key.encode_vec().try_into().expect("key is valid");
+        assert_eq!(decoded, key);
+
+        let json = serde_json::to_string(&PublicKey::Multisig(key.clone())).expect("key is valid");
+        assert!(json.starts_with(r#"{"@type":"/cosmos.crypto.multisig.LegacyAminoPubKey","threshold":2,"public_keys":[{"@type":"/cosmos.crypto.ed25519.PubKey""#));
+        assert_eq!(
+            serde_json::from_str::<PublicKey>(&json).expect("key is valid"),
+            PublicKey::Multisig(key)
+        );
+
+        assert!(serde_json::from_str::<PublicKey>(
+            r#"{"@type":"/cosmos.crypto.multisig.LegacyAminoPubKey","threshold":0,"public_keys":[]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn verify_multisignature_works() {
+        let keys = signing_keys();
+        let multisig = PublicKey::Multisig(multisig_key(&keys));
+        let PublicKey::Multisig(ref key) = multisig else {
+            unreachable!()
+        };
+        let message = b"hello world";
+        let mut sign_bytes = |_: &SignMode| Ok::<_, SigningError>(message.to_vec());
+
+        let (mode_info, signature) = sign(key, &keys, &[2, 0], message).expect("enough signers");
+        let ModeInfo::Multi(ref multi) = mode_info else {
+            panic!("multisig mode info expected")
+        };
+        let bitarray = multi.bitarray.as_ref().expect("bit array is set");
+        assert_eq!(bitarray.count(), 3);
+        assert_eq!(bitarray.num_true_bits(), 2);
+        assert!(bitarray.get_index(0) && !bitarray.get_index(1) && bitarray.get_index(2));
+
+        multisig
+            .verify_signature_data(&mode_info, &signature, &mut sign_bytes)
+            .expect("signatures are valid");
+
+        // signature of another message
+        let (_, other_signature) =
+            sign(key, &keys, &[0, 2], b"goodbye world").expect("enough signers");
+        assert!(multisig
+            .verify_signature_data(&mode_info, &other_signature, &mut sign_bytes)
+            .is_err());
+
+        // too few signatures
+        assert!(sign(key, &keys, &[1], message).is_err());
+        let mut below_threshold = CompactBitArray::new(3);
+        below_threshold.set_index(1, true);
+        let single = MultiSignature {
+            signatures: vec![keys[1].sign(message).to_bytes().to_vec()],
+        };
+        assert!(multisig
+            .verify_signature_data(
+                &ModeInfo::Multi(Multi {
+                    bitarray: Some(below_threshold),
+                    mode_infos: vec![ModeInfo::Single(SignMode::LegacyAminoJson)],
+                }),
+                &single.encode_to_vec(),
+                &mut sign_bytes,
+            )
+            .is_err());
+
+        // single mode info for a multisig key
+        assert!(multisig
+            .verify_signature_data(
+                &ModeInfo::Single(SignMode::LegacyAminoJson),
+                &signature,
+                &mut sign_bytes
+            )
+            .is_err());
+    }
+}
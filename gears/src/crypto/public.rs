@@ -1,12 +1,21 @@
 use address::AccAddress;
 use bytes::Bytes;
 use core_types::any::google::Any;
+use core_types::tx::mode_info::{ModeInfo, SignMode};
 use core_types::Protobuf;
 use serde::{Deserialize, Serialize};
 
-use super::{ed25519::Ed25519PubKey, secp256k1::Secp256k1PubKey};
+use super::{
+    ed25519::Ed25519PubKey,
+    errors::SigningError,
+    multisig::{LegacyAminoPubKey, LEGACY_AMINO_PUB_KEY_TYPE_URL},
+    secp256k1::Secp256k1PubKey,
+    secp256r1::{Secp256r1PubKey, SECP256R1_PUB_KEY_TYPE_URL},
+};
 
-pub type SigningError = secp256k1::Error;
+/// Amino prefixes of `tendermint/PubKeySecp256k1` and `tendermint/PubKeyEd25519`
+const SECP256K1_AMINO_PREFIX: [u8; 4] = [0xeb, 0x5a, 0xe9, 0x87];
+const ED25519_AMINO_PREFIX: [u8; 4] = [0x16, 0x24, 0xde, 0x64];
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
 #[error("invalid key: {0}")]
@@ -17,10 +26,12 @@ pub struct DecodeError(pub String);
 pub enum PublicKey {
     #[serde(rename = "/cosmos.crypto.secp256k1.PubKey")]
     Secp256k1(Secp256k1PubKey),
-    //Secp256r1(Vec<u8>),
+    #[serde(rename = "/cosmos.crypto.secp256r1.PubKey")]
+    Secp256r1(Secp256r1PubKey),
     #[serde(rename = "/cosmos.crypto.ed25519.PubKey")]
     Ed25519(Ed25519PubKey),
-    //Multisig(Vec<u8>),
+    #[serde(rename = "/cosmos.crypto.multisig.LegacyAminoPubKey")]
+    Multisig(LegacyAminoPubKey),
 }
 
 impl PublicKey {
@@ -31,16 +42,74 @@ impl PublicKey {
     ) -> Result<(), SigningError> {
         match self {
             PublicKey::Secp256k1(key) => key.verify_signature(message, signature),
+            PublicKey::Secp256r1(key) => key.verify_signature(message, signature),
             PublicKey::Ed25519(key) => key.verify_signature(message, signature),
+            // multisig keys verify the signatures of their keys, see `verify_signature_data`
+            PublicKey::Multisig(_) => Err(SigningError::ModeInfoMismatch),
+        }
+    }
+
+    /// Verifies the signature of a signer with its mode info. `sign_bytes` returns the
+    /// signed bytes for a sign mode, multisig keys call it for the mode of every key which signed.
+    pub fn verify_signature_data<E: From<SigningError>>(
+        &self,
+        mode_info: &ModeInfo,
+        signature: &[u8],
+        sign_bytes: &mut impl FnMut(&SignMode) -> Result<Vec<u8>, E>,
+    ) -> Result<(), E> {
+        match (self, mode_info) {
+            (PublicKey::Multisig(key), ModeInfo::Multi(multi)) => {
+                key.verify_multisignature(multi, signature, sign_bytes)
+            }
+            (PublicKey::Multisig(_), ModeInfo::Single(_)) | (_, ModeInfo::Multi(_)) => {
+                Err(SigningError::ModeInfoMismatch.into())
+            }
+            (key, ModeInfo::Single(mode)) => {
+                Ok(key.verify_signature(sign_bytes(mode)?, signature)?)
+            }
+        }
+    }
+
+    /// Number of keys which make up the key, keys of nested multisig keys are counted too
+    pub fn count_sub_keys(&self) -> usize {
+        match self {
+            PublicKey::Multisig(key) => key
+                .public_keys()
+                .iter()
+                .map(PublicKey::count_sub_keys)
+                .sum(),
+            PublicKey::Secp256k1(_) | PublicKey::Secp256r1(_) | PublicKey::Ed25519(_) => 1,
         }
     }
 
     pub fn get_address(&self) -> AccAddress {
         match self {
             PublicKey::Secp256k1(key) => key.get_address(),
+            PublicKey::Secp256r1(key) => key.get_address(),
             PublicKey::Ed25519(key) => key.get_address(),
+            PublicKey::Multisig(key) => key.get_address(),
         }
     }
+
+    /// Amino binary encoding of the key, which is used for the addresses of multisig keys.
+    /// Secp256r1 keys have no amino encoding, so they can't be keys of multisig keys.
+    pub(crate) fn amino_bytes(&self) -> Result<Vec<u8>, DecodeError> {
+        let (prefix, key) = match self {
+            PublicKey::Secp256k1(key) => (SECP256K1_AMINO_PREFIX, Vec::from(key.clone())),
+            PublicKey::Secp256r1(_) => {
+                return Err(DecodeError(
+                    "secp256r1 keys can't be keys of multisig keys".to_string(),
+                ))
+            }
+            PublicKey::Ed25519(key) => (ED25519_AMINO_PREFIX, Vec::from(key.clone())),
+            PublicKey::Multisig(key) => return Ok(key.amino_bytes().to_vec()),
+        };
+
+        let mut bytes = prefix.to_vec();
+        prost::encoding::encode_varint(key.len() as u64, &mut bytes);
+        bytes.extend(key);
+        Ok(bytes)
+    }
 }
 
 impl TryFrom<Any> for PublicKey {
@@ -53,11 +122,21 @@ impl TryFrom<Any> for PublicKey {
                     .map_err(|e| DecodeError(e.to_string()))?;
                 Ok(Self::Secp256k1(key))
             }
+            SECP256R1_PUB_KEY_TYPE_URL => {
+                let key = Secp256r1PubKey::decode::<Bytes>(any.value.into())
+                    .map_err(|e| DecodeError(e.to_string()))?;
+                Ok(Self::Secp256r1(key))
+            }
             "/cosmos.crypto.ed25519.PubKey" => {
                 let key = Ed25519PubKey::decode::<Bytes>(any.value.into())
                     .map_err(|e| DecodeError(e.to_string()))?;
                 Ok(Self::Ed25519(key))
             }
+            LEGACY_AMINO_PUB_KEY_TYPE_URL => {
+                let key = LegacyAminoPubKey::decode::<Bytes>(any.value.into())
+                    .map_err(|e| DecodeError(e.to_string()))?;
+                Ok(Self::Multisig(key))
+            }
 
             _ => Err(DecodeError(format!(
                 "Key type not recognized: {}",
@@ -74,10 +153,18 @@ impl From<PublicKey> for Any {
                 type_url: "/cosmos.crypto.secp256k1.PubKey".to_string(),
                 value: key.encode_vec(),
             },
+            PublicKey::Secp256r1(key) => Any {
+                type_url: SECP256R1_PUB_KEY_TYPE_URL.to_string(),
+                value: key.encode_vec(),
+            },
             PublicKey::Ed25519(key) => Any {
                 type_url: "/cosmos.crypto.ed25519.PubKey".to_string(),
                 value: key.encode_vec(),
             },
+            PublicKey::Multisig(key) => Any {
+                type_url: LEGACY_AMINO_PUB_KEY_TYPE_URL.to_string(),
+                value: key.encode_vec(),
+            },
         }
     }
 }
@@ -98,25 +185,35 @@ impl From<TendermintPublicKey> for PublicKey {
     }
 }
 
-impl From<InformalPublicKey> for PublicKey {
-    fn from(key: InformalPublicKey) -> Self {
+impl TryFrom<InformalPublicKey> for PublicKey {
+    type Error = DecodeError;
+
+    fn try_from(key: InformalPublicKey) -> Result<Self, Self::Error> {
         match key {
             InformalPublicKey::Ed25519(value) => {
-                PublicKey::Ed25519(value.as_bytes().to_owned().try_into().unwrap())
+                let key = Ed25519PubKey::try_from(value.as_bytes().to_owned())
+                    .map_err(|e| DecodeError(e.0))?;
+                Ok(PublicKey::Ed25519(key))
             }
-            // TODO: under feature secp256k1
-            _ => todo!(), // InformalPublicKey::Secp256k1(value) => {
-                          //     PublicKey::Secp256k1(value.as_bytes().to_owned().try_into().unwrap())
-                          // }
+            // secp256k1 keys are only available under the `secp256k1` feature of tendermint
+            _ => Err(DecodeError("unsupported public key type".to_string())),
         }
     }
 }
 
-impl From<PublicKey> for TendermintPublicKey {
-    fn from(key: PublicKey) -> Self {
+impl TryFrom<PublicKey> for TendermintPublicKey {
+    type Error = DecodeError;
+
+    fn try_from(key: PublicKey) -> Result<Self, Self::Error> {
         match key {
-            PublicKey::Ed25519(value) => TendermintPublicKey::Ed25519(value.into()),
-            PublicKey::Secp256k1(value) => TendermintPublicKey::Secp256k1(value.into()),
+            PublicKey::Ed25519(value) => Ok(TendermintPublicKey::Ed25519(value.into())),
+            PublicKey::Secp256k1(value) => Ok(TendermintPublicKey::Secp256k1(value.into())),
+            PublicKey::Secp256r1(_) => Err(DecodeError(
+                "secp256r1 keys can't be used as tendermint keys".to_string(),
+            )),
+            PublicKey::Multisig(_) => Err(DecodeError(
+                "multisig keys can't be used as tendermint keys".to_string(),
+            )),
         }
     }
 }
@@ -8,7 +8,7 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use sha2::{Digest, Sha256};
 use std::fmt;
 
-use super::public::SigningError;
+use super::errors::SigningError;
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Secp256k1PubKey {
@@ -0,0 +1,220 @@
+use address::AccAddress;
+use core_types::Protobuf;
+use keyring::error::DecodeError;
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::errors::SigningError;
+
+pub const SECP256R1_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256r1.PubKey";
+
+/// Key type which is hashed into the address, `secp256r1.name` of the cosmos sdk
+const KEY_TYPE: &str = "secp256r1";
+
+/// Length of a compressed secp256r1 public key
+const PUB_KEY_SIZE: usize = 33;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Secp256r1PubKey {
+    #[serde(serialize_with = "serialize_key", deserialize_with = "deserialize_key")]
+    key: inner::Secp256r1PubKey,
+}
+
+impl Secp256r1PubKey {
+    /// Verifies an ECDSA signature over the SHA-256 digest of `message`. Like the cosmos sdk
+    /// only signatures in the 64 byte `r || s` format with a low `s` are accepted.
+    pub fn verify_signature(
+        &self,
+        message: impl AsRef<[u8]>,
+        signature: impl AsRef<[u8]>,
+    ) -> Result<(), SigningError> {
+        let key = VerifyingKey::from_sec1_bytes(&self.key.key)?;
+        let signature = Signature::from_slice(signature.as_ref())?;
+        if signature.normalize_s().is_some() {
+            return Err(SigningError::Secp256r1(p256::ecdsa::Error::new()));
+        }
+
+        Ok(key.verify(message.as_ref(), &signature)?)
+    }
+
+    /// Address of the key, see `address.Hash` of the cosmos sdk
+    pub fn get_address(&self) -> AccAddress {
+        let type_hash = Sha256::digest(KEY_TYPE);
+
+        let mut hasher = Sha256::new();
+        hasher.update(type_hash);
+        hasher.update(&self.key.key);
+        let hash = hasher.finalize();
+
+        hash.as_slice()
+            .try_into()
+            .expect("sha256 digest size is 32 bytes which is less than AccAddress::MAX_ADDR_LEN")
+    }
+}
+
+fn validate_key(key: &[u8]) -> Result<(), String> {
+    if key.len() != PUB_KEY_SIZE {
+        return Err(format!(
+            "wrong key length, expected {PUB_KEY_SIZE}, got {}",
+            key.len()
+        ));
+    }
+
+    VerifyingKey::from_sec1_bytes(key).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+impl TryFrom<Vec<u8>> for Secp256r1PubKey {
+    type Error = DecodeError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        validate_key(&value).map_err(|e| DecodeError(format!("invalid key: {e}")))?;
+
+        Ok(Secp256r1PubKey {
+            key: inner::Secp256r1PubKey { key: value },
+        })
+    }
+}
+
+impl From<Secp256r1PubKey> for Vec<u8> {
+    fn from(key: Secp256r1PubKey) -> Vec<u8> {
+        key.key.key
+    }
+}
+
+mod inner {
+    #[derive(Clone, PartialEq, ::prost::Message, ::serde::Serialize)]
+    pub struct Secp256r1PubKey {
+        #[prost(bytes = "vec", tag = "1")]
+        pub key: Vec<u8>,
+    }
+}
+
+impl TryFrom<inner::Secp256r1PubKey> for Secp256r1PubKey {
+    type Error = DecodeError;
+
+    fn try_from(raw: inner::Secp256r1PubKey) -> Result<Self, Self::Error> {
+        raw.key.try_into()
+    }
+}
+
+impl From<Secp256r1PubKey> for inner::Secp256r1PubKey {
+    fn from(key: Secp256r1PubKey) -> inner::Secp256r1PubKey {
+        inner::Secp256r1PubKey { key: key.into() }
+    }
+}
+
+impl Protobuf<inner::Secp256r1PubKey> for Secp256r1PubKey {}
+
+fn serialize_key<S>(key: &inner::Secp256r1PubKey, s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    s.serialize_str(&data_encoding::BASE64.encode(&key.key))
+}
+
+fn deserialize_key<'de, D>(deserializer: D) -> Result<inner::Secp256r1PubKey, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_str(Secp256r1Visitor)
+}
+
+struct Secp256r1Visitor;
+
+impl<'de> serde::de::Visitor<'de> for Secp256r1Visitor {
+    type Value = inner::Secp256r1PubKey;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("string-encoded secp256r1 public key")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let key = data_encoding::BASE64
+            .decode(v.as_bytes())
+            .map_err(|e| E::custom(format!("Error parsing public key '{}': {}", v, e)))?;
+
+        validate_key(&key)
+            .map_err(|e| E::custom(format!("Error parsing public key '{}': {}", v, e)))?;
+
+        Ok(inner::Secp256r1PubKey { key })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p256::ecdsa::{signature::Signer, SigningKey};
+
+    use super::*;
+
+    fn key_pair() -> (SigningKey, Secp256r1PubKey) {
+        let signing_key = SigningKey::from_slice(&[7; 32]).expect("key is valid");
+        let key = signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec()
+            .try_into()
+            .expect("key is valid");
+
+        (signing_key, key)
+    }
+
+    #[test]
+    fn verify_signature_works() -> Result<(), SigningError> {
+        let (signing_key, key) = key_pair();
+
+        let message = b"hello world";
+        let signature: Signature = signing_key.sign(message);
+        let signature = signature.normalize_s().unwrap_or(signature);
+
+        key.verify_signature(message, signature.to_bytes())?;
+        assert!(key
+            .verify_signature(b"goodbye world", signature.to_bytes())
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn verify_signature_rejects_high_s() {
+        let (signing_key, key) = key_pair();
+
+        let message = b"hello world";
+        let signature: Signature = signing_key.sign(message);
+        let signature = signature.normalize_s().unwrap_or(signature);
+        let (r, s) = signature.split_scalars();
+        let high_s = Signature::from_scalars(r, -s).expect("scalars are valid");
+
+        assert!(key.verify_signature(message, high_s.to_bytes()).is_err());
+    }
+
+    #[test]
+    fn get_address_matches_cosmos_sdk() {
+        let (_, key) = key_pair();
+
+        // `address.Hash("secp256r1", key)` of the cosmos sdk for Ax4YUy/UdUwC8wQdnHXOszuD/9gax85P6ILMscmLxYlu
+        assert_eq!(
+            key.get_address().to_string(),
+            "cosmos1fpndzc29d2e8gd052026pthxw4nx2zjmr5g9p4stmf6kf39xjt2savkxqs"
+        );
+    }
+
+    #[test]
+    fn uncompressed_keys_are_rejected() {
+        let (signing_key, _) = key_pair();
+
+        let key = signing_key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+
+        assert!(Secp256r1PubKey::try_from(key).is_err());
+    }
+}
@@ -10,6 +10,7 @@ impl From<QueryError> for Status {
                 // we always query the latests version. Therefore, something has gone badly wrong if we get this error.
                 Status::internal("An internal error occurred while querying the application state.")
             }
+            QueryError::Simulate(err) => Status::invalid_argument(err.to_string()),
             _ => Status::invalid_argument("Invalid message."), // TODO: Don't forget to add more info later
         }
     }
@@ -5,23 +5,41 @@ use ibc_proto::cosmos::tx::v1beta1::{
     GetTxRequest, GetTxResponse, GetTxsEventRequest, GetTxsEventResponse, SimulateRequest,
     SimulateResponse,
 };
+use prost::Message;
+use std::marker::PhantomData;
 use tonic::{Request, Response, Status};
 use tracing::info;
 
-pub struct TxService;
+use crate::baseapp::{NodeQueryHandler, QueryRequest, QueryResponse};
+
+#[derive(Debug, Default)]
+pub struct TxService<QH, QReq, QRes> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
 
 #[tonic::async_trait]
-impl Service for TxService {
+impl<QReq: QueryRequest, QRes: QueryResponse, QH: NodeQueryHandler<QReq, QRes>> Service
+    for TxService<QH, QReq, QRes>
+{
     async fn simulate(
         &self,
-        _request: Request<SimulateRequest>,
+        request: Request<SimulateRequest>,
     ) -> Result<Response<SimulateResponse>, Status> {
         info!("Received a gRPC request tx::simulate");
-        // TODO: run simulation once implemented
-        Ok(Response::new(SimulateResponse {
-            gas_info: None,
-            result: None,
-        }))
+        let tx_bytes = request.into_inner().tx_bytes;
+        if tx_bytes.is_empty() {
+            return Err(Status::invalid_argument("empty tx bytes are not allowed"));
+        }
+
+        let response = self.app.simulate(tx_bytes.into())?;
+
+        // both responses share the same encoding, but the events of the ibc-proto
+        // response come from a different tendermint-proto version
+        let response = SimulateResponse::decode(response.encode_to_vec().as_slice())
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(response))
     }
 
     async fn get_tx(
@@ -57,6 +75,14 @@ impl Service for TxService {
     }
 }
 
-pub fn tx_server() -> TxServer<TxService> {
-    TxServer::new(TxService)
+pub fn tx_server<QH, QReq, QRes>(app: QH) -> TxServer<TxService<QH, QReq, QRes>>
+where
+    QReq: QueryRequest,
+    QRes: QueryResponse,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    TxServer::new(TxService {
+        app,
+        _phantom: Default::default(),
+    })
 }
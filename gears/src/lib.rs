@@ -45,6 +45,7 @@ pub mod store {
 }
 
 pub mod derive {
+    pub use event_derive::*;
     pub use key_derive::*;
     #[doc(inline)]
     pub use protobuf_derive::*;
@@ -101,6 +101,7 @@ impl From<QueryError> for HTTPError {
             QueryError::Store(_) => {
                 HTTPError::not_found_with_msg("The requested version could not be found.".into())
             }
+            QueryError::Simulate(err) => HTTPError::bad_request(err.to_string()),
             _ => HTTPError::bad_request("Invalid request.".to_owned()), // TODO: Don't forget to add more info later
         }
     }
@@ -6,10 +6,11 @@ use crate::baseapp::NodeQueryHandler;
 use crate::rest::error::HTTPError;
 use crate::types::pagination::request::PaginationRequest;
 use crate::types::pagination::response::PaginationResponse;
-use crate::types::request::tx::BroadcastTxRequest;
+use crate::types::request::tx::{BroadcastTxRequest, SimulateRequest};
 use crate::types::response::any::AnyTx;
 use crate::types::response::block::GetBlockByHeightResponse;
 use crate::types::response::node_info::{GetNodeInfoResponse, VersionInfo};
+use crate::types::response::simulate::SimulateResponse;
 use crate::types::response::tx::{
     BroadcastTxResponse, BroadcastTxResponseLight, TxResponse, TxResponseLight,
 };
@@ -282,6 +283,20 @@ pub async fn send_tx(
     }))
 }
 
+pub async fn simulate<QReq, QRes, App: NodeQueryHandler<QReq, QRes>>(
+    State(state): State<RestState<QReq, QRes, App>>,
+    simulate_request: String,
+) -> Result<Json<SimulateResponse>, HTTPError> {
+    let simulate_request: SimulateRequest = serde_json::from_str(&simulate_request)
+        .map_err(|e| HTTPError::bad_request(format!("invalid request: {e}")))?;
+
+    let bytes = data_encoding::BASE64
+        .decode(simulate_request.tx_bytes.as_bytes())
+        .map_err(|e| HTTPError::bad_request(format!("invalid tx bytes: {e}")))?;
+
+    Ok(Json(state.app.simulate(bytes.into())?))
+}
+
 // wrapper allows to paginate response properly
 // sorting of keys performs by height
 #[derive(Clone)]
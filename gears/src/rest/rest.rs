@@ -2,13 +2,18 @@ use crate::{
     application::ApplicationInfo,
     baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
     rest::handlers::{
-        block, block_latest, health, node_info, send_tx, tx, txs, validatorsets,
+        block, block_latest, health, node_info, send_tx, simulate, tx, txs, validatorsets,
         validatorsets_latest,
     },
     runtime::runtime,
     types::tx::TxMessage,
 };
-use axum::{extract::FromRef, http::Method, routing::get, Router};
+use axum::{
+    extract::FromRef,
+    http::Method,
+    routing::{get, post},
+    Router,
+};
 use std::{marker::PhantomData, net::SocketAddr};
 use tendermint::rpc::client::HttpClientUrl;
 use tower_http::{
@@ -96,6 +101,10 @@ async fn launch<
         )
         .route("/cosmos/tx/v1beta1/txs", get(txs::<M>).post(send_tx))
         .route("/cosmos/tx/v1beta1/txs/:hash", get(tx::<M>))
+        .route(
+            "/cosmos/tx/v1beta1/simulate",
+            post(simulate::<QReq, QRes, App>),
+        )
         .route(
             "/cosmos/base/tendermint/v1beta1/blocks/latest",
             get(block_latest),
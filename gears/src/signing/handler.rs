@@ -54,7 +54,7 @@ impl SignModeHandler {
 #[cfg(test)]
 mod tests {
     use crate::signing::handler::SignModeHandler;
-    use crate::signing::renderer::test_functions::{TestMetadataGetter, TestNoneMetadataGetter};
+    use crate::signing::renderer::test_utils::{TestMetadataGetter, TestNoneMetadataGetter};
     use crate::types::address::AccAddress;
     use crate::types::denom::Denom;
     use crate::types::{
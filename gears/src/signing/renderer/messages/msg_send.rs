@@ -47,7 +47,7 @@ impl ValueRenderer for MsgSend {
 
 #[cfg(test)]
 mod tests {
-    use crate::signing::renderer::test_functions::TestMetadataGetter;
+    use crate::signing::renderer::test_utils::TestMetadataGetter;
     use crate::types::{msg::send::MsgSend, rendering::screen::Screen};
 
     use crate::signing::renderer::value_renderer::ValueRenderer;
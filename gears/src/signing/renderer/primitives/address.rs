@@ -1,6 +1,6 @@
 //! Default formatting implementation for address
 
-use crate::types::address::AccAddress;
+use crate::types::address::{AccAddress, ValAddress};
 use crate::types::rendering::screen::Content;
 
 use crate::signing::renderer::value_renderer::{DefaultPrimitiveRenderer, PrimitiveValueRenderer};
@@ -10,3 +10,9 @@ impl PrimitiveValueRenderer<AccAddress> for DefaultPrimitiveRenderer {
         Content::try_new(value).expect("addresses cannot be empty")
     }
 }
+
+impl PrimitiveValueRenderer<ValAddress> for DefaultPrimitiveRenderer {
+    fn format(value: ValAddress) -> Content {
+        Content::try_new(value).expect("addresses cannot be empty")
+    }
+}
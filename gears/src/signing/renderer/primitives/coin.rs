@@ -112,7 +112,7 @@ impl TryPrimitiveValueRendererWithMetadata<UnsignedCoin> for DefaultPrimitiveRen
 
 #[cfg(test)]
 mod tests {
-    use crate::signing::renderer::test_functions::TestMetadataGetter;
+    use crate::signing::renderer::test_utils::TestMetadataGetter;
     use crate::signing::renderer::value_renderer::{
         DefaultPrimitiveRenderer, TryPrimitiveValueRendererWithMetadata,
     };
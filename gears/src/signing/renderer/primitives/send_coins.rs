@@ -38,7 +38,7 @@ mod tests {
     use cosmwasm_std::Uint256;
     use extensions::testing::UnwrapTesting;
 
-    use crate::signing::renderer::test_functions::TestMetadataGetter;
+    use crate::signing::renderer::test_utils::TestMetadataGetter;
     use crate::signing::renderer::value_renderer::{
         DefaultPrimitiveRenderer, TryPrimitiveValueRendererWithMetadata,
     };
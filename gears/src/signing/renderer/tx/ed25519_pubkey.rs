@@ -0,0 +1,75 @@
+use crate::{
+    crypto::ed25519::Ed25519PubKey,
+    signing::handler::MetadataGetter,
+    types::rendering::screen::{Indent, Screen},
+};
+
+use crate::signing::renderer::value_renderer::{
+    DefaultPrimitiveRenderer, RenderError, TryPrimitiveValueRenderer, ValueRenderer,
+};
+
+const TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+
+impl ValueRenderer for Ed25519PubKey {
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Public key".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(TYPE_URL)
+                    .expect("hard coded type URL is not empty"),
+                indent: None,
+                expert: true,
+            },
+            Screen {
+                title: "Key".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(
+                    Vec::<u8>::from(self.clone()).as_slice(),
+                )
+                .expect("key is not empty so it will never fail to parse"),
+                indent: Some(Indent::one()),
+                expert: true,
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto::ed25519::Ed25519PubKey;
+    use crate::signing::renderer::test_utils::TestMetadataGetter;
+    use crate::types::rendering::screen::{Content, Indent, Screen};
+
+    use crate::signing::renderer::value_renderer::ValueRenderer;
+
+    #[test]
+    fn ed25519_pubkey_formating() -> anyhow::Result<()> {
+        let key: Ed25519PubKey = serde_json::from_str(
+            r#"{
+            "@type": "/cosmos.crypto.ed25519.PubKey",
+            "key": "11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo="
+        }"#,
+        )?;
+
+        let expected_screens = vec![
+            Screen {
+                title: "Public key".to_string(),
+                content: Content::try_new("/cosmos.crypto.ed25519.PubKey")?,
+                indent: None,
+                expert: true,
+            },
+            Screen {
+                title: "Key".to_string(),
+                content: Content::try_new("D75A 9801 82B1 0AB7 D54B FED3 C964 073A 0EE1 72F3 DAA6 2325 AF02 1A68 F707 511A")?,
+                indent: Some(Indent::one()),
+                expert: true,
+            },
+        ];
+
+        let actual_screens = ValueRenderer::format(&key, &TestMetadataGetter)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
+pub mod ed25519_pubkey;
 pub mod public_key_enum;
 pub mod secp256_pubkey;
+pub mod secp256r1_pubkey;
 mod tx;
 
 pub use tx::*;
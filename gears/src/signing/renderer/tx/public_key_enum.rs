@@ -7,7 +7,9 @@ impl ValueRenderer for PublicKey {
     fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
         match self {
             PublicKey::Secp256k1(key) => ValueRenderer::format(key, get_metadata),
-            PublicKey::Ed25519(_) => Err(RenderError::NotImplemented),
+            PublicKey::Secp256r1(key) => ValueRenderer::format(key, get_metadata),
+            PublicKey::Ed25519(key) => ValueRenderer::format(key, get_metadata),
+            PublicKey::Multisig(_) => Err(RenderError::NotImplemented),
         }
     }
 }
@@ -15,7 +17,7 @@ impl ValueRenderer for PublicKey {
 #[cfg(test)]
 mod tests {
     use crate::crypto::secp256k1::Secp256k1PubKey;
-    use crate::signing::renderer::test_functions::TestMetadataGetter;
+    use crate::signing::renderer::test_utils::TestMetadataGetter;
     use crate::signing::renderer::value_renderer::ValueRenderer;
     use crate::types::rendering::screen::{Content, Indent, Screen};
 
@@ -36,7 +36,7 @@ impl ValueRenderer for Secp256k1PubKey {
 #[cfg(test)]
 mod tests {
     use crate::crypto::secp256k1::Secp256k1PubKey;
-    use crate::signing::renderer::test_functions::TestMetadataGetter;
+    use crate::signing::renderer::test_utils::TestMetadataGetter;
     use crate::types::rendering::screen::{Content, Indent, Screen};
 
     use crate::signing::renderer::value_renderer::ValueRenderer;
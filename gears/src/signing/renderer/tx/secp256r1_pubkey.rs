@@ -0,0 +1,73 @@
+use crate::{
+    crypto::secp256r1::{Secp256r1PubKey, SECP256R1_PUB_KEY_TYPE_URL},
+    signing::handler::MetadataGetter,
+    types::rendering::screen::{Indent, Screen},
+};
+
+use crate::signing::renderer::value_renderer::{
+    DefaultPrimitiveRenderer, RenderError, TryPrimitiveValueRenderer, ValueRenderer,
+};
+
+impl ValueRenderer for Secp256r1PubKey {
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Public key".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(SECP256R1_PUB_KEY_TYPE_URL)
+                    .expect("hard coded type URL is not empty"),
+                indent: None,
+                expert: true,
+            },
+            Screen {
+                title: "Key".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(
+                    Vec::<u8>::from(self.clone()).as_slice(),
+                )
+                .expect("key is not empty so it will never fail to parse"),
+                indent: Some(Indent::one()),
+                expert: true,
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto::secp256r1::Secp256r1PubKey;
+    use crate::signing::renderer::test_utils::TestMetadataGetter;
+    use crate::types::rendering::screen::{Content, Indent, Screen};
+
+    use crate::signing::renderer::value_renderer::ValueRenderer;
+
+    #[test]
+    fn secp256r1_pubkey_formating() -> anyhow::Result<()> {
+        let key: Secp256r1PubKey = serde_json::from_str(
+            r#"{
+            "@type": "/cosmos.crypto.secp256r1.PubKey",
+            "key": "A2sX0fLhLEJH+Lzm5WOkQPJ3A32BLeszoPShOUXYmMKW"
+        }"#,
+        )?;
+
+        let expected_screens = vec![
+            Screen {
+                title: "Public key".to_string(),
+                content: Content::try_new("/cosmos.crypto.secp256r1.PubKey")?,
+                indent: None,
+                expert: true,
+            },
+            Screen {
+                title: "Key".to_string(),
+                content: Content::try_new("036B 17D1 F2E1 2C42 47F8 BCE6 E563 A440 F277 037D 812D EB33 A0F4 A139 45D8 98C2 96")?,
+                indent: Some(Indent::one()),
+                expert: true,
+            },
+        ];
+
+        let actual_screens = ValueRenderer::format(&key, &TestMetadataGetter)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+}
@@ -232,7 +232,7 @@ impl<M: TxMessage + ValueRenderer> ValueRenderer for Envelope<M> {
 
 #[cfg(test)]
 mod tests {
-    use crate::signing::renderer::test_functions::TestMetadataGetter;
+    use crate::signing::renderer::test_utils::TestMetadataGetter;
     use crate::signing::renderer::value_renderer::ValueRenderer;
     use crate::types::address::AccAddress;
     use crate::types::auth::fee::Fee;
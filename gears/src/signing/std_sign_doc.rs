@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
+use tendermint::types::chain_id::ChainId;
 
 use crate::types::auth::fee::Fee;
+use crate::types::tx::{body::TxBody, TxMessage};
+
+use super::renderer::amino_renderer::{AminoRenderer, RenderError};
 
 // gears::core::base::coin::Coin has wrong order of fields
 // It is better to create a struct with correct order than
@@ -79,6 +83,35 @@ pub struct StdSignDoc {
 }
 
 impl StdSignDoc {
+    /// Sign doc of a tx with `body` and `fee`, which is signed in `LegacyAminoJson` sign mode
+    pub fn new<M: TxMessage>(
+        body: &TxBody<M>,
+        fee: Fee,
+        chain_id: &ChainId,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<Self, RenderError> {
+        let mut msgs = vec![];
+        for msg in &body.messages {
+            msgs.push(Msg {
+                kind: msg.amino_url().to_string(),
+                value: msg.render()?,
+            })
+        }
+
+        Ok(Self {
+            account_number: account_number.to_string(),
+            chain_id: chain_id.to_string(),
+            fee: fee.into(),
+            memo: body.memo.clone(),
+            msgs,
+            sequence: sequence.to_string(),
+            // TODO: check impl
+            // timeout_height: Some(u64::from(body.timeout_height).to_string()),
+            timeout_height: None,
+        })
+    }
+
     pub fn to_sign_bytes(&self) -> Result<Vec<u8>, serde_json::Error> {
         serde_json::to_vec(self)
     }
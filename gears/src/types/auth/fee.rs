@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use core_types::Protobuf;
-use cosmwasm_std::Uint256;
+use cosmwasm_std::{Uint128, Uint256};
 use serde::Deserialize;
 use serde::Serialize;
 use serde_with::serde_as;
@@ -43,6 +43,29 @@ pub struct Fee {
     pub granter: String,
 }
 
+impl Fee {
+    /// Mempool priority of a transaction paying this fee. This is the lowest fee per
+    /// unit of gas over all fee denominations, saturated at `i64::MAX`. Zero if no fee is paid.
+    pub fn priority(&self) -> i64 {
+        let gas = Uint256::from(self.gas_limit);
+
+        match &self.amount {
+            Some(amount) if !gas.is_zero() => amount
+                .inner()
+                .iter()
+                .map(|coin| {
+                    Uint128::try_from(coin.amount / gas)
+                        .ok()
+                        .and_then(|price| i64::try_from(price.u128()).ok())
+                        .unwrap_or(i64::MAX)
+                })
+                .min()
+                .unwrap_or_default(),
+            _ => 0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum FeeError {
     #[error("{0}")]
@@ -132,3 +155,25 @@ impl From<Fee> for inner::Fee {
 }
 
 impl Protobuf<inner::Fee> for Fee {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee(amount: &str, gas_limit: u64) -> Fee {
+        Fee {
+            amount: Some(amount.parse().expect("hardcoded coins are valid")),
+            gas_limit: gas_limit.try_into().expect("hardcoded gas is valid"),
+            payer: None,
+            granter: String::new(),
+        }
+    }
+
+    #[test]
+    fn priority_is_lowest_fee_per_gas() {
+        assert_eq!(fee("2000uatom,500uosmo", 100).priority(), 5);
+        assert_eq!(fee("99uatom", 100).priority(), 0);
+        assert_eq!(fee("100000000000000000000000uatom", 1).priority(), i64::MAX);
+        assert_eq!(fee("100uatom", 0).priority(), 0);
+    }
+}
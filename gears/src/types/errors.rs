@@ -4,3 +4,11 @@ pub use cosmwasm_std::StdError;
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
 #[error("invalid denom")]
 pub struct DenomError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum TypedEventError {
+    #[error("failed to serialize event: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("event {0} must serialize to a JSON object")]
+    NotAnObject(&'static str),
+}
@@ -0,0 +1,71 @@
+//! Typed events which are emitted as ABCI events with JSON encoded attribute values,
+//! see `EmitTypedEvent` of the cosmos sdk.
+
+use serde::Serialize;
+use tendermint::types::proto::event::{Event, EventAttribute};
+
+use super::errors::TypedEventError;
+
+/// Event which can be emitted with [`TransactionalContext::emit_typed`].
+/// Every field becomes an attribute of the ABCI event with the JSON value of the field.
+///
+/// [`TransactionalContext::emit_typed`]: crate::context::TransactionalContext::emit_typed
+pub trait TypedEvent: Serialize {
+    /// Type of the ABCI event, usually the full protobuf name e.g. `cosmos.bank.v1beta1.EventSend`
+    const KIND: &'static str;
+
+    /// Whether the attribute with `key` is indexed by tendermint
+    fn index(key: &str) -> bool;
+
+    /// Convert into an ABCI event. Attributes are sorted by key.
+    fn to_event(&self) -> Result<Event, TypedEventError> {
+        match serde_json::to_value(self)? {
+            serde_json::Value::Object(fields) => Ok(Event::new(
+                Self::KIND,
+                fields.into_iter().map(|(key, value)| {
+                    let index = Self::index(&key);
+                    EventAttribute::new(key.into(), value.to_string().into(), index)
+                }),
+            )),
+            _ => Err(TypedEventError::NotAnObject(Self::KIND)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::derive::TypedEvent;
+
+    #[derive(Serialize, TypedEvent)]
+    #[event(gears, kind = "cosmos.bank.v1beta1.EventSend")]
+    struct EventSend {
+        to_address: String,
+        from_address: String,
+        #[event(no_index)]
+        amount: Vec<u64>,
+    }
+
+    #[test]
+    fn typed_event_to_event() {
+        let event = EventSend {
+            to_address: "cosmos1to".to_owned(),
+            from_address: "cosmos1from".to_owned(),
+            amount: vec![10, 20],
+        }
+        .to_event()
+        .expect("event is a struct");
+
+        assert_eq!(
+            event,
+            Event::new(
+                "cosmos.bank.v1beta1.EventSend",
+                [
+                    EventAttribute::new("amount".into(), "[10,20]".into(), false),
+                    EventAttribute::new("from_address".into(), "\"cosmos1from\"".into(), true),
+                    EventAttribute::new("to_address".into(), "\"cosmos1to\"".into(), true),
+                ]
+            )
+        );
+    }
+}
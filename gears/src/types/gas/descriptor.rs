@@ -1,3 +1,5 @@
 pub const BLOCK_GAS_DESCRIPTOR: &str = "block gas meter";
 pub const ANTE_SECKP251K1_DESCRIPTOR: &str = "ante verify: secp256k1";
+pub const ANTE_ED25519_DESCRIPTOR: &str = "ante verify: ed25519";
+pub const ANTE_SECP256R1_DESCRIPTOR: &str = "ante verify: secp256r1";
 pub const TX_SIZE_DESCRIPTOR: &str = "txSize";
@@ -8,6 +8,7 @@ pub mod base;
 pub mod decimal256;
 pub mod denom;
 pub mod errors;
+pub mod event;
 pub mod gas;
 pub mod msg;
 pub mod pagination;
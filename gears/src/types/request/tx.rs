@@ -8,3 +8,11 @@ pub struct BroadcastTxRequest {
     pub tx_bytes: String,
     pub mode: String,
 }
+
+/// SimulateRequest is the request type for the Service.Simulate
+/// RPC method.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SimulateRequest {
+    /// tx_bytes is the raw transaction encoded as base64.
+    pub tx_bytes: String,
+}
@@ -1,6 +1,7 @@
 pub mod any;
 pub mod block;
 pub mod node_info;
+pub mod simulate;
 pub mod tx;
 pub mod tx_event;
 pub mod validators;
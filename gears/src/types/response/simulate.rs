@@ -0,0 +1,80 @@
+use core_types::any::google::Any;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tendermint::types::proto::event::Event;
+
+use crate::{baseapp::RunTxInfo, types::gas::Gas};
+
+/// GasInfo defines tx execution gas context.
+#[serde_as]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Message)]
+pub struct GasInfo {
+    /// GasWanted is the maximum units of work we allow this tx to perform.
+    #[prost(uint64, tag = "1")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub gas_wanted: u64,
+    /// GasUsed is the amount of gas actually consumed.
+    #[prost(uint64, tag = "2")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub gas_used: u64,
+}
+
+/// Result is the union of ResponseFormat and ResponseCheckTx.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Message)]
+pub struct TxResult {
+    /// Data is any data returned from message or handler execution.
+    #[prost(bytes = "vec", tag = "1")]
+    #[serde(with = "core_types::serializers::Base64Standard")]
+    pub data: Vec<u8>,
+    /// Log contains the log information from message or handler execution.
+    #[prost(string, tag = "2")]
+    pub log: String,
+    /// Events contains a slice of Event objects that were emitted during message
+    /// or handler execution.
+    #[prost(message, repeated, tag = "3")]
+    pub events: Vec<Event>,
+    /// MsgResponses contains the Msg handler responses type packed in Anys.
+    #[prost(message, repeated, tag = "4")]
+    pub msg_responses: Vec<Any>,
+}
+
+/// SimulationResponse defines the response generated when a transaction is
+/// successfully simulated. Shares its encoding with the Service.Simulate
+/// response.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Message)]
+pub struct SimulateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub gas_info: Option<GasInfo>,
+    #[prost(message, optional, tag = "2")]
+    pub result: Option<TxResult>,
+}
+
+impl From<RunTxInfo> for SimulateResponse {
+    fn from(info: RunTxInfo) -> Self {
+        let data = info.data().to_vec();
+        let RunTxInfo {
+            events,
+            msg_responses,
+            gas_wanted,
+            gas_used,
+            ..
+        } = info;
+
+        Self {
+            gas_info: Some(GasInfo {
+                gas_wanted: match gas_wanted {
+                    Gas::Infinite => u64::MAX,
+                    Gas::Finite(gas_wanted) => gas_wanted.into(),
+                },
+                gas_used: gas_used.into(),
+            }),
+            result: Some(TxResult {
+                data,
+                log: String::new(),
+                events,
+                msg_responses,
+            }),
+        }
+    }
+}
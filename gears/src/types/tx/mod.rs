@@ -1,6 +1,7 @@
 pub mod data;
 pub mod errors;
 pub mod metadata;
+pub mod msg_data;
 pub mod signer;
 use core_types::{any::google::Any, errors::CoreError, tx::signature::SignatureData, Protobuf};
 use serde::{Deserialize, Serialize};
@@ -31,6 +32,15 @@ pub trait TxMessage:
         // on better signing processes
         self.type_url()
     }
+
+    /// Response of a message which doesn't return any data. Its type url is the one of
+    /// the message with a `Response` suffix, e.g. `/cosmos.bank.v1beta1.MsgSendResponse`.
+    fn empty_response(&self) -> Any {
+        Any {
+            type_url: format!("{}Response", self.type_url()),
+            value: Vec::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -0,0 +1,10 @@
+use core_types::any::google::Any;
+
+/// Responses of the messages of a transaction, returned as the data of CheckTx and
+/// DeliverTx. Encoded as `cosmos.base.abci.v1beta1.TxMsgData`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TxMsgData {
+    /// Response of each message in the order the messages appear in the transaction
+    #[prost(message, repeated, tag = "2")]
+    pub msg_responses: Vec<Any>,
+}
@@ -42,6 +42,17 @@ pub fn generate_tx<M: TxMessage>(
         granter: "".into(),
     };
 
+    generate_tx_with_fee(msgs, sequence, user, chain_id, fee)
+}
+
+/// Same as [`generate_tx`] but with a custom fee and gas limit
+pub fn generate_tx_with_fee<M: TxMessage>(
+    msgs: Vec1<M>,
+    sequence: u64,
+    user: &User,
+    chain_id: ChainId,
+    fee: Fee,
+) -> Bytes {
     let signing_info = SigningInfo {
         key: &user.key_pair,
         sequence,
@@ -58,7 +69,7 @@ pub fn generate_tx<M: TxMessage>(
     } = crate::crypto::info::create_signed_transaction_direct(
         vec![signing_info],
         chain_id.to_owned(),
-        fee.to_owned(),
+        fee,
         None,
         body,
     )
@@ -12,10 +12,12 @@ use tendermint::{
             validator::ValidatorUpdate,
         },
         request::{
-            begin_block::RequestBeginBlock, deliver_tx::RequestDeliverTx,
+            begin_block::RequestBeginBlock, check_tx::RequestCheckTx, deliver_tx::RequestDeliverTx,
             end_block::RequestEndBlock, init_chain::RequestInitChain, query::RequestQuery,
         },
-        response::{deliver_tx::ResponseDeliverTx, query::ResponseQuery},
+        response::{
+            check_tx::ResponseCheckTx, deliver_tx::ResponseDeliverTx, query::ResponseQuery,
+        },
         time::timestamp::Timestamp,
     },
 };
@@ -57,6 +59,8 @@ pub struct StepResponse {
     pub app_hash: Bytes,
     pub tx_responses: Vec<ResponseDeliverTx>,
     pub height: u32,
+    /// Consensus params returned by EndBlock if they changed during the block
+    pub consensus_param_updates: Option<ConsensusParams>,
 }
 
 impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
@@ -69,13 +73,14 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
         // 1 then the app subtracts 1 from the height in the header.
         // On the first begin block the header height passed by TM is also 1. The app uses this without modification.
         // To achieve this we initialise the height in this mock state to 0 such that on the first step this gets incremented to the correct height of 1.
+        // Chains starting at a later height begin at `initial_height` in the same way.
 
         let res = app.init_chain(init_state.clone().into());
 
         Self {
             app,
             app_hash: res.app_hash,
-            height: 0,
+            height: init_state.initial_height.saturating_sub(1),
             chain_id: init_state.chain_id,
             time: init_state.time,
             last_block_id: BlockId {
@@ -89,6 +94,10 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
         }
     }
 
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
     pub fn app_hash(&self) -> &Bytes {
         &self.app_hash
     }
@@ -116,7 +125,7 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
             tx_responses.push(self.app.deliver_tx(RequestDeliverTx { tx }));
         }
 
-        self.app.end_block(RequestEndBlock {
+        let res_end_block = self.app.end_block(RequestEndBlock {
             height: self.height as i64,
         });
 
@@ -128,6 +137,7 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
             app_hash: self.app_hash.clone(),
             tx_responses,
             height: self.height,
+            consensus_param_updates: res_end_block.consensus_param_updates,
         }
     }
 
@@ -135,6 +145,10 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
         self.app.query(req)
     }
 
+    pub fn check_tx(&self, req: RequestCheckTx) -> ResponseCheckTx {
+        self.app.check_tx(req)
+    }
+
     fn calculate_header(&self) -> Header {
         Header {
             version: Consensus { block: 11, app: 10 },
@@ -0,0 +1,878 @@
+//! Steps of the default ante handler, in the order in which [`BaseAnteHandler`] runs them.
+//!
+//! [`BaseAnteHandler`]: super::BaseAnteHandler
+
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use core_types::{
+    signing::{SignDoc, SignDocDirectAux},
+    tx::mode_info::SignMode,
+};
+use cosmwasm_std::Decimal256;
+use database::Database;
+use kv_store::StoreKey;
+use prost::Message as ProstMessage;
+use tendermint::types::proto::event::{Event, EventAttribute};
+
+use super::{
+    sim_secp256k1_pub_key, AnteDecorator, MetadataFromState, SignGasConsumer,
+    ATTRIBUTE_KEY_ACCOUNT_SEQUENCE, ATTRIBUTE_KEY_FEE, ATTRIBUTE_KEY_FEE_PAYER,
+    ATTRIBUTE_KEY_SIGNATURE, EVENT_TYPE_TX,
+};
+use crate::application::handlers::node::TxError;
+use crate::baseapp::mode::RunTxMode;
+use crate::baseapp::options::NodeOptions;
+use crate::context::{QueryableContext, TransactionalContext};
+use crate::signing::handler::SignModeHandler;
+use crate::signing::renderer::amino_renderer::{AminoRenderer, RenderError as AminoRendererError};
+use crate::signing::renderer::value_renderer::ValueRenderer;
+use crate::signing::std_sign_doc;
+use crate::types::auth::gas::Gas;
+use crate::types::base::coin::UnsignedCoin;
+use crate::types::base::coins::{format_coins, UnsignedCoins};
+use crate::types::gas::descriptor::TX_SIZE_DESCRIPTOR;
+use crate::types::gas::kind::TxKind;
+use crate::types::gas::GasMeter;
+use crate::types::tx::{raw::TxWithRaw, signer::SignerData, TxMessage};
+use crate::x::errors::{AccountNotFound, AnteError, AnteGasError};
+use crate::x::keepers::auth::{AuthKeeper, AuthParams};
+use crate::x::keepers::bank::BankKeeper;
+use crate::x::module::Module;
+
+/// Rejects new and rechecked txs whose fee is below the minimum gas prices of the node.
+/// Txs which only contain bypass messages and stay within the bypass gas limit of the node
+/// are exempt, see [`NodeOptions::bypass_min_fee_msg_types`].
+#[derive(Debug, Clone, Default)]
+pub struct MempoolFeeDecorator;
+
+impl<SK: StoreKey> AnteDecorator<SK> for MempoolFeeDecorator {
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        _: &mut CTX,
+        TxWithRaw { tx, .. }: &TxWithRaw<M>,
+        mode: RunTxMode,
+        node_opt: &NodeOptions,
+        _: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        if !mode.is_check() {
+            return Ok(());
+        }
+
+        let fee = tx.auth_info.fee.amount.as_ref();
+        let gas = tx.auth_info.fee.gas_limit;
+
+        let bypass_msg_types = node_opt.bypass_min_fee_msg_types();
+        let is_bypass_tx = tx
+            .get_msgs()
+            .iter()
+            .all(|msg| bypass_msg_types.iter().any(|this| this == msg.type_url()));
+        if is_bypass_tx && u64::from(gas) <= node_opt.max_total_bypass_min_fee_msg_gas_usage() {
+            return Ok(());
+        }
+
+        let min_gas_prices = node_opt.min_gas_prices();
+
+        if min_gas_prices.is_empty() || min_gas_prices.is_zero() {
+            return Ok(());
+        }
+
+        if let Some(fee_coins) = fee {
+            let mut required_fees = Vec::with_capacity(min_gas_prices.len());
+
+            for gp in min_gas_prices {
+                required_fees.push(UnsignedCoin {
+                    denom: gp.denom,
+                    amount: gp
+                        .amount
+                        .checked_mul(Into::<Decimal256>::into(gas))
+                        .map_err(|_| {
+                            AnteGasError::Overflow("overflow calculating required fees".into())
+                        })
+                        .map_err(AnteError::from)?
+                        .to_uint_ceil(),
+                });
+            }
+
+            let required_fees = UnsignedCoins::new(required_fees)
+                .expect("we know by now that required_fees: contains at least one coin, all amounts are positive, no duplicate denominations and sorted lexicographically");
+
+            if !is_any_gte(fee_coins.inner(), &required_fees) {
+                Err(AnteError::InsufficientFees {
+                    got: format!("{fee_coins:?}"),
+                    required: format!("{required_fees:?}"),
+                })?
+            }
+        } else {
+            Err(AnteError::MissingFee)?
+        }
+
+        fn is_any_gte(coins_a: &Vec<UnsignedCoin>, coins_b: &UnsignedCoins) -> bool {
+            if coins_b.is_empty() {
+                return false;
+            }
+
+            for coin in coins_a {
+                let amount = coins_b.amount_of(&coin.denom);
+                if coin.amount >= amount && !amount.is_zero() {
+                    return true;
+                }
+            }
+
+            false
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that there is a signature for every signer
+#[derive(Debug, Clone, Default)]
+pub struct ValidateBasicDecorator;
+
+impl<SK: StoreKey> AnteDecorator<SK> for ValidateBasicDecorator {
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        _: &mut CTX,
+        TxWithRaw { tx, .. }: &TxWithRaw<M>,
+        _: RunTxMode,
+        _: &NodeOptions,
+        _: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        // Not sure if we need to explicitly check this given the check which follows.
+        // We'll leave it in for now since it's in the SDK.
+        let sigs = tx.get_signatures();
+        if sigs.is_empty() {
+            Err(AnteError::Validation("signature list is empty".into()))?
+        }
+
+        if sigs.len() != tx.get_signers().len() {
+            Err(AnteError::Validation(format!(
+                "wrong number of signatures; expected {}, got {}",
+                tx.get_signers().len(),
+                sigs.len()
+            )))?
+        }
+
+        Ok(())
+    }
+}
+
+/// Rejects txs whose timeout height has passed
+#[derive(Debug, Clone, Default)]
+pub struct TxTimeoutHeightDecorator;
+
+impl<SK: StoreKey> AnteDecorator<SK> for TxTimeoutHeightDecorator {
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        TxWithRaw { tx, .. }: &TxWithRaw<M>,
+        _: RunTxMode,
+        _: &NodeOptions,
+        _: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        let timeout_height = tx.get_timeout_height();
+
+        // timeout_height of zero means no timeout height
+        if timeout_height == 0 {
+            return Ok(());
+        }
+
+        let block_height = ctx.height();
+
+        if block_height > timeout_height {
+            Err(AnteError::Timeout {
+                timeout: timeout_height,
+                current: block_height,
+            })?
+        }
+
+        Ok(())
+    }
+}
+
+/// Rejects txs whose memo is longer than allowed by the auth params
+#[derive(Debug, Clone)]
+pub struct ValidateMemoDecorator<AK, M> {
+    auth_keeper: AK,
+    _module: PhantomData<M>,
+}
+
+impl<AK, M> ValidateMemoDecorator<AK, M> {
+    pub fn new(auth_keeper: AK) -> Self {
+        Self {
+            auth_keeper,
+            _module: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, MOD: Module, AK: AuthKeeper<SK, MOD>> AnteDecorator<SK>
+    for ValidateMemoDecorator<AK, MOD>
+{
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        TxWithRaw { tx, .. }: &TxWithRaw<M>,
+        _: RunTxMode,
+        _: &NodeOptions,
+        _: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        let max_memo_chars = self
+            .auth_keeper
+            .get_auth_params(ctx)
+            .map_err(AnteError::from)?
+            .max_memo_characters();
+        let memo_length: u64 = tx
+            .get_memo()
+            .len()
+            .try_into()
+            .map_err(|_| AnteError::Memo(max_memo_chars))?;
+
+        if memo_length > max_memo_chars {
+            Err(AnteError::Memo(max_memo_chars))?
+        };
+
+        Ok(())
+    }
+}
+
+/// Charges gas for every byte of the tx
+#[derive(Debug, Clone)]
+pub struct ConsumeTxSizeGasDecorator<AK, M> {
+    auth_keeper: AK,
+    _module: PhantomData<M>,
+}
+
+impl<AK, M> ConsumeTxSizeGasDecorator<AK, M> {
+    pub fn new(auth_keeper: AK) -> Self {
+        Self {
+            auth_keeper,
+            _module: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, MOD: Module, AK: AuthKeeper<SK, MOD>> AnteDecorator<SK>
+    for ConsumeTxSizeGasDecorator<AK, MOD>
+{
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        TxWithRaw { tx_len, .. }: &TxWithRaw<M>,
+        _: RunTxMode,
+        _: &NodeOptions,
+        gas_meter: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        let params = self
+            .auth_keeper
+            .get_auth_params(ctx)
+            .map_err(AnteError::from)?;
+        let tx_len: Gas = (*tx_len as u64).try_into().map_err(|_| AnteError::TxLen)?;
+        let cost_per_byte: Gas = params
+            .tx_cost_per_byte()
+            .try_into()
+            .map_err(|_| {
+                AnteGasError::Overflow("overflow converting tx cost per byte to gas".to_string())
+            })
+            .map_err(AnteError::from)?;
+        let gas_required = tx_len
+            .checked_mul(cost_per_byte)
+            .ok_or(AnteGasError::Overflow(
+                "overflow calculating gas required for tx size".to_string(),
+            ))
+            .map_err(AnteError::from)?;
+
+        gas_meter
+            .borrow_mut()
+            .consume_gas(gas_required, TX_SIZE_DESCRIPTOR)
+            .map_err(Into::<AnteGasError>::into)
+            .map_err(AnteError::from)?;
+
+        Ok(())
+    }
+}
+
+/// Moves the fee from the fee payer to the fee collector module and emits the `tx` event
+/// with the fee and fee payer
+#[derive(Debug, Clone)]
+pub struct DeductFeeDecorator<AK, BK, M> {
+    auth_keeper: AK,
+    bank_keeper: BK,
+    fee_collector_module: M,
+}
+
+impl<AK, BK, M> DeductFeeDecorator<AK, BK, M> {
+    pub fn new(auth_keeper: AK, bank_keeper: BK, fee_collector_module: M) -> Self {
+        Self {
+            auth_keeper,
+            bank_keeper,
+            fee_collector_module,
+        }
+    }
+}
+
+impl<SK: StoreKey, MOD: Module, AK: AuthKeeper<SK, MOD>, BK: BankKeeper<SK, MOD>> AnteDecorator<SK>
+    for DeductFeeDecorator<AK, BK, MOD>
+{
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        TxWithRaw { tx, .. }: &TxWithRaw<M>,
+        _: RunTxMode,
+        _: &NodeOptions,
+        _: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        let fee = tx.get_fee();
+        let fee_payer = tx.get_fee_payer();
+
+        if !self
+            .auth_keeper
+            .has_account(ctx, fee_payer)
+            .map_err(AnteError::from)?
+        {
+            Err(AnteError::from(AccountNotFound::from(fee_payer.clone())))?
+        }
+
+        if let Some(fee) = fee {
+            self.bank_keeper
+                .send_coins_from_account_to_module(
+                    ctx,
+                    fee_payer.to_owned(),
+                    &self.fee_collector_module,
+                    fee.to_owned(),
+                )
+                .map_err(AnteError::from)?;
+        }
+
+        ctx.push_event(Event::new(
+            EVENT_TYPE_TX,
+            [
+                EventAttribute::new(
+                    ATTRIBUTE_KEY_FEE.into(),
+                    fee.map(|fee| format_coins(fee.inner())).unwrap_or_default(),
+                    true,
+                ),
+                EventAttribute::new(
+                    ATTRIBUTE_KEY_FEE_PAYER.into(),
+                    fee_payer.to_string().into(),
+                    true,
+                ),
+            ],
+        ));
+
+        Ok(())
+    }
+}
+
+/// Sets the public keys of signers which don't have one yet. Must run before any step
+/// which uses the public keys of the signers.
+#[derive(Debug, Clone)]
+pub struct SetPubKeyDecorator<AK, M> {
+    auth_keeper: AK,
+    _module: PhantomData<M>,
+}
+
+impl<AK, M> SetPubKeyDecorator<AK, M> {
+    pub fn new(auth_keeper: AK) -> Self {
+        Self {
+            auth_keeper,
+            _module: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, MOD: Module, AK: AuthKeeper<SK, MOD>> AnteDecorator<SK>
+    for SetPubKeyDecorator<AK, MOD>
+{
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        TxWithRaw { tx, .. }: &TxWithRaw<M>,
+        _: RunTxMode,
+        _: &NodeOptions,
+        _: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        let public_keys = tx.get_public_keys();
+        let signers = tx.get_signers();
+
+        // additional check not found in the sdk - this prevents a panic
+        if signers.len() != public_keys.len() {
+            Err(AnteError::Validation(format!(
+                "wrong number of signer info; expected {}, got {}",
+                signers.len(),
+                public_keys.len()
+            )))?
+        }
+
+        for (i, key) in public_keys.into_iter().enumerate() {
+            if let Some(key) = key {
+                let addr = key.get_address();
+
+                if &addr != signers[i] {
+                    Err(AnteError::Validation(format!(
+                        "public key address number {i} does not match signer {i}; expected {}, got {addr}",
+                        signers[i]
+                    )))?
+                }
+
+                let mut acct = self
+                    .auth_keeper
+                    .get_account(ctx, &addr)
+                    .map_err(AnteError::from)?
+                    .ok_or(AnteError::from(AccountNotFound::from(addr.to_owned())))?;
+
+                if acct.get_public_key().is_some() {
+                    continue;
+                }
+
+                acct.set_public_key(key.clone());
+                self.auth_keeper
+                    .set_account(ctx, acct)
+                    .map_err(AnteError::from)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Rejects txs with more signatures than the `tx_sig_limit` auth param allows, the keys
+/// of multisig keys are counted. Runs before any gas is charged for signatures.
+#[derive(Debug, Clone)]
+pub struct ValidateSigCountDecorator<AK, M> {
+    auth_keeper: AK,
+    _module: PhantomData<M>,
+}
+
+impl<AK, M> ValidateSigCountDecorator<AK, M> {
+    pub fn new(auth_keeper: AK) -> Self {
+        Self {
+            auth_keeper,
+            _module: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, MOD: Module, AK: AuthKeeper<SK, MOD>> AnteDecorator<SK>
+    for ValidateSigCountDecorator<AK, MOD>
+{
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        TxWithRaw { tx, .. }: &TxWithRaw<M>,
+        _: RunTxMode,
+        _: &NodeOptions,
+        _: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        let limit = self
+            .auth_keeper
+            .get_auth_params(ctx)
+            .map_err(AnteError::from)?
+            .tx_sig_limit();
+
+        let mut count = 0_u64;
+        for key in tx.get_public_keys().into_iter().flatten() {
+            count = count.saturating_add(key.count_sub_keys() as u64);
+
+            if count > limit {
+                Err(AnteError::TooManySignatures { count, limit })?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Charges gas for the verification of every signature
+#[derive(Debug, Clone)]
+pub struct SigGasConsumeDecorator<AK, GC, M> {
+    auth_keeper: AK,
+    sign_gas_consumer: GC,
+    _module: PhantomData<M>,
+}
+
+impl<AK, GC, M> SigGasConsumeDecorator<AK, GC, M> {
+    pub fn new(auth_keeper: AK, sign_gas_consumer: GC) -> Self {
+        Self {
+            auth_keeper,
+            sign_gas_consumer,
+            _module: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, MOD: Module, AK: AuthKeeper<SK, MOD>, GC: SignGasConsumer> AnteDecorator<SK>
+    for SigGasConsumeDecorator<AK, GC, MOD>
+{
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        TxWithRaw { tx, .. }: &TxWithRaw<M>,
+        mode: RunTxMode,
+        _: &NodeOptions,
+        gas_meter: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        let auth_params = self
+            .auth_keeper
+            .get_auth_params(ctx)
+            .map_err(AnteError::from)?;
+
+        let signatures = tx.get_signatures_data();
+        let signers_addr = tx.get_signers();
+
+        for (i, signer_addr) in signers_addr.into_iter().enumerate() {
+            let acct = self
+                .auth_keeper
+                .get_account(ctx, signer_addr)
+                .map_err(AnteError::from)?
+                .ok_or(AnteError::from(AccountNotFound::from(
+                    signer_addr.to_owned(),
+                )))?;
+
+            let pub_key = acct
+                .get_public_key()
+                .cloned()
+                .or_else(|| mode.is_simulate().then(sim_secp256k1_pub_key))
+                .expect("account pub keys are set in SetPubKeyDecorator");
+
+            let sig = signatures.get(i).expect("TODO"); //TODO: expect message
+
+            self.sign_gas_consumer
+                .consume(&mut gas_meter.borrow_mut(), pub_key, sig, &auth_params)
+                .map_err(Into::<AnteGasError>::into)
+                .map_err(AnteError::from)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that every signature was made with the current sequence of its signer
+#[derive(Debug, Clone)]
+pub struct SigSequenceDecorator<AK, M> {
+    auth_keeper: AK,
+    _module: PhantomData<M>,
+}
+
+impl<AK, M> SigSequenceDecorator<AK, M> {
+    pub fn new(auth_keeper: AK) -> Self {
+        Self {
+            auth_keeper,
+            _module: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, MOD: Module, AK: AuthKeeper<SK, MOD>> AnteDecorator<SK>
+    for SigSequenceDecorator<AK, MOD>
+{
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        TxWithRaw { tx, .. }: &TxWithRaw<M>,
+        _: RunTxMode,
+        _: &NodeOptions,
+        _: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        for (signer, signature_data) in tx.get_signers().into_iter().zip(tx.get_signatures_data()) {
+            let account_seq = self
+                .auth_keeper
+                .get_account(ctx, signer)
+                .map_err(AnteError::from)?
+                .ok_or(AnteError::from(AccountNotFound::from(signer.to_owned())))?
+                .get_sequence();
+
+            if account_seq != signature_data.sequence {
+                Err(AnteError::Validation(format!(
+                    "incorrect tx sequence; expected {}, got {}",
+                    account_seq, signature_data.sequence
+                )))?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Verifies the signatures and emits a `tx` event with every signature.
+/// Signatures of simulated txs are usually empty and rechecked txs were already verified,
+/// their signatures can't be invalidated by a new block, so both are skipped.
+#[derive(Debug, Clone)]
+pub struct SigVerificationDecorator<AK, BK, M> {
+    auth_keeper: AK,
+    bank_keeper: BK,
+    _module: PhantomData<M>,
+}
+
+impl<AK, BK, M> SigVerificationDecorator<AK, BK, M> {
+    pub fn new(auth_keeper: AK, bank_keeper: BK) -> Self {
+        Self {
+            auth_keeper,
+            bank_keeper,
+            _module: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, MOD: Module, AK: AuthKeeper<SK, MOD>, BK: BankKeeper<SK, MOD>> AnteDecorator<SK>
+    for SigVerificationDecorator<AK, BK, MOD>
+{
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        mode: RunTxMode,
+        _: &NodeOptions,
+        _: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        if mode.is_simulate() || mode.is_recheck() {
+            return Ok(());
+        }
+
+        Ok(self.verify_signatures(ctx, tx)?)
+    }
+}
+
+impl<SK: StoreKey, MOD: Module, AK: AuthKeeper<SK, MOD>, BK: BankKeeper<SK, MOD>>
+    SigVerificationDecorator<AK, BK, MOD>
+{
+    fn verify_signatures<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+    ) -> Result<(), AnteError> {
+        let signers = tx.tx.get_signers();
+        let signature_data = tx.tx.get_signatures_data();
+
+        // NOTE: this is also checked in ValidateBasicDecorator
+        if signature_data.len() != signers.len() {
+            return Err(AnteError::Validation(format!(
+                "wrong number of signatures; expected {}, got {}",
+                signers.len(),
+                signature_data.len()
+            )));
+        }
+
+        for (i, signature_data) in signature_data.iter().enumerate() {
+            let signer = signers[i];
+
+            let acct = self
+                .auth_keeper
+                .get_account(ctx, signer)?
+                .ok_or(AccountNotFound::from(signer.to_owned()))?;
+
+            // checked in SigSequenceDecorator
+            let account_seq = acct.get_sequence();
+
+            let public_key = acct
+                .get_public_key()
+                .expect("account pub keys are set in SetPubKeyDecorator"); //TODO: but can't they be set to None?
+
+            let genesis = ctx.height() == 0;
+            let account_number = if genesis {
+                0
+            } else {
+                acct.get_account_number()
+            };
+
+            let ctx_ref = &*ctx;
+            let mut sign_bytes = |mode: &SignMode| -> Result<Vec<u8>, AnteError> {
+                match mode {
+                    SignMode::Direct => Ok(SignDoc {
+                        body_bytes: tx.raw.body_bytes.clone(),
+                        auth_info_bytes: tx.raw.auth_info_bytes.clone(),
+                        chain_id: ctx_ref.chain_id().to_string(),
+                        account_number,
+                    }
+                    .encode_to_vec()),
+                    SignMode::LegacyAminoJson => std_sign_doc::StdSignDoc::new(
+                        &tx.tx.body,
+                        tx.tx.auth_info.fee.clone(),
+                        ctx_ref.chain_id(),
+                        account_number,
+                        account_seq,
+                    )?
+                    .to_sign_bytes()
+                    .map_err(|e| {
+                        AnteError::LegacyAminoJson(AminoRendererError::Rendering(e.to_string()))
+                    }),
+                    SignMode::Textual => {
+                        let handler = SignModeHandler;
+
+                        let signer_data = SignerData {
+                            address: signer.to_owned(),
+                            chain_id: ctx_ref.chain_id().to_owned(),
+                            account_number,
+                            sequence: account_seq,
+                            pub_key: public_key.to_owned(),
+                        };
+
+                        let f = MetadataFromState {
+                            bank_keeper: &self.bank_keeper,
+                            ctx: ctx_ref,
+                            _phantom: PhantomData,
+                        };
+
+                        Ok(handler.sign_bytes_get(
+                            &f,
+                            signer_data,
+                            &tx.tx.body,
+                            &tx.tx.auth_info,
+                        )?)
+                    }
+                    SignMode::DirectAux => {
+                        // the fee payer signs over the fee, which isn't part of the aux sign doc
+                        if signer == tx.tx.get_fee_payer() {
+                            Err(AnteError::Validation(format!(
+                                "fee payer {signer} can't sign in direct aux mode"
+                            )))?
+                        }
+
+                        Ok(SignDocDirectAux {
+                            body_bytes: tx.raw.body_bytes.clone(),
+                            public_key: Some(public_key.to_owned().into()),
+                            chain_id: ctx_ref.chain_id().to_string(),
+                            account_number,
+                            sequence: account_seq,
+                            tip: tx.tx.auth_info.tip.clone().map(Into::into),
+                        }
+                        .encode_to_vec())
+                    }
+                    mode => Err(AnteError::Validation(format!(
+                        "sign mode not supported: {:?}",
+                        mode
+                    ))),
+                }
+            };
+
+            // multisig keys verify the signature of every key which signed with its own mode
+            public_key.verify_signature_data(
+                &signature_data.mode_info,
+                &signature_data.signature,
+                &mut sign_bytes,
+            )?;
+
+            ctx.push_event(Event::new(
+                EVENT_TYPE_TX,
+                [EventAttribute::new(
+                    ATTRIBUTE_KEY_SIGNATURE.into(),
+                    data_encoding::BASE64
+                        .encode(&signature_data.signature)
+                        .into(),
+                    true,
+                )],
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Increments the sequence of every signer and emits the `tx` event with the sequence
+/// the tx was signed with
+#[derive(Debug, Clone)]
+pub struct IncrementSequenceDecorator<AK, M> {
+    auth_keeper: AK,
+    _module: PhantomData<M>,
+}
+
+impl<AK, M> IncrementSequenceDecorator<AK, M> {
+    pub fn new(auth_keeper: AK) -> Self {
+        Self {
+            auth_keeper,
+            _module: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, MOD: Module, AK: AuthKeeper<SK, MOD>> AnteDecorator<SK>
+    for IncrementSequenceDecorator<AK, MOD>
+{
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        TxWithRaw { tx, .. }: &TxWithRaw<M>,
+        _: RunTxMode,
+        _: &NodeOptions,
+        _: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        for signer in tx.get_signers() {
+            let mut acct = self
+                .auth_keeper
+                .get_account(ctx, signer)
+                .map_err(AnteError::from)?
+                .ok_or(AnteError::from(AccountNotFound::from(signer.to_owned())))?;
+
+            // the sequence the tx was signed with, checked in SigSequenceDecorator
+            ctx.push_event(Event::new(
+                EVENT_TYPE_TX,
+                [EventAttribute::new(
+                    ATTRIBUTE_KEY_ACCOUNT_SEQUENCE.into(),
+                    format!("{signer}/{}", acct.get_sequence()).into(),
+                    true,
+                )],
+            ));
+
+            acct.increment_sequence();
+            self.auth_keeper
+                .set_account(ctx, acct)
+                .map_err(AnteError::from)?;
+        }
+
+        Ok(())
+    }
+}
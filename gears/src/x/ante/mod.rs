@@ -0,0 +1,437 @@
+use crate::application::handlers::node::TxError;
+use crate::baseapp::mode::RunTxMode;
+use crate::baseapp::options::NodeOptions;
+use crate::context::TransactionalContext;
+use crate::crypto::multisig::MultiSignature;
+use crate::crypto::public::PublicKey;
+use crate::signing::handler::MetadataGetter;
+use crate::signing::renderer::amino_renderer::AminoRenderer;
+use crate::signing::renderer::value_renderer::ValueRenderer;
+use crate::types::denom::Denom;
+use crate::types::gas::descriptor::{
+    ANTE_ED25519_DESCRIPTOR, ANTE_SECKP251K1_DESCRIPTOR, ANTE_SECP256R1_DESCRIPTOR,
+};
+use crate::types::gas::kind::TxKind;
+use crate::types::gas::GasMeter;
+use crate::types::store::gas::errors::GasStoreErrors;
+use crate::x::keepers::auth::AuthKeeper;
+use crate::x::keepers::auth::AuthParams;
+use crate::x::keepers::bank::BankKeeper;
+use crate::{
+    context::QueryableContext,
+    types::tx::{raw::TxWithRaw, TxMessage},
+};
+use core_types::tx::mode_info::{ModeInfo, Multi};
+use core_types::tx::signature::SignatureData;
+use database::Database;
+use kv_store::StoreKey;
+use prost::Message as ProstMessage;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use self::decorators::{
+    ConsumeTxSizeGasDecorator, DeductFeeDecorator, IncrementSequenceDecorator, MempoolFeeDecorator,
+    SetPubKeyDecorator, SigGasConsumeDecorator, SigSequenceDecorator, SigVerificationDecorator,
+    TxTimeoutHeightDecorator, ValidateBasicDecorator, ValidateMemoDecorator,
+    ValidateSigCountDecorator,
+};
+use super::module::Module;
+
+pub mod decorators;
+
+/// Compressed secp256k1 generator point. Stands in for the keys of signers which
+/// aren't known yet when simulating, so their signatures are still charged for.
+const SIM_SECP256K1_PUB_KEY: [u8; 33] = [
+    0x02, 0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87, 0x0b,
+    0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16, 0xf8, 0x17,
+    0x98,
+];
+
+/// Type of the events emitted by the ante handler, which allow to search txs by fee payer,
+/// account sequence and signature
+pub const EVENT_TYPE_TX: &str = "tx";
+pub const ATTRIBUTE_KEY_FEE: &str = "fee";
+pub const ATTRIBUTE_KEY_FEE_PAYER: &str = "fee_payer";
+pub const ATTRIBUTE_KEY_ACCOUNT_SEQUENCE: &str = "acc_seq";
+pub const ATTRIBUTE_KEY_SIGNATURE: &str = "signature";
+
+fn sim_secp256k1_pub_key() -> PublicKey {
+    PublicKey::Secp256k1(
+        SIM_SECP256K1_PUB_KEY
+            .to_vec()
+            .try_into()
+            .expect("generator point is a valid secp256k1 key"),
+    )
+}
+
+pub trait SignGasConsumer: Clone + Sync + Send + 'static {
+    fn consume<AP: AuthParams>(
+        &self,
+        gas_meter: &mut GasMeter<TxKind>,
+        pub_key: PublicKey,
+        data: &SignatureData,
+        params: &AP,
+    ) -> Result<(), GasStoreErrors>;
+}
+
+#[derive(Debug, Clone)]
+pub struct DefaultSignGasConsumer;
+
+impl SignGasConsumer for DefaultSignGasConsumer {
+    fn consume<AP: AuthParams>(
+        &self,
+        gas_meter: &mut GasMeter<TxKind>,
+        pub_key: PublicKey,
+        data: &SignatureData,
+        params: &AP,
+    ) -> Result<(), GasStoreErrors> {
+        // see: https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/auth/ante/sigverify.go#L401
+        match pub_key {
+            PublicKey::Secp256k1(_key) => {
+                let amount = params
+                    .sig_verify_cost_secp256k1()
+                    .try_into()
+                    .map_err(|e| GasStoreErrors::new(&[], e))?; // TODO: Should be okay for now, but needs to be changed
+                gas_meter
+                    .consume_gas(amount, ANTE_SECKP251K1_DESCRIPTOR)
+                    .map_err(|e| GasStoreErrors::new(&[], e))?; // TODO: Should be okay for now, but needs to be changed
+            }
+            PublicKey::Secp256r1(_key) => {
+                let amount = params
+                    .sig_verify_cost_secp256r1()
+                    .try_into()
+                    .map_err(|e| GasStoreErrors::new(&[], e))?; // TODO: Should be okay for now, but needs to be changed
+                gas_meter
+                    .consume_gas(amount, ANTE_SECP256R1_DESCRIPTOR)
+                    .map_err(|e| GasStoreErrors::new(&[], e))?; // TODO: Should be okay for now, but needs to be changed
+            }
+            PublicKey::Ed25519(_key) => {
+                let amount = params
+                    .sig_verify_cost_ed25519()
+                    .try_into()
+                    .map_err(|e| GasStoreErrors::new(&[], e))?; // TODO: Should be okay for now, but needs to be changed
+                gas_meter
+                    .consume_gas(amount, ANTE_ED25519_DESCRIPTOR)
+                    .map_err(|e| GasStoreErrors::new(&[], e))?; // TODO: Should be okay for now, but needs to be changed
+            }
+            PublicKey::Multisig(key) => {
+                // Every key which signed is charged for. Malformed multisignatures are rejected
+                // by the signature verification, signatures of simulated txs may be empty.
+                let ModeInfo::Multi(Multi {
+                    bitarray: Some(bitarray),
+                    mode_infos,
+                }) = &data.mode_info
+                else {
+                    return Ok(());
+                };
+
+                let signatures = MultiSignature::decode(data.signature.as_slice())
+                    .map(|multisig| multisig.signatures)
+                    .unwrap_or_default();
+
+                for (i, (sub_key, mode_info)) in key.signers(bitarray).zip(mode_infos).enumerate() {
+                    let sub_data = SignatureData {
+                        signature: signatures.get(i).cloned().unwrap_or_default(),
+                        sequence: data.sequence,
+                        mode_info: mode_info.clone(),
+                    };
+
+                    self.consume(gas_meter, sub_key.clone(), &sub_data, params)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Step of an ante handler, which runs before the messages of a tx. Steps are combined
+/// into a chain with [`AnteHandlerBuilder`] or as a tuple, which runs its steps in order.
+/// See [`decorators`] for the steps of the default [`BaseAnteHandler`].
+pub trait AnteDecorator<SK: StoreKey> {
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        mode: RunTxMode,
+        node_opt: &NodeOptions,
+        gas_meter: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError>;
+}
+
+/// Two decorators which run one after the other. The second one doesn't run if the first fails.
+#[derive(Debug, Clone)]
+pub struct AnteChain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<SK: StoreKey, A: AnteDecorator<SK>, B: AnteDecorator<SK>> AnteDecorator<SK>
+    for AnteChain<A, B>
+{
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        mode: RunTxMode,
+        node_opt: &NodeOptions,
+        gas_meter: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        self.first.ante_handle(ctx, tx, mode, node_opt, gas_meter)?;
+        self.second.ante_handle(ctx, tx, mode, node_opt, gas_meter)
+    }
+}
+
+/// Assembles a chain of decorators which run in the order they were added, e.g.
+/// ```ignore
+/// let ante_handler = AnteHandlerBuilder::new(MempoolFeeDecorator)
+///     .decorator(CustomDecorator)
+///     .decorator(base_ante_handler)
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnteHandlerBuilder<D> {
+    chain: D,
+}
+
+impl<D> AnteHandlerBuilder<D> {
+    pub fn new(first: D) -> Self {
+        Self { chain: first }
+    }
+
+    /// Add a decorator which runs after all previously added decorators
+    pub fn decorator<N>(self, next: N) -> AnteHandlerBuilder<AnteChain<D, N>> {
+        AnteHandlerBuilder {
+            chain: AnteChain {
+                first: self.chain,
+                second: next,
+            },
+        }
+    }
+
+    pub fn build(self) -> D {
+        self.chain
+    }
+}
+
+/// Decorators of a tuple run in order like an [`AnteChain`] of them, later decorators
+/// don't run if one fails
+macro_rules! impl_ante_decorator_for_tuple {
+    ($($decorator:ident),+) => {
+        impl<SK: StoreKey, $($decorator: AnteDecorator<SK>),+> AnteDecorator<SK> for ($($decorator,)+) {
+            fn ante_handle<
+                DB: Database,
+                M: TxMessage + ValueRenderer + AminoRenderer,
+                CTX: TransactionalContext<DB, SK>,
+            >(
+                &self,
+                ctx: &mut CTX,
+                tx: &TxWithRaw<M>,
+                mode: RunTxMode,
+                node_opt: &NodeOptions,
+                gas_meter: &Arc<RefCell<GasMeter<TxKind>>>,
+            ) -> Result<(), TxError> {
+                #[allow(non_snake_case)]
+                let ($($decorator,)+) = self;
+                $($decorator.ante_handle(ctx, tx, mode, node_opt, gas_meter)?;)+
+
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Implements [`AnteDecorator`] for tuples of up to as many decorators as there are idents
+macro_rules! impl_ante_decorator_for_tuples {
+    ($first:ident $(, $rest:ident)*) => {
+        impl_ante_decorator_for_tuple!($first $(, $rest)*);
+        impl_ante_decorator_for_tuples!($($rest),*);
+    };
+    () => {};
+}
+
+impl_ante_decorator_for_tuples!(
+    D1, D2, D3, D4, D5, D6, D7, D8, D9, D10, D11, D12, D13, D14, D15, D16
+);
+
+/// Steps of [`BaseAnteHandler`], in the order in which they run
+type BaseAnteChain<AK, BK, GC, M> = (
+    MempoolFeeDecorator,
+    ValidateBasicDecorator,
+    TxTimeoutHeightDecorator,
+    ValidateMemoDecorator<AK, M>,
+    ConsumeTxSizeGasDecorator<AK, M>,
+    DeductFeeDecorator<AK, BK, M>,
+    SetPubKeyDecorator<AK, M>,
+    ValidateSigCountDecorator<AK, M>,
+    SigGasConsumeDecorator<AK, GC, M>,
+    SigSequenceDecorator<AK, M>,
+    SigVerificationDecorator<AK, BK, M>,
+    IncrementSequenceDecorator<AK, M>,
+);
+
+/// Default ante handler, runs the steps of the cosmos sdk ante handler. Apps which need
+/// other steps chain it with their own decorators using [`AnteHandlerBuilder`].
+#[derive(Debug, Clone)]
+pub struct BaseAnteHandler<
+    BK: BankKeeper<SK, M>,
+    AK: AuthKeeper<SK, M>,
+    SK: StoreKey,
+    GC,
+    M: Module,
+> {
+    chain: BaseAnteChain<AK, BK, GC, M>,
+    sk: PhantomData<SK>,
+}
+
+impl<
+        AK: AuthKeeper<SK, MOD>,
+        BK: BankKeeper<SK, MOD>,
+        SK: StoreKey,
+        GC: SignGasConsumer,
+        MOD: Module,
+    > BaseAnteHandler<BK, AK, SK, GC, MOD>
+{
+    pub fn new(
+        auth_keeper: AK,
+        bank_keeper: BK,
+        sign_gas_consumer: GC,
+        fee_collector_module: MOD,
+    ) -> BaseAnteHandler<BK, AK, SK, GC, MOD> {
+        // ante.NewSetUpContextDecorator(), // WE not going to implement this in ante. Some logic should be in application
+        let chain = (
+            MempoolFeeDecorator,
+            ValidateBasicDecorator,
+            TxTimeoutHeightDecorator,
+            ValidateMemoDecorator::new(auth_keeper.clone()),
+            ConsumeTxSizeGasDecorator::new(auth_keeper.clone()),
+            DeductFeeDecorator::new(
+                auth_keeper.clone(),
+                bank_keeper.clone(),
+                fee_collector_module,
+            ),
+            // SetPubKeyDecorator must be called before all signature verification decorators
+            SetPubKeyDecorator::new(auth_keeper.clone()),
+            ValidateSigCountDecorator::new(auth_keeper.clone()),
+            SigGasConsumeDecorator::new(auth_keeper.clone(), sign_gas_consumer),
+            SigSequenceDecorator::new(auth_keeper.clone()),
+            SigVerificationDecorator::new(auth_keeper.clone(), bank_keeper),
+            IncrementSequenceDecorator::new(auth_keeper),
+            //  ** ibcante.NewAnteDecorator(opts.IBCkeeper),
+        );
+
+        BaseAnteHandler {
+            chain,
+            sk: PhantomData,
+        }
+    }
+
+    pub fn run<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        mode: RunTxMode,
+        node_opt: NodeOptions,
+        gas_meter: Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        self.ante_handle(ctx, tx, mode, &node_opt, &gas_meter)
+    }
+}
+
+impl<
+        AK: AuthKeeper<SK, MOD>,
+        BK: BankKeeper<SK, MOD>,
+        SK: StoreKey,
+        GC: SignGasConsumer,
+        MOD: Module,
+    > AnteDecorator<SK> for BaseAnteHandler<BK, AK, SK, GC, MOD>
+{
+    fn ante_handle<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        mode: RunTxMode,
+        node_opt: &NodeOptions,
+        gas_meter: &Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        self.chain.ante_handle(ctx, tx, mode, node_opt, gas_meter)
+    }
+}
+
+pub struct MetadataFromState<'a, DB, SK, BK, CTX, MK> {
+    pub bank_keeper: &'a BK,
+    pub ctx: &'a CTX,
+    pub _phantom: PhantomData<(DB, SK, MK)>,
+}
+
+impl<
+        'a,
+        DB: Database,
+        SK: StoreKey,
+        BK: BankKeeper<SK, M>,
+        CTX: QueryableContext<DB, SK>,
+        M: Module,
+    > MetadataGetter for MetadataFromState<'a, DB, SK, BK, CTX, M>
+{
+    type Error = GasStoreErrors; // this is not used here
+
+    fn metadata(
+        &self,
+        denom: &Denom,
+    ) -> Result<Option<crate::types::tx::metadata::Metadata>, Self::Error> {
+        self.bank_keeper.denom_metadata(self.ctx, denom)
+    }
+}
+
+// TODO: uncomment tests
+// #[cfg(test)]
+// mod tests {
+//     use database::MemDB;
+//     use proto_messages::cosmos::auth::v1beta1::{Account, BaseAccount};
+//     use proto_types::AccAddress;
+//     use extensions::testing::UnwrapCorrupt;
+
+//     use crate::store::MultiStore;
+//     use crate::types::tests::get_signed_tx;
+//     use crate::types::InitContext;
+//     use crate::x::auth::Auth;
+
+//     use super::*;
+
+//     #[test]
+//     fn sig_verification_handler_works() {
+//         // TODO: add tests for transactions that are expected to fail
+//         let tx = get_signed_tx();
+
+//         let db = MemDB::new();
+//         let mut store = MultiStore::new(db);
+//         let mut ctx = InitContext::new(&mut store, 0, "unit-testing".into());
+//         let account = BaseAccount {
+//             address: AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux")
+//                 .unwrap_test(),
+//             pub_key: None,
+//             account_number: 1,
+//             sequence: 1,
+//         };
+//         Auth::set_account(&mut ctx.as_any(), Account::Base(account));
+//         set_pub_key_ante_handler(&mut ctx.as_any(), &tx).unwrap_test();
+//         sig_verification_handler(&mut ctx.as_any(), &tx).unwrap_test();
+//     }
+// }
@@ -6,6 +6,7 @@ use thiserror::Error;
 
 use crate::{
     application::handlers::node::TxError,
+    crypto::errors::SigningError,
     signing::{errors::SigningErrors, renderer::amino_renderer::RenderError},
     types::{
         base::errors::CoinsError,
@@ -106,6 +107,14 @@ pub(crate) enum AnteError {
     LegacyAminoJson(#[from] RenderError),
     #[error("failed get sign bytes from tx: {0}")]
     Signing(#[from] SigningErrors),
+    #[error("too many signatures; signatures: {count}, limit: {limit}")]
+    TooManySignatures { count: u64, limit: u64 },
+}
+
+impl From<SigningError> for AnteError {
+    fn from(error: SigningError) -> Self {
+        AnteError::Validation(format!("invalid signature: {error}"))
+    }
 }
 
 impl From<AnteError> for TxError {
@@ -130,6 +139,7 @@ impl From<AnteError> for TxError {
             AnteError::Gas(_) => 10,
             AnteError::LegacyAminoJson(_) => 11,
             AnteError::Signing(_) => 12,
+            AnteError::TooManySignatures { count: _, limit: _ } => 13,
         };
 
         TxError {
@@ -140,6 +150,29 @@ impl From<AnteError> for TxError {
     }
 }
 
+#[derive(Error, Debug)]
+pub(crate) enum PostError {
+    #[error("{0}")]
+    Validation(String),
+    #[error("failed to send coins: {0}")]
+    CoinsSend(#[from] BankKeeperError),
+}
+
+impl From<PostError> for TxError {
+    fn from(error: PostError) -> Self {
+        let code = match &error {
+            PostError::Validation(_) => 1,
+            PostError::CoinsSend(_) => 2,
+        };
+
+        TxError {
+            msg: format!("{error}").into(),
+            code: NonZero::new(code).expect("all > 0"),
+            codespace: "post",
+        }
+    }
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum AuthKeeperError {
     #[error("{0}")]
@@ -14,6 +14,8 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct MockAuthParams {
     pub max_memo_characters: u64,
+    pub tx_sig_limit: u64,
+    pub sig_verify_cost_ed25519: u64,
     pub sig_verify_cost_secp256k1: u64,
     pub tx_cost_per_byte: u64,
 }
@@ -22,7 +24,9 @@ impl Default for MockAuthParams {
     fn default() -> Self {
         Self {
             max_memo_characters: 256,
+            tx_sig_limit: 7,
             tx_cost_per_byte: 10,
+            sig_verify_cost_ed25519: 590,
             sig_verify_cost_secp256k1: 1000,
         }
     }
@@ -33,6 +37,14 @@ impl AuthParams for MockAuthParams {
         self.max_memo_characters
     }
 
+    fn tx_sig_limit(&self) -> u64 {
+        self.tx_sig_limit
+    }
+
+    fn sig_verify_cost_ed25519(&self) -> u64 {
+        self.sig_verify_cost_ed25519
+    }
+
     fn sig_verify_cost_secp256k1(&self) -> u64 {
         self.sig_verify_cost_secp256k1
     }
@@ -2,6 +2,7 @@ pub mod ante;
 pub mod errors;
 pub mod keepers;
 pub mod module;
+pub mod post;
 
 pub mod query;
 pub mod types;
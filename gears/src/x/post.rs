@@ -0,0 +1,147 @@
+//! Post handlers run after all messages of a tx succeeded. Their changes are discarded
+//! together with the changes of the messages when they fail, see
+//! [`ABCIHandler::run_post_handlers`].
+//!
+//! Unlike the ante handler they don't run before the messages, so a tip is only paid for a
+//! tx whose messages succeeded. They still run in CheckTx, where no messages are executed,
+//! so a tx whose tipper can't afford the tip is rejected from the mempool.
+//!
+//! [`ABCIHandler::run_post_handlers`]: crate::application::handlers::node::ABCIHandler::run_post_handlers
+
+use std::marker::PhantomData;
+
+use database::Database;
+use kv_store::StoreKey;
+use tendermint::types::proto::event::{Event, EventAttribute};
+
+use crate::application::handlers::node::TxError;
+use crate::baseapp::mode::RunTxMode;
+use crate::context::TransactionalContext;
+use crate::types::base::coins::format_coins;
+use crate::types::msg::send::MsgSend;
+use crate::types::tx::{raw::TxWithRaw, TxMessage};
+use crate::x::errors::PostError;
+use crate::x::keepers::bank::BankKeeper;
+use crate::x::module::Module;
+
+/// Type of the event emitted when a tip is paid to the fee payer
+pub const EVENT_TYPE_TIP: &str = "tip";
+pub const ATTRIBUTE_KEY_TIP: &str = "tip";
+pub const ATTRIBUTE_KEY_TIPPER: &str = "tipper";
+
+/// Step of a post handler, which runs after the messages of a tx
+pub trait PostDecorator<SK: StoreKey> {
+    fn post_handle<DB: Database, M: TxMessage, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        mode: RunTxMode,
+    ) -> Result<(), TxError>;
+}
+
+/// Pays the tip of a tx from the tipper to the fee payer and emits a `tip` event.
+/// The tipper must be one of the signers, it usually signs in direct aux mode.
+#[derive(Debug, Clone)]
+pub struct TipDecorator<BK, M> {
+    bank_keeper: BK,
+    _module: PhantomData<M>,
+}
+
+impl<BK, M> TipDecorator<BK, M> {
+    pub fn new(bank_keeper: BK) -> Self {
+        Self {
+            bank_keeper,
+            _module: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, MOD: Module, BK: BankKeeper<SK, MOD>> PostDecorator<SK>
+    for TipDecorator<BK, MOD>
+{
+    fn post_handle<DB: Database, M: TxMessage, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        TxWithRaw { tx, .. }: &TxWithRaw<M>,
+        _: RunTxMode,
+    ) -> Result<(), TxError> {
+        let Some(tip) = &tx.auth_info.tip else {
+            return Ok(());
+        };
+
+        if !tx.get_signers().contains(&&tip.tipper) {
+            Err(PostError::Validation(format!(
+                "tipper {} must be a signer of the tx",
+                tip.tipper
+            )))?
+        }
+
+        let Some(amount) = &tip.amount else {
+            return Ok(());
+        };
+
+        let fee_payer = tx.get_fee_payer();
+        self.bank_keeper
+            .send_coins_from_account_to_account(
+                ctx,
+                &MsgSend {
+                    from_address: tip.tipper.clone(),
+                    to_address: fee_payer.clone(),
+                    amount: amount.clone(),
+                },
+            )
+            .map_err(PostError::from)?;
+
+        ctx.push_event(Event::new(
+            EVENT_TYPE_TIP,
+            [
+                EventAttribute::new(ATTRIBUTE_KEY_TIP.into(), format_coins(amount.inner()), true),
+                EventAttribute::new(
+                    ATTRIBUTE_KEY_TIPPER.into(),
+                    tip.tipper.to_string().into(),
+                    true,
+                ),
+            ],
+        ));
+
+        Ok(())
+    }
+}
+
+/// Default post handler, runs the steps of the cosmos sdk post handler
+#[derive(Debug, Clone)]
+pub struct BasePostHandler<BK: BankKeeper<SK, M>, SK: StoreKey, M: Module> {
+    tip: TipDecorator<BK, M>,
+    sk: PhantomData<SK>,
+}
+
+impl<BK: BankKeeper<SK, MOD>, SK: StoreKey, MOD: Module> BasePostHandler<BK, SK, MOD> {
+    pub fn new(bank_keeper: BK) -> Self {
+        Self {
+            tip: TipDecorator::new(bank_keeper),
+            sk: PhantomData,
+        }
+    }
+
+    pub fn run<DB: Database, M: TxMessage, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        mode: RunTxMode,
+    ) -> Result<(), TxError> {
+        self.post_handle(ctx, tx, mode)
+    }
+}
+
+impl<BK: BankKeeper<SK, MOD>, SK: StoreKey, MOD: Module> PostDecorator<SK>
+    for BasePostHandler<BK, SK, MOD>
+{
+    fn post_handle<DB: Database, M: TxMessage, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        mode: RunTxMode,
+    ) -> Result<(), TxError> {
+        self.tip.post_handle(ctx, tx, mode)
+    }
+}
@@ -0,0 +1,136 @@
+//! Compares the concurrent commit of a multistore with committing its stores one after another.
+//! The stores and the number of writes per block roughly follow a busy gaia-rs block.
+//!
+//! Run with `cargo bench -p kv_store --features bench`.
+
+use std::sync::Arc;
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use database::MemDB;
+use kv_store::{bank::multi::ApplicationMultiBank, StoreKey};
+use rand::{distributions::Standard, Rng};
+use strum::{EnumIter, IntoEnumIterator};
+
+criterion_group!(benches, commit_benchmark);
+criterion_main!(benches);
+
+/// Number of blocks committed before measuring, so that the trees aren't empty
+const INIT_BLOCKS: usize = 10;
+const KEY_LENGTH: usize = 32;
+const DATA_LENGTH: usize = 64;
+
+#[derive(EnumIter, Debug, PartialEq, Eq, Hash, Clone)]
+enum GaiaStoreKey {
+    Bank,
+    Auth,
+    Params,
+    Staking,
+    Ibc,
+    Capability,
+    Gov,
+    Upgrade,
+}
+
+impl GaiaStoreKey {
+    /// Writes to the store in a single block
+    fn block_writes(&self) -> usize {
+        match self {
+            GaiaStoreKey::Bank => 2_000,
+            GaiaStoreKey::Auth => 1_000,
+            GaiaStoreKey::Staking => 500,
+            GaiaStoreKey::Ibc => 200,
+            GaiaStoreKey::Gov => 50,
+            GaiaStoreKey::Params | GaiaStoreKey::Capability | GaiaStoreKey::Upgrade => 5,
+        }
+    }
+}
+
+impl StoreKey for GaiaStoreKey {
+    fn name(&self) -> &'static str {
+        match self {
+            GaiaStoreKey::Bank => "bank",
+            GaiaStoreKey::Auth => "acc",
+            GaiaStoreKey::Params => "params",
+            GaiaStoreKey::Staking => "staking",
+            GaiaStoreKey::Ibc => "ibc",
+            GaiaStoreKey::Capability => "capability",
+            GaiaStoreKey::Gov => "gov",
+            GaiaStoreKey::Upgrade => "upgrade",
+        }
+    }
+
+    fn params() -> &'static Self {
+        const PARAM_KEY: GaiaStoreKey = GaiaStoreKey::Params;
+
+        &PARAM_KEY
+    }
+}
+
+type Block = Vec<(GaiaStoreKey, Vec<(Vec<u8>, Vec<u8>)>)>;
+
+fn random_bytes(len: usize) -> Vec<u8> {
+    rand::thread_rng().sample_iter(Standard).take(len).collect()
+}
+
+fn random_block() -> Block {
+    GaiaStoreKey::iter()
+        .map(|sk| {
+            let writes = (0..sk.block_writes())
+                .map(|_| (random_bytes(KEY_LENGTH), random_bytes(DATA_LENGTH)))
+                .collect();
+
+            (sk, writes)
+        })
+        .collect()
+}
+
+fn apply_block(multi_store: &mut ApplicationMultiBank<MemDB, GaiaStoreKey>, block: Block) {
+    for (sk, writes) in block {
+        let kv_store = multi_store.kv_store_mut(&sk);
+        for (key, value) in writes {
+            kv_store.set(key, value);
+        }
+    }
+}
+
+fn prepare_multi_store() -> ApplicationMultiBank<MemDB, GaiaStoreKey> {
+    let mut multi_store =
+        ApplicationMultiBank::new(Arc::new(MemDB::new())).expect("failed to create multi store");
+
+    for _ in 0..INIT_BLOCKS {
+        apply_block(&mut multi_store, random_block());
+        multi_store.commit();
+    }
+
+    multi_store
+}
+
+fn commit_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("commit");
+
+    let mut multi_store = prepare_multi_store();
+    group.bench_function(BenchmarkId::from_parameter("concurrent"), |b| {
+        b.iter_batched(
+            random_block,
+            |block| {
+                apply_block(&mut multi_store, block);
+                black_box(multi_store.commit())
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    let mut multi_store = prepare_multi_store();
+    group.bench_function(BenchmarkId::from_parameter("sequential"), |b| {
+        b.iter_batched(
+            random_block,
+            |block| {
+                apply_block(&mut multi_store, block);
+                black_box(multi_store.commit_sequential())
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
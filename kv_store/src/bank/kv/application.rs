@@ -7,7 +7,7 @@ use std::{
 
 use database::Database;
 use extensions::corruption::UnwrapCorrupt;
-use trees::iavl::Tree;
+use trees::iavl::{ImportedTree, Importer, QueryTree, Tree};
 
 use crate::{
     cache::KVCache,
@@ -147,29 +147,84 @@ impl<DB: Database> ApplicationKVBank<DB> {
         }
     }
 
+    /// Check out the persisted tree at `version` for export
+    pub fn exporter(&self, version: u32) -> Result<QueryTree<DB>, KVStoreError> {
+        Ok(QueryTree::new(&self.persistent(), version)?)
+    }
+
+    /// Start importing exported nodes into an empty store, see [`Tree::importer`]
+    pub fn importer(&self) -> Result<Importer<DB>, KVStoreError> {
+        Ok(self.persistent().importer()?)
+    }
+
+    /// Save an imported tree as `version` of an empty store, discarding any uncommitted cache
+    pub fn finish_import(
+        &mut self,
+        version: u32,
+        imported: ImportedTree,
+    ) -> Result<[u8; 32], KVStoreError> {
+        self.cache_clear();
+
+        Ok(self
+            .persistent
+            .write()
+            .expect(POISONED_LOCK)
+            .finish_import(version, imported)?)
+    }
+
     pub fn commit(&mut self) -> [u8; 32] {
         let (insert, delete) = self.cache.take();
 
         let mut persistent = self.persistent.write().expect(POISONED_LOCK);
 
-        let cache = insert
-            .into_iter()
-            .map(|(key, value)| (key, Some(value)))
-            .chain(delete.into_iter().map(|key| (key, None)))
-            .collect::<BTreeMap<_, _>>();
-
-        for (key, value) in cache {
-            match value {
-                Some(value) => persistent.set(key, value),
-                None => {
-                    let _ = persistent.remove(&key);
-                }
-            }
-        }
+        apply_changes(&mut persistent, insert, delete);
 
         //TODO: is it safe to assume this won't ever error?
         persistent.save_version().unwrap_or_corrupt().0
     }
+
+    /// Hash the store would have if the cache was committed now
+    pub fn working_hash(&self) -> [u8; 32] {
+        let mut tree = self.persistent().clone();
+
+        apply_changes(
+            &mut tree,
+            self.cache.storage.clone(),
+            self.cache.delete.clone(),
+        );
+
+        tree.root_hash()
+    }
+
+    /// Make `version` the version of the first commit of an empty store
+    pub fn set_initial_version(&mut self, version: u32) -> Result<(), KVStoreError> {
+        Ok(self
+            .persistent
+            .write()
+            .expect(POISONED_LOCK)
+            .set_initial_version(version)?)
+    }
+}
+
+fn apply_changes<DB: Database>(
+    tree: &mut Tree<DB>,
+    insert: impl IntoIterator<Item = (Vec<u8>, Vec<u8>)>,
+    delete: impl IntoIterator<Item = Vec<u8>>,
+) {
+    let changes = insert
+        .into_iter()
+        .map(|(key, value)| (key, Some(value)))
+        .chain(delete.into_iter().map(|key| (key, None)))
+        .collect::<BTreeMap<_, _>>();
+
+    for (key, value) in changes {
+        match value {
+            Some(value) => tree.set(key, value),
+            None => {
+                let _ = tree.remove(&key);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
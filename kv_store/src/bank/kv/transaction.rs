@@ -65,6 +65,16 @@ impl<DB: Database> TransactionKVBank<DB> {
         self.block.take()
     }
 
+    /// Branch off a copy of the store which shares persistent storage,
+    /// but whose changes never reach this store
+    pub fn fork(&self) -> Self {
+        Self {
+            persistent: Arc::clone(&self.persistent),
+            tx: self.tx.clone(),
+            block: self.block.clone(),
+        }
+    }
+
     /// Delete value from storage
     #[inline]
     pub fn delete(&mut self, k: &[u8]) -> Option<Vec<u8>> {
@@ -212,6 +222,25 @@ mod tests {
         assert_eq!(None, expected_get)
     }
 
+    /// # What
+    /// Test checks that changes to a fork don't affect the original store
+    #[test]
+    fn fork_is_isolated() {
+        let store = tx_store_build([(0, 0)], [(1, 11)], [(2, 22)], [], []);
+
+        let mut fork = store.fork();
+        fork.set([1], [111]);
+        fork.delete(&[2]);
+        fork.upgrade_cache();
+
+        assert_eq!(Some(vec![111]), fork.get(&[1]));
+        assert_eq!(None, fork.get(&[2]));
+
+        assert_eq!(Some(vec![0]), store.get(&[0]));
+        assert_eq!(Some(vec![11]), store.get(&[1]));
+        assert_eq!(Some(vec![22]), store.get(&[2]));
+    }
+
     /// # What
     /// Test checks that after upgrade cache is correct
     #[test]
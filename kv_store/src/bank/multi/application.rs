@@ -1,10 +1,15 @@
 use std::{collections::HashMap, sync::Arc};
 
 use database::{prefix::PrefixDB, Database};
+use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+use trees::iavl::{ExportedNode, ImportedTree, Importer, NodeExporter, QueryTree};
 
 use crate::{
-    bank::kv::application::ApplicationKVBank, build_prefixed_stores, error::MultiStoreError,
-    hash::StoreInfo, StoreKey,
+    bank::kv::application::ApplicationKVBank,
+    build_prefixed_stores,
+    error::{KVStoreError, MultiStoreError, MultiStoreImportError, KEY_EXISTS_MSG},
+    hash::StoreInfo,
+    StoreKey,
 };
 
 use super::*;
@@ -78,17 +83,42 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
         }
     }
 
+    /// Commit every store and return the hash of the new multistore state.
+    ///
+    /// Each store is an independent tree backed by its own prefixed database,
+    /// so stores are committed concurrently on the rayon thread pool, which is
+    /// bounded by the number of cores and reused between blocks. The order of
+    /// the store infos doesn't matter, they're sorted when hashed.
     pub fn commit(&mut self) -> [u8; 32] {
-        let mut store_infos = vec![];
-        for (store, kv_store) in &mut self.backend.0 {
-            let store_info = StoreInfo {
+        let store_infos = self
+            .backend
+            .0
+            .par_iter_mut()
+            .map(|(store, kv_store)| StoreInfo {
                 name: store.name().into(),
                 hash: kv_store.commit(),
-            };
+            })
+            .collect();
+
+        self.finish_commit(store_infos)
+    }
 
-            store_infos.push(store_info)
+    /// Commit every store one after another. Produces the same hash as [`Self::commit`],
+    /// kept as a baseline for tests and benchmarks of the concurrent commit.
+    #[cfg(any(test, feature = "bench"))]
+    pub fn commit_sequential(&mut self) -> [u8; 32] {
+        let mut store_infos = Vec::with_capacity(self.backend.0.len());
+        for (store, kv_store) in &mut self.backend.0 {
+            store_infos.push(StoreInfo {
+                name: store.name().into(),
+                hash: kv_store.commit(),
+            })
         }
 
+        self.finish_commit(store_infos)
+    }
+
+    fn finish_commit(&mut self, store_infos: Vec<StoreInfo>) -> [u8; 32] {
         let hash = crate::hash::hash_store_infos(store_infos);
 
         self.head_commit_hash = hash;
@@ -99,9 +129,355 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
         hash
     }
 
+    /// Hash the multistore would have if the caches of all stores were committed now
+    pub fn working_hash(&self) -> [u8; 32] {
+        let store_infos = self
+            .backend
+            .0
+            .iter()
+            .map(|(store, kv_store)| StoreInfo {
+                name: store.name().into(),
+                hash: kv_store.working_hash(),
+            })
+            .collect();
+
+        crate::hash::hash_store_infos(store_infos)
+    }
+
+    /// Make `version` the version of the first commit. Used by chains which start
+    /// at a height other than 1, only allowed before anything was committed.
+    pub fn set_initial_version(&mut self, version: u32) -> Result<(), MultiStoreError<SK>> {
+        for (sk, kv_store) in &mut self.backend.0 {
+            kv_store
+                .set_initial_version(version)
+                .map_err(|err| MultiStoreError {
+                    sk: sk.clone(),
+                    err,
+                })?;
+        }
+
+        self.head_version = version.saturating_sub(1);
+
+        Ok(())
+    }
+
+    /// Check out every store at `version` so that it can be exported without holding the multistore.
+    pub fn exporter(&self, version: u32) -> Result<MultiBankExporter<DB>, MultiStoreError<SK>> {
+        let mut stores = Vec::with_capacity(self.backend.0.len());
+        for (sk, store) in &self.backend.0 {
+            let tree = store.exporter(version).map_err(|err| MultiStoreError {
+                sk: sk.clone(),
+                err,
+            })?;
+
+            stores.push((sk.name(), tree));
+        }
+
+        stores.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Ok(MultiBankExporter { version, stores })
+    }
+
+    /// Start importing exported stores into an empty multistore.
+    /// The multistore only changes once the import is passed to [`Self::finish_import`].
+    pub fn importer(&self) -> Result<MultiBankImporter<DB, SK>, MultiStoreImportError> {
+        let mut pending = HashMap::with_capacity(self.backend.0.len());
+        for (sk, kv_store) in &self.backend.0 {
+            let importer = kv_store.importer().map_err(|KVStoreError::Tree(err)| {
+                MultiStoreImportError::Tree {
+                    store: sk.name().to_owned(),
+                    err,
+                }
+            })?;
+
+            pending.insert(sk.clone(), importer);
+        }
+
+        Ok(MultiBankImporter {
+            pending,
+            current: None,
+            imported: HashMap::with_capacity(self.backend.0.len()),
+        })
+    }
+
+    /// Save imported stores as `version`. Returns the new commit hash.
+    pub fn finish_import(
+        &mut self,
+        version: u32,
+        ImportedMultiBank { mut stores, hash }: ImportedMultiBank<SK>,
+    ) -> Result<[u8; 32], MultiStoreImportError> {
+        for (sk, kv_store) in &mut self.backend.0 {
+            let tree = stores.remove(sk).expect(KEY_EXISTS_MSG);
+
+            kv_store
+                .finish_import(version, tree)
+                .map_err(|KVStoreError::Tree(err)| MultiStoreImportError::Tree {
+                    store: sk.name().to_owned(),
+                    err,
+                })?;
+        }
+
+        self.head_commit_hash = hash;
+        self.head_version = version;
+
+        Ok(hash)
+    }
+
+    /// Import exported stores into an empty multistore as `version`.
+    /// Stores missing from `stores` are imported empty. Returns the new commit hash.
+    pub fn import<N: IntoIterator<Item = ExportedNode>>(
+        &mut self,
+        version: u32,
+        stores: impl IntoIterator<Item = (String, N)>,
+    ) -> Result<[u8; 32], MultiStoreImportError> {
+        let mut importer = self.importer()?;
+        for (name, nodes) in stores {
+            importer.store(&name)?;
+
+            for node in nodes {
+                importer.node(node)?;
+            }
+        }
+
+        let imported = importer.finish()?;
+
+        self.finish_import(version, imported)
+    }
+
     pub fn clear_cache(&mut self) {
         for store in self.backend.0.values_mut() {
             store.cache_clear();
         }
     }
 }
+
+/// Stores of a multistore checked out at a single version
+#[derive(Debug)]
+pub struct MultiBankExporter<DB> {
+    version: u32,
+    stores: Vec<(&'static str, QueryTree<PrefixDB<DB>>)>,
+}
+
+impl<DB: Database> MultiBankExporter<DB> {
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Export the nodes of every store, ordered by store name
+    pub fn export(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, NodeExporter<'_, PrefixDB<DB>>)> + '_ {
+        self.stores
+            .iter()
+            .map(|(name, tree)| (*name, tree.export()))
+    }
+}
+
+/// Import of exported stores into an empty multistore, see [`MultiBank::importer`]
+#[derive(Debug)]
+pub struct MultiBankImporter<DB, SK> {
+    pending: HashMap<SK, Importer<PrefixDB<DB>>>,
+    current: Option<(SK, Importer<PrefixDB<DB>>)>,
+    imported: HashMap<SK, ImportedTree>,
+}
+
+impl<DB: Database, SK: StoreKey> MultiBankImporter<DB, SK> {
+    /// Start importing the nodes of store `name`. Every store can only be imported once.
+    pub fn store(&mut self, name: &str) -> Result<(), MultiStoreImportError> {
+        self.finish_store()?;
+
+        let sk = match self.pending.keys().find(|sk| sk.name() == name) {
+            Some(sk) => sk.clone(),
+            None if self.imported.keys().any(|sk| sk.name() == name) => {
+                return Err(MultiStoreImportError::DuplicateStore(name.to_owned()))
+            }
+            None => return Err(MultiStoreImportError::UnknownStore(name.to_owned())),
+        };
+
+        let importer = self.pending.remove(&sk).expect(KEY_EXISTS_MSG);
+        self.current = Some((sk, importer));
+
+        Ok(())
+    }
+
+    /// Add the next exported node of the current store
+    pub fn node(&mut self, node: ExportedNode) -> Result<(), MultiStoreImportError> {
+        let (sk, importer) = self
+            .current
+            .as_mut()
+            .ok_or(MultiStoreImportError::NodeBeforeStore)?;
+
+        importer
+            .add(node)
+            .map_err(|err| MultiStoreImportError::Tree {
+                store: sk.name().to_owned(),
+                err,
+            })
+    }
+
+    /// Finish the import, stores without any imported nodes are imported empty
+    pub fn finish(mut self) -> Result<ImportedMultiBank<SK>, MultiStoreImportError> {
+        self.finish_store()?;
+
+        for (sk, importer) in self.pending {
+            let tree = importer
+                .finish()
+                .map_err(|err| MultiStoreImportError::Tree {
+                    store: sk.name().to_owned(),
+                    err,
+                })?;
+
+            self.imported.insert(sk, tree);
+        }
+
+        let store_infos = self
+            .imported
+            .iter()
+            .map(|(sk, tree)| StoreInfo {
+                name: sk.name().into(),
+                hash: tree.root_hash(),
+            })
+            .collect();
+
+        Ok(ImportedMultiBank {
+            hash: crate::hash::hash_store_infos(store_infos),
+            stores: self.imported,
+        })
+    }
+
+    fn finish_store(&mut self) -> Result<(), MultiStoreImportError> {
+        if let Some((sk, importer)) = self.current.take() {
+            let tree = importer
+                .finish()
+                .map_err(|err| MultiStoreImportError::Tree {
+                    store: sk.name().to_owned(),
+                    err,
+                })?;
+
+            self.imported.insert(sk, tree);
+        }
+
+        Ok(())
+    }
+}
+
+/// Imported stores which aren't part of a multistore yet
+#[derive(Debug)]
+pub struct ImportedMultiBank<SK> {
+    stores: HashMap<SK, ImportedTree>,
+    hash: [u8; 32],
+}
+
+impl<SK> ImportedMultiBank<SK> {
+    /// Commit hash the multistore will have once the import is finished
+    pub fn hash(&self) -> [u8; 32] {
+        self.hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use database::MemDB;
+    use strum::{EnumIter, IntoEnumIterator};
+
+    use super::*;
+
+    #[derive(EnumIter, Debug, PartialEq, Eq, Hash, Clone)]
+    enum TestStoreKey {
+        Bank,
+        Auth,
+        Params,
+    }
+
+    impl StoreKey for TestStoreKey {
+        fn name(&self) -> &'static str {
+            match self {
+                TestStoreKey::Bank => "bank",
+                TestStoreKey::Auth => "acc",
+                TestStoreKey::Params => "params",
+            }
+        }
+
+        fn params() -> &'static Self {
+            const PARAM_KEY: TestStoreKey = TestStoreKey::Params;
+
+            &PARAM_KEY
+        }
+    }
+
+    #[test]
+    fn commit_hash_matches_sequential_commit() {
+        let mut concurrent = ApplicationMultiBank::<_, TestStoreKey>::new(Arc::new(MemDB::new()))
+            .expect("failed to create multi store");
+        let mut sequential = ApplicationMultiBank::<_, TestStoreKey>::new(Arc::new(MemDB::new()))
+            .expect("failed to create multi store");
+
+        for version in 1..=3u8 {
+            for multi_store in [&mut concurrent, &mut sequential] {
+                for (i, sk) in TestStoreKey::iter().enumerate() {
+                    // leave the auth store unchanged in some versions
+                    if sk == TestStoreKey::Auth && version == 2 {
+                        continue;
+                    }
+
+                    let kv_store = multi_store.kv_store_mut(&sk);
+                    kv_store.set([i as u8, version], [1, 2, 3]);
+                    kv_store.set([i as u8, version, 1], [4, 5, 6]);
+                }
+            }
+
+            let hash = concurrent.commit();
+
+            assert_eq!(hash, sequential.commit_sequential());
+            assert_eq!(concurrent.head_version(), version as u32);
+            assert_eq!(concurrent.head_commit_hash(), hash);
+        }
+    }
+
+    #[test]
+    fn working_hash_matches_commit_hash() {
+        let mut multi_store = ApplicationMultiBank::<_, TestStoreKey>::new(Arc::new(MemDB::new()))
+            .expect("failed to create multi store");
+
+        multi_store
+            .set_initial_version(5)
+            .expect("nothing committed yet");
+
+        multi_store.kv_store_mut(&TestStoreKey::Bank).set([1], [2]);
+        multi_store.kv_store_mut(&TestStoreKey::Auth).set([3], [4]);
+        multi_store.kv_store_mut(&TestStoreKey::Auth).delete(&[5]);
+
+        let working_hash = multi_store.working_hash();
+
+        assert_eq!(multi_store.commit(), working_hash);
+        assert_eq!(multi_store.head_version(), 5);
+        assert!(multi_store.set_initial_version(10).is_err());
+    }
+
+    #[test]
+    fn export_import_round_trip() {
+        let mut multi_store = ApplicationMultiBank::<_, TestStoreKey>::new(Arc::new(MemDB::new()))
+            .expect("failed to create multi store");
+
+        multi_store.kv_store_mut(&TestStoreKey::Bank).set([1], [2]);
+        multi_store.commit();
+        multi_store.kv_store_mut(&TestStoreKey::Auth).set([3], [4]);
+        let hash = multi_store.commit();
+
+        let exporter = multi_store.exporter(2).expect("version exists");
+        let exported = exporter
+            .export()
+            .filter(|(name, _)| *name != TestStoreKey::Params.name())
+            .map(|(name, nodes)| (name.to_owned(), nodes));
+
+        let mut imported = ApplicationMultiBank::<_, TestStoreKey>::new(Arc::new(MemDB::new()))
+            .expect("failed to create multi store");
+
+        assert_eq!(imported.import(2, exported), Ok(hash));
+        assert_eq!(imported.head_version(), 2);
+        assert_eq!(
+            imported.kv_store(&TestStoreKey::Bank).get(&[1]),
+            Some(vec![2])
+        );
+    }
+}
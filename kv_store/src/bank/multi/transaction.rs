@@ -40,6 +40,22 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, TransactionStore<DB, SK>> {
         }
     }
 
+    /// Branch off a copy of the multistore whose changes are thrown away when dropped
+    pub fn fork(&self) -> Self {
+        Self {
+            head_version: self.head_version,
+            head_commit_hash: self.head_commit_hash,
+            backend: TransactionStore(
+                self.backend
+                    .0
+                    .iter()
+                    .map(|(sk, store)| (sk.to_owned(), store.fork()))
+                    .collect(),
+            ),
+            _marker: PhantomData,
+        }
+    }
+
     pub fn append_block_cache(&mut self, other: &mut ApplicationMultiBank<DB, SK>) {
         for (sk, store) in &mut self.backend.0 {
             store.append_block_cache(other.kv_store_mut(sk))
@@ -26,5 +26,17 @@ impl<SK: StoreKey> std::fmt::Display for MultiStoreError<SK> {
     }
 }
 
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum MultiStoreImportError {
+    #[error("unknown store in import: {0}")]
+    UnknownStore(String),
+    #[error("store imported more than once: {0}")]
+    DuplicateStore(String),
+    #[error("node imported before any store")]
+    NodeBeforeStore,
+    #[error("failed to import store {store}: {err}")]
+    Tree { store: String, err: trees::Error },
+}
+
 pub const KEY_EXISTS_MSG: &str = "a store for every key is guaranteed to exist";
 pub const POISONED_LOCK: &str = "poisoned lock";
@@ -1,5 +1,9 @@
 use integer_encoding::VarInt;
 use sha2::{Digest, Sha256};
+use trees::{
+    merkle::LEAF_PREFIX,
+    proof::{ExistenceProof, LeafOp},
+};
 
 #[derive(Debug)]
 pub struct StoreInfo {
@@ -49,6 +53,25 @@ pub fn hash_store_infos(store_infos: Vec<StoreInfo>) -> [u8; 32] {
     trees::merkle::root_hash(&byte_pairs)
 }
 
+/// Proof of the root hash of store `name` against the hash of all `store_infos`
+pub fn store_info_proof(store_infos: Vec<StoreInfo>, name: &str) -> Option<ExistenceProof> {
+    let hash = store_infos.iter().find(|info| info.name == name)?.hash;
+
+    let mut pairs: Vec<Pair> = store_infos.into_iter().map(|info| info.into()).collect();
+    pairs.sort();
+    let index = pairs.iter().position(|pair| pair.key == name.as_bytes())?;
+    let byte_pairs: Vec<Vec<u8>> = pairs.into_iter().map(|pair| pair.to_bytes()).collect();
+
+    Some(ExistenceProof {
+        key: name.as_bytes().to_vec(),
+        value: hash.to_vec(),
+        leaf: LeafOp {
+            prefix: LEAF_PREFIX.to_vec(),
+        },
+        path: trees::merkle::proof_path(&byte_pairs, index),
+    })
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -95,4 +118,25 @@ mod tests {
             "9328960b097a043bd62b6d22075084251688dff84d004743d0666f4ecdd5b86d"
         );
     }
+
+    #[test]
+    fn store_info_proof_hashes_to_commit_hash() {
+        let store_infos = || {
+            ["bob", "alice", "carol"]
+                .into_iter()
+                .enumerate()
+                .map(|(i, name)| StoreInfo {
+                    name: name.to_string(),
+                    hash: [i as u8; 32],
+                })
+                .collect::<Vec<_>>()
+        };
+        let commit_hash = hash_store_infos(store_infos());
+
+        let proof = store_info_proof(store_infos(), "bob").unwrap_test();
+        assert_eq!(proof.value, vec![0; 32]);
+        assert_eq!(proof.calculate_root(), commit_hash);
+
+        assert_eq!(store_info_proof(store_infos(), "dave"), None);
+    }
 }
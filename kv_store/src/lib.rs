@@ -12,6 +12,9 @@ pub mod store;
 mod hash;
 mod utils;
 
+pub use trees::iavl::ExportedNode;
+pub use trees::proof;
+
 pub(crate) const TREE_CACHE_SIZE: usize = 100_000;
 
 #[derive(Debug, Clone, Hash, Default, PartialEq, Eq, PartialOrd, Ord)]
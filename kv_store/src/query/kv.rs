@@ -1,7 +1,7 @@
 use std::ops::RangeBounds;
 
 use database::Database;
-use trees::iavl::QueryTree;
+use trees::{iavl::QueryTree, proof::CommitmentProof};
 
 use crate::store::prefix::immutable::ImmutablePrefixStore;
 
@@ -26,6 +26,15 @@ impl<DB: Database> QueryKVStore<DB> {
         self.0.get(k.as_ref())
     }
 
+    pub fn root_hash(&self) -> [u8; 32] {
+        self.0.root_hash()
+    }
+
+    /// Proof of existence or absence of `k` against the root hash of the store
+    pub fn proof<R: AsRef<[u8]> + ?Sized>(&self, k: &R) -> CommitmentProof {
+        self.0.get_proof(k.as_ref())
+    }
+
     pub fn prefix_store<I: IntoIterator<Item = u8>>(
         &self,
         prefix: I,
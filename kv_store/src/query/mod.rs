@@ -1,12 +1,16 @@
 use std::collections::HashMap;
 
 use database::{prefix::PrefixDB, Database};
-use trees::iavl::QueryTree;
+use trees::{
+    iavl::QueryTree,
+    proof::{CommitmentProof, ExistenceProof},
+};
 
 use crate::{
     bank::kv::application::ApplicationKVBank,
     bank::multi::{ApplicationMultiBank, MultiBankBackend},
     error::{KVStoreError, KEY_EXISTS_MSG, POISONED_LOCK},
+    hash::{store_info_proof, StoreInfo},
     StoreKey,
 };
 
@@ -32,6 +36,15 @@ impl<'a, DB, SK> From<&'a ApplicationMultiBank<DB, SK>> for QueryStoreOptions<'a
     }
 }
 
+/// Proof of a key in one of the stores against the commit hash of the multistore
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiStoreProof {
+    /// Proof of existence or absence of the key in the IAVL tree of the store
+    pub store: CommitmentProof,
+    /// Proof of the root hash of the store against the commit hash
+    pub commit: ExistenceProof,
+}
+
 #[derive(Debug)]
 pub struct QueryMultiStore<DB, SK> {
     pub(crate) head_version: u32,
@@ -74,6 +87,24 @@ impl<DB: Database, SK: StoreKey> QueryMultiStore<DB, SK> {
         ))
     }
 
+    /// Proof of `key` in the store of `store_key` at the checked out version
+    pub fn proof(&self, store_key: &SK, key: &[u8]) -> MultiStoreProof {
+        let store_infos = self
+            .inner
+            .iter()
+            .map(|(store_key, store)| StoreInfo {
+                name: store_key.name().to_owned(),
+                hash: store.root_hash(),
+            })
+            .collect();
+
+        MultiStoreProof {
+            store: self.inner.get(store_key).expect(KEY_EXISTS_MSG).proof(key),
+            commit: store_info_proof(store_infos, store_key.name())
+                .expect("store infos are built from all stores"),
+        }
+    }
+
     pub fn head_version(&self) -> u32 {
         self.head_version
     }
@@ -0,0 +1,76 @@
+#![cfg(not(doctest))]
+#![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"),"/","Readme.md"))]
+
+use darling::{ast::Data, util::Flag, FromDeriveInput, FromField};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, parse_macro_input, DeriveInput, Generics, Ident};
+
+#[derive(FromField)]
+#[darling(attributes(event), forward_attrs(allow, doc, cfg))]
+struct EventField {
+    ident: Option<Ident>,
+    #[darling(default)]
+    no_index: Flag,
+}
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(event), forward_attrs(allow, doc, cfg))]
+#[darling(supports(struct_named))]
+struct EventArg {
+    ident: Ident,
+    generics: Generics,
+    data: Data<(), EventField>,
+    kind: String,
+    #[darling(default)]
+    gears: Flag,
+}
+
+#[proc_macro_derive(TypedEvent, attributes(event))]
+pub fn event_derive(input: TokenStream) -> TokenStream {
+    expand_event(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_event(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let EventArg {
+        ident,
+        generics,
+        data,
+        kind,
+        gears,
+    } = EventArg::from_derive_input(&input)?;
+
+    let crate_prefix = match gears.is_present() {
+        true => quote! { crate },
+        false => quote! { ::gears },
+    };
+
+    let not_indexed = data
+        .take_struct()
+        .expect("only named structs are supported")
+        .fields
+        .into_iter()
+        .filter(|field| field.no_index.is_present())
+        .filter_map(|field| field.ident)
+        .map(|ident| ident.unraw().to_string())
+        .collect::<Vec<_>>();
+
+    let index = match not_indexed.is_empty() {
+        true => quote! { true },
+        false => quote! { !matches!(key, #(#not_indexed)|*) },
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_prefix::types::event::TypedEvent for #ident #ty_generics #where_clause {
+            const KIND: &'static str = #kind;
+
+            fn index(#[allow(unused_variables)] key: &str) -> bool {
+                #index
+            }
+        }
+    })
+}
@@ -1,4 +1,5 @@
 pub mod errors;
+pub mod v0_38;
 pub use tendermint_abci::ServerBuilder;
 
 pub use tendermint_informal::abci::Event;
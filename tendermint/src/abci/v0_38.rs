@@ -0,0 +1,618 @@
+//! Server for CometBFT 0.38 nodes, which speak ABCI++ instead of the 0.34 protocol.
+//!
+//! Messages whose encoding didn't change since 0.34 are converted through their 0.34
+//! protobuf types, so the conversions of the domain types are shared by both protocols.
+
+use prost::Message;
+use serde::de::DeserializeOwned;
+use tendermint_abci_v0_38::Application;
+use tendermint_proto::abci as v0_34;
+use tendermint_proto_v0_38::google::protobuf::Timestamp as ProtoTimestamp;
+use tendermint_proto_v0_38::v0_38::types::BlockIdFlag;
+use tracing::info;
+
+pub use tendermint_abci_v0_38::{Error, ServerBuilder};
+pub use tendermint_proto_v0_38::v0_38::abci as proto;
+
+use crate::{
+    application::ABCIPlusPlusApplication,
+    error::Error as DataError,
+    ext::UnwrapInvalid,
+    types::{
+        proto::{
+            event::{Event, EventAttribute},
+            info::{Evidence, ExtendedCommitInfo, ExtendedVoteInfo, LastCommitInfo, VoteInfo},
+            validator::Validator,
+        },
+        request::{
+            finalize_block::RequestFinalizeBlock,
+            proposal::{RequestPrepareProposal, RequestProcessProposal},
+            vote_extension::{RequestExtendVote, RequestVerifyVoteExtension},
+        },
+        response::{
+            check_tx::ResponseCheckTx,
+            deliver_tx::ResponseDeliverTx,
+            finalize_block::ResponseFinalizeBlock,
+            proposal::{ProposalStatus, ResponsePrepareProposal, ResponseProcessProposal},
+            vote_extension::{ResponseExtendVote, ResponseVerifyVoteExtension, VerifyStatus},
+            ResponseCommit,
+        },
+        time::timestamp::Timestamp,
+    },
+};
+
+/// Serves an [`ABCIPlusPlusApplication`] to a CometBFT 0.38 node
+#[derive(Debug, Clone)]
+pub struct ABCIPlusPlus<T: ABCIPlusPlusApplication<G>, G> {
+    handler: T,
+    _phantom: std::marker::PhantomData<G>,
+}
+
+impl<G, T: ABCIPlusPlusApplication<G>> From<T> for ABCIPlusPlus<T, G> {
+    fn from(handler: T) -> Self {
+        Self {
+            handler,
+            _phantom: Default::default(),
+        }
+    }
+}
+
+impl<G: DeserializeOwned + Send + Clone + 'static, T: ABCIPlusPlusApplication<G>> Application
+    for ABCIPlusPlus<T, G>
+{
+    fn echo(&self, request: proto::RequestEcho) -> proto::ResponseEcho {
+        info!("Got echo request");
+
+        let request: v0_34::RequestEcho = transcode(&request);
+        let response: v0_34::ResponseEcho = T::echo(&self.handler, request.into()).into();
+        transcode(&response)
+    }
+
+    fn info(&self, request: proto::RequestInfo) -> proto::ResponseInfo {
+        info!(
+            "Got info request. CometBFT version: {}; Block version: {}; P2P version: {}; ABCI version: {}",
+            request.version, request.block_version, request.p2p_version, request.abci_version
+        );
+
+        let request: v0_34::RequestInfo = transcode(&request);
+        let response: v0_34::ResponseInfo = T::info(&self.handler, request.into()).into();
+        transcode(&response)
+    }
+
+    fn init_chain(&self, request: proto::RequestInitChain) -> proto::ResponseInitChain {
+        info!("Got init chain request");
+
+        let request: v0_34::RequestInitChain = transcode(&request);
+        let response: v0_34::ResponseInitChain =
+            T::init_chain(&self.handler, request.try_into().unwrap_or_invalid()).into();
+        transcode(&response)
+    }
+
+    fn query(&self, request: proto::RequestQuery) -> proto::ResponseQuery {
+        info!("Got query request to: {}", request.path);
+
+        let request: v0_34::RequestQuery = transcode(&request);
+        let response: v0_34::ResponseQuery = T::query(&self.handler, request.into()).into();
+        transcode(&response)
+    }
+
+    fn check_tx(&self, request: proto::RequestCheckTx) -> proto::ResponseCheckTx {
+        info!("Got check tx request");
+
+        let request: v0_34::RequestCheckTx = transcode(&request);
+        T::check_tx(&self.handler, request.into()).into()
+    }
+
+    fn flush(&self) -> proto::ResponseFlush {
+        info!("Got flush request");
+
+        proto::ResponseFlush {}
+    }
+
+    fn commit(&self) -> proto::ResponseCommit {
+        info!("Got commit request");
+
+        // the app hash was already returned by FinalizeBlock
+        let ResponseCommit {
+            data: _,
+            retain_height,
+        } = T::commit(&self.handler);
+
+        proto::ResponseCommit {
+            retain_height: retain_height.into(),
+        }
+    }
+
+    fn list_snapshots(&self) -> proto::ResponseListSnapshots {
+        info!("Got list snapshots request");
+
+        let response: v0_34::ResponseListSnapshots = T::list_snapshots(&self.handler).into();
+        transcode(&response)
+    }
+
+    fn offer_snapshot(&self, request: proto::RequestOfferSnapshot) -> proto::ResponseOfferSnapshot {
+        info!("Got offer snapshot request");
+
+        let request: v0_34::RequestOfferSnapshot = transcode(&request);
+        let response: v0_34::ResponseOfferSnapshot =
+            T::offer_snapshot(&self.handler, request.into()).into();
+        transcode(&response)
+    }
+
+    fn load_snapshot_chunk(
+        &self,
+        request: proto::RequestLoadSnapshotChunk,
+    ) -> proto::ResponseLoadSnapshotChunk {
+        info!("Got load snapshot chunk request");
+
+        let request: v0_34::RequestLoadSnapshotChunk = transcode(&request);
+        let response: v0_34::ResponseLoadSnapshotChunk =
+            T::load_snapshot_chunk(&self.handler, request.into()).into();
+        transcode(&response)
+    }
+
+    fn apply_snapshot_chunk(
+        &self,
+        request: proto::RequestApplySnapshotChunk,
+    ) -> proto::ResponseApplySnapshotChunk {
+        info!("Got apply snapshot chunk request");
+
+        let request: v0_34::RequestApplySnapshotChunk = transcode(&request);
+        let response: v0_34::ResponseApplySnapshotChunk =
+            T::apply_snapshot_chunk(&self.handler, request.into()).into();
+        transcode(&response)
+    }
+
+    fn extend_vote(&self, request: proto::RequestExtendVote) -> proto::ResponseExtendVote {
+        info!("Got extend vote request");
+
+        T::extend_vote(&self.handler, request.try_into().unwrap_or_invalid()).into()
+    }
+
+    fn verify_vote_extension(
+        &self,
+        request: proto::RequestVerifyVoteExtension,
+    ) -> proto::ResponseVerifyVoteExtension {
+        info!("Got verify vote extension request");
+
+        T::verify_vote_extension(&self.handler, request.try_into().unwrap_or_invalid()).into()
+    }
+
+    fn prepare_proposal(
+        &self,
+        request: proto::RequestPrepareProposal,
+    ) -> proto::ResponsePrepareProposal {
+        info!("Got prepare proposal request");
+
+        T::prepare_proposal(&self.handler, request.try_into().unwrap_or_invalid()).into()
+    }
+
+    fn process_proposal(
+        &self,
+        request: proto::RequestProcessProposal,
+    ) -> proto::ResponseProcessProposal {
+        info!("Got process proposal request");
+
+        T::process_proposal(&self.handler, request.try_into().unwrap_or_invalid()).into()
+    }
+
+    fn finalize_block(&self, request: proto::RequestFinalizeBlock) -> proto::ResponseFinalizeBlock {
+        info!("Got finalize block request");
+
+        T::finalize_block(&self.handler, request.try_into().unwrap_or_invalid()).into()
+    }
+}
+
+/// Converts a message to the protobuf type of another ABCI version with the same encoding
+fn transcode<T: Message, U: Message + Default>(message: &T) -> U {
+    U::decode(message.encode_to_vec().as_slice())
+        .expect("the encoding of the message is the same in both ABCI versions")
+}
+
+fn height(height: i64) -> Result<u32, DataError> {
+    height
+        .try_into()
+        .map_err(|e| DataError::InvalidData(format!("invalid height {height}: {e}")))
+}
+
+fn timestamp(time: Option<ProtoTimestamp>) -> Result<Timestamp, DataError> {
+    let time = time.ok_or(DataError::InvalidData("time is missing".into()))?;
+
+    Timestamp::try_new(time.seconds, time.nanos).map_err(|e| DataError::InvalidData(e.to_string()))
+}
+
+fn validator(validator: Option<proto::Validator>) -> Result<Validator, DataError> {
+    let validator = validator.ok_or(DataError::InvalidData("validator is missing".into()))?;
+
+    transcode::<_, v0_34::Validator>(&validator).try_into()
+}
+
+/// Misbehavior is encoded like the evidence of 0.34
+fn misbehavior(misbehavior: Vec<proto::Misbehavior>) -> Result<Vec<Evidence>, DataError> {
+    misbehavior
+        .iter()
+        .map(|misbehavior| transcode::<_, v0_34::Evidence>(misbehavior).try_into())
+        .collect()
+}
+
+/// Like the Cosmos SDK, only validators whose vote is absent didn't sign the block
+fn signed_last_block(block_id_flag: i32) -> bool {
+    block_id_flag != BlockIdFlag::Absent as i32
+}
+
+impl TryFrom<proto::CommitInfo> for LastCommitInfo {
+    type Error = DataError;
+
+    fn try_from(
+        proto::CommitInfo { round, votes }: proto::CommitInfo,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            round,
+            votes: votes
+                .into_iter()
+                .map(|vote| {
+                    Ok(VoteInfo {
+                        validator: validator(vote.validator)?,
+                        signed_last_block: signed_last_block(vote.block_id_flag),
+                    })
+                })
+                .collect::<Result<_, DataError>>()?,
+        })
+    }
+}
+
+impl TryFrom<proto::ExtendedCommitInfo> for ExtendedCommitInfo {
+    type Error = DataError;
+
+    fn try_from(
+        proto::ExtendedCommitInfo { round, votes }: proto::ExtendedCommitInfo,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            round,
+            votes: votes
+                .into_iter()
+                .map(|vote| {
+                    Ok(ExtendedVoteInfo {
+                        validator: validator(vote.validator)?,
+                        signed_last_block: signed_last_block(vote.block_id_flag),
+                        vote_extension: vote.vote_extension,
+                        extension_signature: vote.extension_signature,
+                    })
+                })
+                .collect::<Result<_, DataError>>()?,
+        })
+    }
+}
+
+impl TryFrom<proto::RequestPrepareProposal> for RequestPrepareProposal {
+    type Error = DataError;
+
+    fn try_from(
+        proto::RequestPrepareProposal {
+            max_tx_bytes,
+            txs,
+            local_last_commit,
+            misbehavior: evidence,
+            height: block_height,
+            time,
+            next_validators_hash,
+            proposer_address,
+        }: proto::RequestPrepareProposal,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            max_tx_bytes,
+            txs,
+            local_last_commit: local_last_commit
+                .ok_or(DataError::InvalidData(
+                    "local last commit is missing".into(),
+                ))?
+                .try_into()?,
+            misbehavior: misbehavior(evidence)?,
+            height: height(block_height)?,
+            time: timestamp(time)?,
+            next_validators_hash,
+            proposer_address,
+        })
+    }
+}
+
+impl TryFrom<proto::RequestProcessProposal> for RequestProcessProposal {
+    type Error = DataError;
+
+    fn try_from(
+        proto::RequestProcessProposal {
+            txs,
+            proposed_last_commit,
+            misbehavior: evidence,
+            hash,
+            height: block_height,
+            time,
+            next_validators_hash,
+            proposer_address,
+        }: proto::RequestProcessProposal,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            txs,
+            proposed_last_commit: proposed_last_commit
+                .ok_or(DataError::InvalidData(
+                    "proposed last commit is missing".into(),
+                ))?
+                .try_into()?,
+            misbehavior: misbehavior(evidence)?,
+            hash,
+            height: height(block_height)?,
+            time: timestamp(time)?,
+            next_validators_hash,
+            proposer_address,
+        })
+    }
+}
+
+impl TryFrom<proto::RequestExtendVote> for RequestExtendVote {
+    type Error = DataError;
+
+    fn try_from(
+        proto::RequestExtendVote {
+            hash,
+            height: block_height,
+            time,
+            txs,
+            proposed_last_commit,
+            misbehavior: evidence,
+            next_validators_hash,
+            proposer_address,
+        }: proto::RequestExtendVote,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            hash,
+            height: height(block_height)?,
+            time: timestamp(time)?,
+            txs,
+            proposed_last_commit: proposed_last_commit
+                .ok_or(DataError::InvalidData(
+                    "proposed last commit is missing".into(),
+                ))?
+                .try_into()?,
+            misbehavior: misbehavior(evidence)?,
+            next_validators_hash,
+            proposer_address,
+        })
+    }
+}
+
+impl TryFrom<proto::RequestVerifyVoteExtension> for RequestVerifyVoteExtension {
+    type Error = DataError;
+
+    fn try_from(
+        proto::RequestVerifyVoteExtension {
+            hash,
+            validator_address,
+            height: block_height,
+            vote_extension,
+        }: proto::RequestVerifyVoteExtension,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            hash,
+            validator_address,
+            height: height(block_height)?,
+            vote_extension,
+        })
+    }
+}
+
+impl TryFrom<proto::RequestFinalizeBlock> for RequestFinalizeBlock {
+    type Error = DataError;
+
+    fn try_from(
+        proto::RequestFinalizeBlock {
+            txs,
+            decided_last_commit,
+            misbehavior: evidence,
+            hash,
+            height: block_height,
+            time,
+            next_validators_hash,
+            proposer_address,
+        }: proto::RequestFinalizeBlock,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            txs,
+            decided_last_commit: decided_last_commit
+                .ok_or(DataError::InvalidData(
+                    "decided last commit is missing".into(),
+                ))?
+                .try_into()?,
+            misbehavior: misbehavior(evidence)?,
+            hash,
+            height: height(block_height)?,
+            time: timestamp(time)?,
+            next_validators_hash,
+            proposer_address,
+        })
+    }
+}
+
+/// Event attributes are strings since 0.37
+impl From<Event> for proto::Event {
+    fn from(Event { r#type, attributes }: Event) -> Self {
+        Self {
+            r#type,
+            attributes: attributes
+                .into_iter()
+                .map(
+                    |EventAttribute { key, value, index }| proto::EventAttribute {
+                        key: String::from_utf8_lossy(&key).into_owned(),
+                        value: String::from_utf8_lossy(&value).into_owned(),
+                        index,
+                    },
+                )
+                .collect(),
+        }
+    }
+}
+
+/// The mempool of 0.38 no longer uses the priority and sender of a transaction
+impl From<ResponseCheckTx> for proto::ResponseCheckTx {
+    fn from(
+        ResponseCheckTx {
+            code,
+            data,
+            log,
+            info,
+            gas_wanted,
+            gas_used,
+            events,
+            codespace,
+            sender: _,
+            priority: _,
+            mempool_error: _,
+        }: ResponseCheckTx,
+    ) -> Self {
+        Self {
+            code,
+            data,
+            log,
+            info,
+            gas_wanted,
+            gas_used,
+            events: events.into_iter().map(Into::into).collect(),
+            codespace,
+        }
+    }
+}
+
+impl From<ResponseDeliverTx> for proto::ExecTxResult {
+    fn from(
+        ResponseDeliverTx {
+            code,
+            data,
+            log,
+            info,
+            gas_wanted,
+            gas_used,
+            events,
+            codespace,
+        }: ResponseDeliverTx,
+    ) -> Self {
+        Self {
+            code,
+            data,
+            log,
+            info,
+            gas_wanted,
+            gas_used,
+            events: events.into_iter().map(Into::into).collect(),
+            codespace,
+        }
+    }
+}
+
+impl From<ResponsePrepareProposal> for proto::ResponsePrepareProposal {
+    fn from(ResponsePrepareProposal { txs }: ResponsePrepareProposal) -> Self {
+        Self { txs }
+    }
+}
+
+impl From<ResponseProcessProposal> for proto::ResponseProcessProposal {
+    fn from(ResponseProcessProposal { status }: ResponseProcessProposal) -> Self {
+        use proto::response_process_proposal::ProposalStatus as Status;
+
+        let status = match status {
+            ProposalStatus::Unknown => Status::Unknown,
+            ProposalStatus::Accept => Status::Accept,
+            ProposalStatus::Reject => Status::Reject,
+        };
+
+        Self {
+            status: status.into(),
+        }
+    }
+}
+
+impl From<ResponseExtendVote> for proto::ResponseExtendVote {
+    fn from(ResponseExtendVote { vote_extension }: ResponseExtendVote) -> Self {
+        Self { vote_extension }
+    }
+}
+
+impl From<ResponseVerifyVoteExtension> for proto::ResponseVerifyVoteExtension {
+    fn from(ResponseVerifyVoteExtension { status }: ResponseVerifyVoteExtension) -> Self {
+        use proto::response_verify_vote_extension::VerifyStatus as Status;
+
+        let status = match status {
+            VerifyStatus::Unknown => Status::Unknown,
+            VerifyStatus::Accept => Status::Accept,
+            VerifyStatus::Reject => Status::Reject,
+        };
+
+        Self {
+            status: status.into(),
+        }
+    }
+}
+
+impl From<ResponseFinalizeBlock> for proto::ResponseFinalizeBlock {
+    fn from(
+        ResponseFinalizeBlock {
+            events,
+            tx_results,
+            validator_updates,
+            consensus_param_updates,
+            app_hash,
+        }: ResponseFinalizeBlock,
+    ) -> Self {
+        Self {
+            events: events.into_iter().map(Into::into).collect(),
+            tx_results: tx_results.into_iter().map(Into::into).collect(),
+            validator_updates: validator_updates
+                .into_iter()
+                .map(|update| transcode(&v0_34::ValidatorUpdate::from(update)))
+                .collect(),
+            // the ABCI params of 0.38 are left out, so CometBFT keeps its current ones
+            consensus_param_updates: consensus_param_updates
+                .map(|params| transcode(&v0_34::ConsensusParams::from(params))),
+            app_hash,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn absent_validators_did_not_sign() {
+        assert!(!signed_last_block(BlockIdFlag::Absent as i32));
+        assert!(signed_last_block(BlockIdFlag::Commit as i32));
+        assert!(signed_last_block(BlockIdFlag::Nil as i32));
+    }
+
+    #[test]
+    fn unchanged_messages_are_transcoded() {
+        let request = proto::RequestQuery {
+            data: vec![1, 2, 3].into(),
+            path: "/store/bank/key".to_owned(),
+            height: 5,
+            prove: true,
+        };
+
+        let transcoded: v0_34::RequestQuery = transcode(&request);
+        assert_eq!(transcoded.data, request.data);
+        assert_eq!(transcoded.path, request.path);
+        assert_eq!(transcoded.height, request.height);
+        assert!(transcoded.prove);
+    }
+
+    #[test]
+    fn commit_info_without_validator_is_invalid() {
+        let commit = proto::CommitInfo {
+            round: 0,
+            votes: vec![proto::VoteInfo {
+                validator: None,
+                block_id_flag: BlockIdFlag::Commit as i32,
+            }],
+        };
+
+        assert!(LastCommitInfo::try_from(commit).is_err());
+    }
+}
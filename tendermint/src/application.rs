@@ -11,10 +11,13 @@ use crate::types::{
         deliver_tx::RequestDeliverTx,
         echo::RequestEcho,
         end_block::RequestEndBlock,
+        finalize_block::RequestFinalizeBlock,
         info::RequestInfo,
         init_chain::RequestInitChain,
+        proposal::{RequestPrepareProposal, RequestProcessProposal},
         query::RequestQuery,
         snapshot::{RequestApplySnapshotChunk, RequestLoadSnapshotChunk, RequestOfferSnapshot},
+        vote_extension::{RequestExtendVote, RequestVerifyVoteExtension},
     },
     response::{
         begin_block::ResponseBeginBlock,
@@ -22,13 +25,16 @@ use crate::types::{
         deliver_tx::ResponseDeliverTx,
         echo::ResponseEcho,
         end_block::ResponseEndBlock,
+        finalize_block::ResponseFinalizeBlock,
         info::ResponseInfo,
         init_chain::ResponseInitChain,
+        proposal::{ProposalStatus, ResponsePrepareProposal, ResponseProcessProposal},
         query::ResponseQuery,
         snapshot::{
             ResponseApplySnapshotChunk, ResponseListSnapshots, ResponseLoadSnapshotChunk,
             ResponseOfferSnapshot,
         },
+        vote_extension::{ResponseExtendVote, ResponseVerifyVoteExtension, VerifyStatus},
         ResponseCommit, ResponseFlush,
     },
 };
@@ -111,6 +117,46 @@ pub trait ABCIApplication<G>: Send + Clone + 'static {
     }
 }
 
+/// An ABCI++ (CometBFT 0.38) application.
+///
+/// `finalize_block` replaces the `begin_block`, `deliver_tx` and `end_block` calls of
+/// [`ABCIApplication`], and `commit` only persists the state whose hash was already
+/// returned by `finalize_block`. It's served to CometBFT 0.38 nodes by
+/// [`ABCIPlusPlus`](crate::abci::v0_38::ABCIPlusPlus).
+pub trait ABCIPlusPlusApplication<G>: ABCIApplication<G> {
+    /// Lets the proposer of a block reorder, drop or add transactions.
+    fn prepare_proposal(&self, request: RequestPrepareProposal) -> ResponsePrepareProposal {
+        ResponsePrepareProposal {
+            txs: request.txs_within_limit(),
+        }
+    }
+
+    /// Accept or reject a block proposed by another validator.
+    fn process_proposal(&self, _request: RequestProcessProposal) -> ResponseProcessProposal {
+        ResponseProcessProposal {
+            status: ProposalStatus::Accept,
+        }
+    }
+
+    /// Attach application data to the precommit vote of this validator.
+    fn extend_vote(&self, _request: RequestExtendVote) -> ResponseExtendVote {
+        Default::default()
+    }
+
+    /// Check the vote extension of another validator.
+    fn verify_vote_extension(
+        &self,
+        _request: RequestVerifyVoteExtension,
+    ) -> ResponseVerifyVoteExtension {
+        ResponseVerifyVoteExtension {
+            status: VerifyStatus::Accept,
+        }
+    }
+
+    /// Execute a decided block.
+    fn finalize_block(&self, request: RequestFinalizeBlock) -> ResponseFinalizeBlock;
+}
+
 #[derive(Debug, Clone)]
 pub struct ABCI<T: ABCIApplication<G>, G> {
     handler: T,
@@ -74,6 +74,23 @@ impl From<VoteInfo> for inner::VoteInfo {
     }
 }
 
+/// Votes of the last commit together with the vote extensions of the validators
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ExtendedCommitInfo {
+    pub round: i32,
+    pub votes: Vec<ExtendedVoteInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ExtendedVoteInfo {
+    pub validator: Validator,
+    pub signed_last_block: bool,
+    /// Application data attached to the precommit vote, empty if the validator didn't sign
+    pub vote_extension: ::prost::bytes::Bytes,
+    /// Signature of the validator over the vote extension, already verified by tendermint
+    pub extension_signature: ::prost::bytes::Bytes,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Evidence {
     pub r#type: i32,
@@ -0,0 +1,20 @@
+use bytes::Bytes;
+
+use crate::types::{
+    proto::info::{Evidence, LastCommitInfo},
+    time::timestamp::Timestamp,
+};
+
+/// Delivers a decided block to the application. Replaces BeginBlock, DeliverTx and EndBlock.
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]
+pub struct RequestFinalizeBlock {
+    pub txs: Vec<Bytes>,
+    pub decided_last_commit: LastCommitInfo,
+    pub misbehavior: Vec<Evidence>,
+    /// Hash of the decided block
+    pub hash: Bytes,
+    pub height: u32,
+    pub time: Timestamp,
+    pub next_validators_hash: Bytes,
+    pub proposer_address: Bytes,
+}
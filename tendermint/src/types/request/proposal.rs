@@ -0,0 +1,77 @@
+use bytes::Bytes;
+
+use crate::types::{
+    proto::info::{Evidence, ExtendedCommitInfo, LastCommitInfo},
+    time::timestamp::Timestamp,
+};
+
+/// Asks the proposer of a block which transactions to include in it
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]
+pub struct RequestPrepareProposal {
+    /// Maximum total size in bytes of the transactions of the proposal
+    pub max_tx_bytes: i64,
+    /// Transactions from the mempool, may exceed `max_tx_bytes`
+    pub txs: Vec<Bytes>,
+    /// Votes of the last block, including the vote extensions of the validators
+    pub local_last_commit: ExtendedCommitInfo,
+    pub misbehavior: Vec<Evidence>,
+    pub height: u32,
+    pub time: Timestamp,
+    pub next_validators_hash: Bytes,
+    /// Address of the validator which creates the proposal
+    pub proposer_address: Bytes,
+}
+
+impl RequestPrepareProposal {
+    /// Transactions in mempool order, up to the first one which no longer fits into `max_tx_bytes`
+    pub fn txs_within_limit(&self) -> Vec<Bytes> {
+        let mut total_bytes = 0_i64;
+
+        self.txs
+            .iter()
+            .take_while(|tx| {
+                total_bytes = total_bytes.saturating_add(tx.len().try_into().unwrap_or(i64::MAX));
+                total_bytes <= self.max_tx_bytes
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Asks a validator whether a block proposed by another validator is acceptable
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]
+pub struct RequestProcessProposal {
+    pub txs: Vec<Bytes>,
+    pub proposed_last_commit: LastCommitInfo,
+    pub misbehavior: Vec<Evidence>,
+    /// Hash of the proposed block
+    pub hash: Bytes,
+    pub height: u32,
+    pub time: Timestamp,
+    pub next_validators_hash: Bytes,
+    pub proposer_address: Bytes,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn txs_within_limit_stops_at_first_oversized_tx() {
+        let request = RequestPrepareProposal {
+            max_tx_bytes: 5,
+            txs: vec![vec![1, 1].into(), vec![2, 2, 2].into(), vec![3].into()],
+            local_last_commit: ExtendedCommitInfo {
+                round: 0,
+                votes: vec![],
+            },
+            misbehavior: vec![],
+            height: 1,
+            time: Timestamp::UNIX_EPOCH,
+            next_validators_hash: Bytes::new(),
+            proposer_address: Bytes::new(),
+        };
+
+        assert_eq!(request.txs_within_limit().len(), 2);
+    }
+}
@@ -0,0 +1,31 @@
+use bytes::Bytes;
+
+use crate::types::{
+    proto::info::{Evidence, LastCommitInfo},
+    time::timestamp::Timestamp,
+};
+
+/// Asks a validator for application data to attach to its precommit vote
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]
+pub struct RequestExtendVote {
+    /// Hash of the block which is voted for
+    pub hash: Bytes,
+    pub height: u32,
+    pub time: Timestamp,
+    pub txs: Vec<Bytes>,
+    pub proposed_last_commit: LastCommitInfo,
+    pub misbehavior: Vec<Evidence>,
+    pub next_validators_hash: Bytes,
+    pub proposer_address: Bytes,
+}
+
+/// Asks a validator to check the vote extension of another validator
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]
+pub struct RequestVerifyVoteExtension {
+    /// Hash of the block which is voted for
+    pub hash: Bytes,
+    /// Address of the validator which extended its vote
+    pub validator_address: Bytes,
+    pub height: u32,
+    pub vote_extension: Bytes,
+}
@@ -0,0 +1,20 @@
+use bytes::Bytes;
+
+use crate::types::proto::{consensus::ConsensusParams, event::Event, validator::ValidatorUpdate};
+
+use super::deliver_tx::ResponseDeliverTx;
+
+/// Result of executing a single transaction of a finalized block
+pub type ExecTxResult = ResponseDeliverTx;
+
+#[derive(Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize, Debug)]
+pub struct ResponseFinalizeBlock {
+    /// Block level events, emitted outside of transactions
+    pub events: Vec<Event>,
+    /// Results of the block's transactions, in block order
+    pub tx_results: Vec<ExecTxResult>,
+    pub validator_updates: Vec<ValidatorUpdate>,
+    pub consensus_param_updates: Option<ConsensusParams>,
+    /// Hash of the state after executing the block, committed by the following Commit
+    pub app_hash: Bytes,
+}
@@ -3,10 +3,13 @@ pub mod check_tx;
 pub mod deliver_tx;
 pub mod echo;
 pub mod end_block;
+pub mod finalize_block;
 pub mod info;
 pub mod init_chain;
+pub mod proposal;
 pub mod query;
 pub mod snapshot;
+pub mod vote_extension;
 
 pub(crate) mod inner {
     pub use tendermint_informal::abci::response::DeliverTx;
@@ -0,0 +1,29 @@
+use bytes::Bytes;
+
+#[derive(Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize, Debug)]
+pub struct ResponsePrepareProposal {
+    /// Transactions of the proposed block, must fit into the requested `max_tx_bytes`
+    pub txs: Vec<Bytes>,
+}
+
+#[derive(Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize, Debug)]
+pub struct ResponseProcessProposal {
+    pub status: ProposalStatus,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize, Debug)]
+pub enum ProposalStatus {
+    #[default]
+    Unknown,
+    Accept,
+    Reject,
+}
+
+impl From<bool> for ProposalStatus {
+    fn from(accept: bool) -> Self {
+        match accept {
+            true => Self::Accept,
+            false => Self::Reject,
+        }
+    }
+}
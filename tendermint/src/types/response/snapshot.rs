@@ -124,9 +124,69 @@ impl From<super::inner::ResponseLoadSnapshotChunk> for ResponseLoadSnapshotChunk
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ApplySnapshotChunkResult {
+    /// Unknown result, abort all snapshot restoration
+    Unknown = 0,
+    /// Chunk successfully accepted
+    Accept = 1,
+    /// Abort all snapshot restoration
+    Abort = 2,
+    /// Retry chunk (combine with refetch and reject)
+    Retry = 3,
+    /// Retry snapshot (combine with refetch and reject)
+    RetrySnapshot = 4,
+    /// Reject this snapshot, try others
+    RejectSnapshot = 5,
+}
+
+impl ApplySnapshotChunkResult {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ApplySnapshotChunkResult::Unknown => "UNKNOWN",
+            ApplySnapshotChunkResult::Accept => "ACCEPT",
+            ApplySnapshotChunkResult::Abort => "ABORT",
+            ApplySnapshotChunkResult::Retry => "RETRY",
+            ApplySnapshotChunkResult::RetrySnapshot => "RETRY_SNAPSHOT",
+            ApplySnapshotChunkResult::RejectSnapshot => "REJECT_SNAPSHOT",
+        }
+    }
+}
+
+impl From<ApplySnapshotChunkResult> for inner::ApplySnapshotChunkResult {
+    fn from(value: ApplySnapshotChunkResult) -> Self {
+        match value {
+            ApplySnapshotChunkResult::Unknown => Self::Unknown,
+            ApplySnapshotChunkResult::Accept => Self::Accept,
+            ApplySnapshotChunkResult::Abort => Self::Abort,
+            ApplySnapshotChunkResult::Retry => Self::Retry,
+            ApplySnapshotChunkResult::RetrySnapshot => Self::RetrySnapshot,
+            ApplySnapshotChunkResult::RejectSnapshot => Self::RejectSnapshot,
+        }
+    }
+}
+
+impl From<inner::ApplySnapshotChunkResult> for ApplySnapshotChunkResult {
+    fn from(value: inner::ApplySnapshotChunkResult) -> Self {
+        match value {
+            inner::ApplySnapshotChunkResult::Unknown => Self::Unknown,
+            inner::ApplySnapshotChunkResult::Accept => Self::Accept,
+            inner::ApplySnapshotChunkResult::Abort => Self::Abort,
+            inner::ApplySnapshotChunkResult::Retry => Self::Retry,
+            inner::ApplySnapshotChunkResult::RetrySnapshot => Self::RetrySnapshot,
+            inner::ApplySnapshotChunkResult::RejectSnapshot => Self::RejectSnapshot,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, ::prost::Message, serde::Serialize, serde::Deserialize)]
 pub struct ResponseApplySnapshotChunk {
-    #[prost(enumeration = "OfferResult", tag = "1")]
+    #[prost(enumeration = "ApplySnapshotChunkResult", tag = "1")]
     pub result: i32,
     /// Chunks to refetch and reapply
     #[prost(uint32, repeated, tag = "2")]
@@ -169,5 +229,6 @@ impl From<ResponseApplySnapshotChunk> for super::inner::ResponseApplySnapshotChu
 }
 
 pub mod inner {
+    pub use tendermint_proto::abci::response_apply_snapshot_chunk::Result as ApplySnapshotChunkResult;
     pub use tendermint_proto::abci::response_offer_snapshot::Result;
 }
@@ -0,0 +1,29 @@
+use bytes::Bytes;
+
+#[derive(Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize, Debug)]
+pub struct ResponseExtendVote {
+    pub vote_extension: Bytes,
+}
+
+#[derive(Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize, Debug)]
+pub struct ResponseVerifyVoteExtension {
+    pub status: VerifyStatus,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize, Debug)]
+pub enum VerifyStatus {
+    #[default]
+    Unknown,
+    Accept,
+    /// Rejecting makes the whole precommit vote invalid, use with care
+    Reject,
+}
+
+impl From<bool> for VerifyStatus {
+    fn from(accept: bool) -> Self {
+        match accept {
+            true => Self::Accept,
+            false => Self::Reject,
+        }
+    }
+}
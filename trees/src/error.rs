@@ -7,6 +7,12 @@ pub enum Error {
     VersionNotFound(u32),
     #[error("cannot overwrite existing version")]
     Overwrite,
+    #[error("initial version can only be set before any version is saved")]
+    InitialVersionAfterSave,
+    #[error("cannot import into a tree which already has versions")]
+    ImportIntoNonEmpty,
+    #[error("invalid exported tree: {0}")]
+    InvalidImport(String),
 }
 
 #[derive(Error, Debug, PartialEq, Eq)]
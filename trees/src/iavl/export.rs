@@ -0,0 +1,393 @@
+use std::borrow::Cow;
+
+use database::Database;
+
+use crate::{merkle::EMPTY_HASH, Error};
+
+use super::{node_db::NodeDB, InnerNode, LeafNode, Node, QueryTree, Tree};
+
+/// A single node of an exported tree.
+///
+/// Nodes are exported in post-order (left subtree, right subtree, node) which
+/// allows the exact same tree, and therefore the same root hash, to be rebuilt
+/// bottom-up on import.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedNode {
+    pub key: Vec<u8>,
+    /// Value of a leaf node, `None` for inner nodes
+    pub value: Option<Vec<u8>>,
+    pub version: u32,
+    pub height: u8,
+}
+
+impl From<&LeafNode> for ExportedNode {
+    fn from(leaf: &LeafNode) -> Self {
+        Self {
+            key: leaf.key.clone(),
+            value: Some(leaf.value.clone()),
+            version: leaf.version,
+            height: 0,
+        }
+    }
+}
+
+impl From<&InnerNode> for ExportedNode {
+    fn from(inner: &InnerNode) -> Self {
+        Self {
+            key: inner.key.clone(),
+            value: None,
+            version: inner.version,
+            height: inner.height,
+        }
+    }
+}
+
+impl<DB: Database> QueryTree<DB> {
+    /// Export all nodes of the checked out version in post-order.
+    /// Nodes are loaded lazily, so only a path from the root is held in memory.
+    pub fn export(&self) -> NodeExporter<'_, DB> {
+        NodeExporter {
+            node_db: &self.node_db,
+            stack: self
+                .root
+                .iter()
+                .map(|root| PendingNode::Visit(Cow::Borrowed(&**root)))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum PendingNode<'a> {
+    /// Node whose subtrees still have to be exported
+    Visit(Cow<'a, Node>),
+    /// Inner node whose subtrees were exported
+    Export(ExportedNode),
+}
+
+/// Iterator over the nodes of a tree, see [`QueryTree::export`]
+#[derive(Debug)]
+pub struct NodeExporter<'a, DB> {
+    node_db: &'a NodeDB<DB>,
+    stack: Vec<PendingNode<'a>>,
+}
+
+impl<'a, DB: Database> NodeExporter<'a, DB> {
+    fn child(&self, node: Option<Cow<'a, Node>>, hash: &[u8; 32]) -> Cow<'a, Node> {
+        node.unwrap_or_else(|| {
+            Cow::Owned(
+                *self
+                    .node_db
+                    .get_node(hash)
+                    .expect("node db should contain all nodes"),
+            )
+        })
+    }
+}
+
+impl<DB: Database> Iterator for NodeExporter<'_, DB> {
+    type Item = ExportedNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = match self.stack.pop()? {
+                PendingNode::Export(node) => return Some(node),
+                PendingNode::Visit(node) => node,
+            };
+
+            let (exported, left_node, right_node) = match node {
+                Cow::Borrowed(Node::Leaf(leaf)) => return Some(leaf.into()),
+                Cow::Owned(Node::Leaf(leaf)) => return Some((&leaf).into()),
+                Cow::Borrowed(Node::Inner(inner)) => (
+                    ExportedNode::from(inner),
+                    self.child(
+                        inner.left_node.as_deref().map(Cow::Borrowed),
+                        &inner.left_hash,
+                    ),
+                    self.child(
+                        inner.right_node.as_deref().map(Cow::Borrowed),
+                        &inner.right_hash,
+                    ),
+                ),
+                Cow::Owned(Node::Inner(inner)) => (
+                    ExportedNode::from(&inner),
+                    self.child(
+                        inner.left_node.map(|node| Cow::Owned(*node)),
+                        &inner.left_hash,
+                    ),
+                    self.child(
+                        inner.right_node.map(|node| Cow::Owned(*node)),
+                        &inner.right_hash,
+                    ),
+                ),
+            };
+
+            self.stack.push(PendingNode::Export(exported));
+            self.stack.push(PendingNode::Visit(right_node));
+            self.stack.push(PendingNode::Visit(left_node));
+        }
+    }
+}
+
+/// Rebuilds a tree from exported nodes, see [`Tree::importer`]
+#[derive(Debug)]
+pub struct Importer<DB> {
+    node_db: NodeDB<DB>,
+    // Children are written to the db as soon as their parent is built, so the
+    // stack only ever holds shallow nodes and is bounded by the tree height.
+    stack: Vec<(Node, [u8; 32])>,
+}
+
+/// Tree rebuilt by an [`Importer`] which isn't part of any tree yet
+#[derive(Debug)]
+pub struct ImportedTree {
+    root: Option<(Node, [u8; 32])>,
+}
+
+impl ImportedTree {
+    pub fn root_hash(&self) -> [u8; 32] {
+        match &self.root {
+            Some((_, hash)) => *hash,
+            None => EMPTY_HASH,
+        }
+    }
+}
+
+impl<DB: Database> Importer<DB> {
+    /// Add the next node produced by [`QueryTree::export`]
+    pub fn add(
+        &mut self,
+        ExportedNode {
+            key,
+            value,
+            version,
+            height,
+        }: ExportedNode,
+    ) -> Result<(), Error> {
+        let node = match (value, height) {
+            (Some(value), 0) => Node::Leaf(LeafNode {
+                value,
+                key,
+                version,
+            }),
+            (None, height) if height > 0 => {
+                let (right_node, right_hash) = self.stack.pop().ok_or_else(|| {
+                    Error::InvalidImport("inner node is missing children".to_owned())
+                })?;
+                let (left_node, left_hash) = self.stack.pop().ok_or_else(|| {
+                    Error::InvalidImport("inner node is missing children".to_owned())
+                })?;
+
+                if height != 1 + std::cmp::max(left_node.get_height(), right_node.get_height()) {
+                    return Err(Error::InvalidImport(format!(
+                        "inner node height {height} doesn't match its children"
+                    )));
+                }
+
+                self.node_db.save_node(&left_node, &left_hash);
+                self.node_db.save_node(&right_node, &right_hash);
+
+                Node::Inner(InnerNode {
+                    left_node: None,
+                    right_node: None,
+                    height,
+                    size: left_node.get_size() + right_node.get_size(),
+                    left_hash,
+                    right_hash,
+                    key,
+                    version,
+                })
+            }
+            _ => {
+                return Err(Error::InvalidImport(format!(
+                    "node of height {height} has an unexpected value"
+                )))
+            }
+        };
+
+        let hash = node.hash();
+        self.stack.push((node, hash));
+
+        Ok(())
+    }
+
+    /// Check that the added nodes form a single tree
+    pub fn finish(mut self) -> Result<ImportedTree, Error> {
+        let root = self.stack.pop();
+
+        if !self.stack.is_empty() {
+            return Err(Error::InvalidImport(
+                "exported nodes don't form a single tree".to_owned(),
+            ));
+        }
+
+        Ok(ImportedTree { root })
+    }
+}
+
+impl<T: Database> Tree<T> {
+    /// Start rebuilding a tree from nodes produced by [`QueryTree::export`].
+    ///
+    /// Nodes are written to the db as they are added but the tree only changes once
+    /// [`Tree::finish_import`] is called, so an import can be discarded at any point.
+    /// Nodes of a discarded import stay in the db without being referenced by any version.
+    pub fn importer(&self) -> Result<Importer<T>, Error> {
+        if !self.versions.is_empty() || self.root.is_some() {
+            return Err(Error::ImportIntoNonEmpty);
+        }
+
+        Ok(Importer {
+            node_db: self.node_db.clone(),
+            stack: Vec::new(),
+        })
+    }
+
+    /// Save an imported tree as `version`. The tree must not contain any versions.
+    /// Returns the root hash of the imported tree.
+    pub fn finish_import(
+        &mut self,
+        version: u32,
+        ImportedTree { root }: ImportedTree,
+    ) -> Result<[u8; 32], Error> {
+        if !self.versions.is_empty() || self.root.is_some() {
+            return Err(Error::ImportIntoNonEmpty);
+        }
+
+        let root_hash = match root {
+            Some((root, root_hash)) => {
+                self.node_db.save_node(&root, &root_hash);
+                self.node_db.save_version(version, &root_hash);
+                self.root = Some(Box::new(root));
+                root_hash
+            }
+            None => {
+                self.node_db.save_version(version, &EMPTY_HASH);
+                EMPTY_HASH
+            }
+        };
+
+        self.versions.insert(version);
+        self.loaded_version = version;
+
+        Ok(root_hash)
+    }
+
+    /// Rebuild a tree from nodes produced by [`QueryTree::export`] and save it as `version`.
+    /// The tree must not contain any versions. Returns the root hash of the imported tree.
+    pub fn import(
+        &mut self,
+        version: u32,
+        nodes: impl IntoIterator<Item = ExportedNode>,
+    ) -> Result<[u8; 32], Error> {
+        let mut importer = self.importer()?;
+        for node in nodes {
+            importer.add(node)?;
+        }
+
+        self.finish_import(version, importer.finish()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+
+    #[test]
+    fn export_import_round_trip() {
+        let mut tree =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        for i in 0..50u8 {
+            tree.set(vec![i], vec![i, i]);
+        }
+        tree.save_version().unwrap_test();
+        for i in 10..30u8 {
+            tree.set(vec![i], vec![i]);
+        }
+        tree.remove(&[40]);
+        let (hash, version) = tree.save_version().unwrap_test();
+
+        let query_tree = QueryTree::new(&tree, version).unwrap_test();
+
+        let mut imported =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        let imported_hash = imported.import(version, query_tree.export()).unwrap_test();
+
+        assert_eq!(hash, imported_hash);
+        assert_eq!(imported.loaded_version(), version);
+        assert_eq!(imported.get(&[20]), Some(vec![20]));
+        assert_eq!(imported.get(&[40]), None);
+
+        // the imported tree can continue to be used as usual
+        tree.set(vec![100], vec![1]);
+        imported.set(vec![100], vec![1]);
+        assert_eq!(tree.save_version(), imported.save_version());
+    }
+
+    #[test]
+    fn import_empty_tree() {
+        let mut tree =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        let (hash, version) = tree.save_version().unwrap_test();
+
+        let exported = QueryTree::new(&tree, version)
+            .unwrap_test()
+            .export()
+            .collect::<Vec<_>>();
+        assert!(exported.is_empty());
+
+        let mut imported =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+
+        assert_eq!(imported.import(version, exported), Ok(hash));
+    }
+
+    #[test]
+    fn import_into_non_empty_tree_fails() {
+        let mut tree =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        tree.save_version().unwrap_test();
+
+        assert_eq!(tree.import(2, vec![]), Err(Error::ImportIntoNonEmpty));
+    }
+
+    #[test]
+    fn discarded_import_leaves_tree_unchanged() {
+        let mut tree =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        for i in 0..10u8 {
+            tree.set(vec![i], vec![i]);
+        }
+        let (hash, version) = tree.save_version().unwrap_test();
+        let query_tree = QueryTree::new(&tree, version).unwrap_test();
+
+        let db = MemDB::new();
+        let mut imported =
+            Tree::new(db.clone(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+
+        let mut importer = imported.importer().unwrap_test();
+        for node in query_tree.export().take(5) {
+            importer.add(node).unwrap_test();
+        }
+        drop(importer);
+
+        assert_eq!(imported.loaded_version(), 0);
+        assert_eq!(imported.get(&[1]), None);
+
+        // a tree loaded from the db doesn't contain any version either
+        let reloaded = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+        assert_eq!(reloaded.loaded_version(), 0);
+
+        // and another import can still be done
+        let mut importer = imported.importer().unwrap_test();
+        for node in query_tree.export() {
+            importer.add(node).unwrap_test();
+        }
+        let imported_tree = importer.finish().unwrap_test();
+        assert_eq!(imported_tree.root_hash(), hash);
+
+        assert_eq!(imported.finish_import(version, imported_tree), Ok(hash));
+        assert_eq!(imported.get(&[1]), Some(vec![1]));
+    }
+}
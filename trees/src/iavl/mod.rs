@@ -1,9 +1,12 @@
+mod export;
 mod node_db;
+mod proof;
 mod query_tree;
 mod range;
 mod tree;
 #[allow(dead_code)]
 pub mod tree_v3;
 
+pub use export::*;
 pub use query_tree::*;
 pub use tree::*;
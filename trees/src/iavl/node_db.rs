@@ -85,7 +85,7 @@ where
         Some(Box::new(node))
     }
 
-    fn save_node(&mut self, node: &Node, hash: &[u8; 32]) {
+    pub(crate) fn save_node(&mut self, node: &Node, hash: &[u8; 32]) {
         self.db.put(Self::get_node_key(hash), node.serialize());
         self.cache
             .lock()
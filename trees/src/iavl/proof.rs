@@ -0,0 +1,160 @@
+use std::ops::Bound;
+
+use database::Database;
+use integer_encoding::VarInt;
+
+use crate::{
+    merkle::EMPTY_HASH,
+    proof::{CommitmentProof, ExistenceProof, InnerOp, LeafOp, NonExistenceProof},
+};
+
+use super::{Node, QueryTree};
+
+impl<DB: Database> QueryTree<DB> {
+    pub fn root_hash(&self) -> [u8; 32] {
+        match &self.root {
+            Some(root) => root.hash(),
+            None => EMPTY_HASH,
+        }
+    }
+
+    /// Proof of existence of `key` if it's set, otherwise proof of its absence
+    pub fn get_proof(&self, key: &[u8]) -> CommitmentProof {
+        if let Some(proof) = self.existence_proof(key) {
+            return CommitmentProof::Exist(proof);
+        }
+
+        let left = self
+            .range(..key)
+            .next_back()
+            .and_then(|(left_key, _)| self.existence_proof(&left_key));
+        let right = self
+            .range((Bound::Excluded(key), Bound::Unbounded))
+            .next()
+            .and_then(|(right_key, _)| self.existence_proof(&right_key));
+
+        CommitmentProof::Nonexist(NonExistenceProof {
+            key: key.to_vec(),
+            left,
+            right,
+        })
+    }
+
+    fn existence_proof(&self, key: &[u8]) -> Option<ExistenceProof> {
+        let mut node = self.root.clone()?;
+        let mut path = Vec::new();
+
+        loop {
+            match *node {
+                Node::Leaf(leaf) => {
+                    if leaf.key != key {
+                        return None;
+                    }
+
+                    path.reverse();
+
+                    return Some(ExistenceProof {
+                        leaf: LeafOp {
+                            prefix: hash_prefix(0, 1, leaf.version),
+                        },
+                        key: leaf.key,
+                        value: leaf.value,
+                        path,
+                    });
+                }
+                Node::Inner(inner) => {
+                    let prefix = hash_prefix(inner.height, inner.size, inner.version);
+
+                    // hashes are length prefixed in the serialized node
+                    if key < inner.key.as_slice() {
+                        path.push(InnerOp {
+                            prefix: [prefix, vec![32]].concat(),
+                            suffix: [vec![32], inner.right_hash.to_vec()].concat(),
+                        });
+
+                        node = match inner.left_node {
+                            Some(left_node) => left_node,
+                            None => self
+                                .node_db
+                                .get_node(&inner.left_hash)
+                                .expect("node db should contain all nodes"),
+                        };
+                    } else {
+                        path.push(InnerOp {
+                            prefix: [prefix, vec![32], inner.left_hash.to_vec(), vec![32]].concat(),
+                            suffix: Vec::new(),
+                        });
+
+                        node = match inner.right_node {
+                            Some(right_node) => right_node,
+                            None => self
+                                .node_db
+                                .get_node(&inner.right_hash)
+                                .expect("node db should contain all nodes"),
+                        };
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Start of the serialized node which is hashed, see [`Node::hash`]
+fn hash_prefix(height: u8, size: u32, version: u32) -> Vec<u8> {
+    // NOTE: i64 is used here for parameters for compatibility wih cosmos
+    let mut prefix = i64::from(height).encode_var_vec();
+    prefix.extend(i64::from(size).encode_var_vec());
+    prefix.extend(i64::from(version).encode_var_vec());
+
+    prefix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iavl::Tree;
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+
+    #[test]
+    fn proofs_hash_to_root() {
+        let mut tree =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        for i in (0..50u8).step_by(2) {
+            tree.set(vec![i], vec![i, i]);
+        }
+        let (hash, version) = tree.save_version().unwrap_test();
+        let query_tree = QueryTree::new(&tree, version).unwrap_test();
+
+        assert_eq!(query_tree.root_hash(), hash);
+
+        match query_tree.get_proof(&[20]) {
+            CommitmentProof::Exist(proof) => {
+                assert_eq!(proof.value, vec![20, 20]);
+                assert_eq!(proof.calculate_root(), hash);
+            }
+            proof => panic!("expected existence proof, got {proof:?}"),
+        }
+
+        match query_tree.get_proof(&[21]) {
+            CommitmentProof::Nonexist(NonExistenceProof { left, right, .. }) => {
+                let (left, right) = (left.unwrap_test(), right.unwrap_test());
+                assert_eq!(
+                    (left.key.as_slice(), right.key.as_slice()),
+                    (&[20][..], &[22][..])
+                );
+                assert_eq!(left.calculate_root(), hash);
+                assert_eq!(right.calculate_root(), hash);
+            }
+            proof => panic!("expected non existence proof, got {proof:?}"),
+        }
+
+        match query_tree.get_proof(&[100]) {
+            CommitmentProof::Nonexist(NonExistenceProof { left, right, .. }) => {
+                assert_eq!(left.unwrap_test().key, vec![48]);
+                assert_eq!(right, None);
+            }
+            proof => panic!("expected non existence proof, got {proof:?}"),
+        }
+    }
+}
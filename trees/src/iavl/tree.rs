@@ -26,12 +26,12 @@ pub(crate) struct InnerNode {
     // Sha256Hash to avoid needing to calculate hashes until save_version is called
     pub(crate) left_node: Option<Box<Node>>, // None means value is the same as what's in the DB
     pub(crate) right_node: Option<Box<Node>>,
-    height: u8,
-    size: u32, // number of leaf nodes in this node's subtrees
+    pub(crate) height: u8,
+    pub(crate) size: u32, // number of leaf nodes in this node's subtrees
     pub(crate) left_hash: Sha256Hash,
     pub(crate) right_hash: Sha256Hash,
     pub(crate) key: Vec<u8>,
-    version: u32,
+    pub(crate) version: u32,
 }
 
 impl InnerNode {
@@ -140,7 +140,7 @@ impl InnerNode {
 pub(crate) struct LeafNode {
     pub(crate) value: Vec<u8>,
     pub(crate) key: Vec<u8>,
-    version: u32,
+    pub(crate) version: u32,
 }
 
 impl LeafNode {
@@ -499,7 +499,7 @@ impl Node {
         }
     }
 
-    fn get_size(&self) -> u32 {
+    pub(crate) fn get_size(&self) -> u32 {
         match &self {
             Node::Leaf(_) => 1,
             Node::Inner(n) => n.size,
@@ -508,7 +508,7 @@ impl Node {
 }
 
 // TODO: rename loaded_version to head_version introduce a working_version (+ remove redundant loaded_version?). this will allow the first committed version to be version 0 rather than 1 (there is no version 0 currently!)
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Tree<T> {
     pub(crate) root: Option<Box<Node>>,
     pub(crate) node_db: NodeDB<T>,
@@ -611,6 +611,18 @@ where
         Ok((root_hash, self.loaded_version))
     }
 
+    /// Make `version` the version of the next save. Allows a tree to start at a version
+    /// other than 1, but only before any version has been saved.
+    pub fn set_initial_version(&mut self, version: u32) -> Result<(), Error> {
+        if !self.versions.is_empty() {
+            return Err(Error::InitialVersionAfterSave);
+        }
+
+        self.loaded_version = version.saturating_sub(1);
+
+        Ok(())
+    }
+
     pub fn root_hash(&self) -> [u8; 32] {
         match &self.root {
             Some(root) => root.hash(),
@@ -1217,6 +1229,23 @@ mod tests {
         assert_eq!(hash, expected);
     }
 
+    #[test]
+    fn set_initial_version_works() {
+        let db = MemDB::new();
+        let mut tree = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+        tree.set_initial_version(10).unwrap_test();
+        tree.set(vec![1], vec![4]);
+
+        let (_, version) = tree.save_version().unwrap_test();
+
+        assert_eq!(version, 10);
+        assert_eq!(tree.versions.iter().collect::<Vec<_>>(), vec![&10]);
+        assert_eq!(
+            tree.set_initial_version(20),
+            Err(Error::InitialVersionAfterSave)
+        );
+    }
+
     #[test]
     fn right_rotate_works() {
         let t3 = InnerNode {
@@ -1,6 +1,8 @@
 use sha2::{Digest, Sha256};
 
-const LEAF_PREFIX: [u8; 1] = [0];
+use crate::proof::InnerOp;
+
+pub const LEAF_PREFIX: [u8; 1] = [0];
 const INNER_PREFIX: [u8; 1] = [1];
 pub const EMPTY_HASH: [u8; 32] = [
     227, 176, 196, 66, 152, 252, 28, 20, 154, 251, 244, 200, 153, 111, 185, 36, 39, 174, 65, 228,
@@ -24,6 +26,30 @@ pub fn root_hash(items: &[Vec<u8>]) -> [u8; 32] {
     }
 }
 
+/// Returns the inner ops which hash the leaf at `index` up to the root, ordered from the leaf
+pub fn proof_path(items: &[Vec<u8>], index: usize) -> Vec<InnerOp> {
+    if items.len() <= 1 {
+        return Vec::new();
+    }
+
+    let k = get_split_point(items.len());
+    if index < k {
+        let mut path = proof_path(&items[0..k], index);
+        path.push(InnerOp {
+            prefix: INNER_PREFIX.to_vec(),
+            suffix: root_hash(&items[k..]).to_vec(),
+        });
+        path
+    } else {
+        let mut path = proof_path(&items[k..], index - k);
+        path.push(InnerOp {
+            prefix: [&INNER_PREFIX, root_hash(&items[0..k]).as_slice()].concat(),
+            suffix: Vec::new(),
+        });
+        path
+    }
+}
+
 /// Returns sha256(0x00 || leaf)
 fn leaf_hash(leaf: &[u8]) -> [u8; 32] {
     Sha256::digest([&LEAF_PREFIX, leaf].concat()).into()
@@ -103,6 +129,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn proof_path_hashes_to_root() {
+        let items = [vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8], vec![9, 10]];
+        let root = root_hash(&items);
+
+        for (index, item) in items.iter().enumerate() {
+            let hash = proof_path(&items, index)
+                .iter()
+                .fold(leaf_hash(item), |hash, op| op.apply(&hash));
+
+            assert_eq!(hash, root);
+        }
+    }
+
     #[test]
     fn get_split_point_works() {
         let split = get_split_point(100);
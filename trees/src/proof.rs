@@ -0,0 +1,81 @@
+//! Merkle proofs laid out as ICS23 proofs.
+//!
+//! Both IAVL trees and the simple merkle tree of the multistore hash leaves as
+//! `sha256(prefix || len(key) || key || len(sha256(value)) || sha256(value))` with
+//! varint length prefixes, and inner nodes as `sha256(prefix || child || suffix)`.
+//! The hash and length operations of the ICS23 specs are therefore implicit.
+
+use integer_encoding::VarInt;
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LeafOp {
+    pub prefix: Vec<u8>,
+}
+
+impl LeafOp {
+    pub fn apply(&self, key: &[u8], value: &[u8]) -> [u8; 32] {
+        let hashed_value = Sha256::digest(value);
+
+        Sha256::new()
+            .chain_update(&self.prefix)
+            .chain_update(key.len().encode_var_vec())
+            .chain_update(key)
+            .chain_update(hashed_value.len().encode_var_vec())
+            .chain_update(hashed_value)
+            .finalize()
+            .into()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InnerOp {
+    pub prefix: Vec<u8>,
+    pub suffix: Vec<u8>,
+}
+
+impl InnerOp {
+    pub fn apply(&self, child: &[u8]) -> [u8; 32] {
+        Sha256::new()
+            .chain_update(&self.prefix)
+            .chain_update(child)
+            .chain_update(&self.suffix)
+            .finalize()
+            .into()
+    }
+}
+
+/// Proof that `key` is set to `value`. The path is ordered from the leaf up to the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExistenceProof {
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+    pub leaf: LeafOp,
+    pub path: Vec<InnerOp>,
+}
+
+impl ExistenceProof {
+    /// Root hash of the tree which the proof was created from
+    pub fn calculate_root(&self) -> [u8; 32] {
+        self.path
+            .iter()
+            .fold(self.leaf.apply(&self.key, &self.value), |hash, op| {
+                op.apply(&hash)
+            })
+    }
+}
+
+/// Proof that `key` isn't set, made of the proofs of its direct neighbours.
+/// A neighbour is `None` if `key` is before the first or after the last key of the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonExistenceProof {
+    pub key: Vec<u8>,
+    pub left: Option<ExistenceProof>,
+    pub right: Option<ExistenceProof>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitmentProof {
+    Exist(ExistenceProof),
+    Nonexist(NonExistenceProof),
+}
@@ -1,9 +1,11 @@
 use gears::application::handlers::node::{ABCIHandler, TxError};
 use gears::baseapp::errors::QueryError;
+use gears::baseapp::mode::RunTxMode;
 use gears::baseapp::QueryRequest;
 use gears::context::init::InitContext;
 use gears::context::query::QueryContext;
 use gears::context::tx::TxContext;
+use gears::core::any::google::Any;
 use gears::core::Protobuf as _;
 use gears::derive::Query;
 use gears::extensions::gas::GasResultExt;
@@ -89,7 +91,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHa
         &self,
         _: &mut TxContext<'_, DB, Self::StoreKey>,
         _: &TxWithRaw<Self::Message>,
-        _: bool,
+        _: RunTxMode,
     ) -> Result<(), TxError> {
         Ok(())
     }
@@ -98,7 +100,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHa
         &self,
         _: &mut TxContext<'_, DB, Self::StoreKey>,
         _: &Self::Message,
-    ) -> Result<(), TxError> {
+    ) -> Result<Any, TxError> {
         unreachable!("auth doesn't contain any tx")
     }
 
@@ -151,6 +151,14 @@ impl AuthParams for AuthsParams {
         self.max_memo_characters
     }
 
+    fn tx_sig_limit(&self) -> u64 {
+        self.tx_sig_limit
+    }
+
+    fn sig_verify_cost_ed25519(&self) -> u64 {
+        self.sig_verify_cost_ed25519
+    }
+
     fn sig_verify_cost_secp256k1(&self) -> u64 {
         self.sig_verify_cost_secp256k1
     }
@@ -2,8 +2,10 @@ use std::marker::PhantomData;
 
 use gears::application::handlers::node::{ABCIHandler, ModuleInfo, TxError};
 use gears::baseapp::errors::QueryError;
+use gears::baseapp::mode::RunTxMode;
 use gears::baseapp::QueryRequest;
 use gears::context::{init::InitContext, query::QueryContext, tx::TxContext};
+use gears::core::any::google::Any;
 use gears::core::Protobuf;
 use gears::derive::Query;
 use gears::extensions::gas::GasResultExt;
@@ -13,6 +15,7 @@ use gears::store::database::Database;
 use gears::store::StoreKey;
 use gears::tendermint::types::request::query::RequestQuery;
 use gears::types::pagination::response::PaginationResponse;
+use gears::types::tx::TxMessage;
 use gears::x::keepers::auth::AuthKeeper;
 use gears::x::keepers::bank::{BalancesKeeper, BankKeeper};
 use gears::x::module::Module;
@@ -131,7 +134,7 @@ impl<
         &self,
         _: &mut TxContext<'_, DB, Self::StoreKey>,
         _: &gears::types::tx::raw::TxWithRaw<Self::Message>,
-        _: bool,
+        _: RunTxMode,
     ) -> Result<(), TxError> {
         Ok(())
     }
@@ -140,11 +143,12 @@ impl<
         &self,
         ctx: &mut TxContext<'_, DB, Self::StoreKey>,
         msg: &Self::Message,
-    ) -> Result<(), TxError> {
+    ) -> Result<Any, TxError> {
         let result = match msg {
             Message::Send(msg_send) => self
                 .keeper
-                .send_coins_from_account_to_account(ctx, msg_send),
+                .send_coins_from_account_to_account(ctx, msg_send)
+                .map(|_| msg.empty_response()),
         };
 
         result.map_err(|e| Into::<BankTxError>::into(e).into::<MI>())
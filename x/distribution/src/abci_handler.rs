@@ -1,5 +1,6 @@
 use crate::{
-    errors::DistributionTxError, GenesisState, Keeper, Message, QueryCommunityPoolRequest,
+    errors::DistributionTxError, GenesisState, Keeper, Message, MsgFundCommunityPoolResponse,
+    MsgSetWithdrawAddressResponse, MsgWithdrawDelegatorRewardResponse, QueryCommunityPoolRequest,
     QueryCommunityPoolResponse, QueryDelegationRewardsRequest, QueryDelegatorParams,
     QueryDelegatorTotalRewardsResponse, QueryParamsRequest, QueryParamsResponse,
     QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
@@ -12,7 +13,7 @@ use gears::{
         block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext,
         QueryableContext,
     },
-    core::Protobuf,
+    core::{any::google::Any, Protobuf},
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
     tendermint::{request::RequestBeginBlock, types::request::query::RequestQuery},
@@ -81,13 +82,21 @@ impl<
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
         msg: &Message,
-    ) -> Result<(), DistributionTxError> {
+    ) -> Result<Any, DistributionTxError> {
         match msg {
-            Message::WithdrawRewards(msg) => Ok(self
-                .keeper
-                .withdraw_delegator_reward_and_commission(ctx, msg)?),
-            Message::SetWithdrawAddr(msg) => Ok(self.keeper.set_withdraw_address(ctx, msg)?),
-            Message::FundCommunityPool(msg) => Ok(self.keeper.fund_community_pool_cmd(ctx, msg)?),
+            Message::WithdrawRewards(msg) => Ok(MsgWithdrawDelegatorRewardResponse::from(
+                self.keeper
+                    .withdraw_delegator_reward_and_commission(ctx, msg)?,
+            )
+            .into()),
+            Message::SetWithdrawAddr(msg) => {
+                self.keeper.set_withdraw_address(ctx, msg)?;
+                Ok(MsgSetWithdrawAddressResponse {}.into())
+            }
+            Message::FundCommunityPool(msg) => {
+                self.keeper.fund_community_pool_cmd(ctx, msg)?;
+                Ok(MsgFundCommunityPoolResponse {}.into())
+            }
         }
     }
 
@@ -16,8 +16,9 @@ impl<
         &self,
         ctx: &mut TxContext<DB, SK>,
         msg: &MsgWithdrawDelegatorReward,
-    ) -> Result<(), DistributionError> {
-        self.withdraw_delegation_rewards(ctx, &msg.delegator_address, &msg.validator_address)?;
+    ) -> Result<Option<UnsignedCoins>, DistributionError> {
+        let rewards =
+            self.withdraw_delegation_rewards(ctx, &msg.delegator_address, &msg.validator_address)?;
 
         ctx.push_event(Event {
             r#type: "message".to_string(),
@@ -54,7 +55,7 @@ impl<
             });
         }
 
-        Ok(())
+        Ok(rewards)
     }
 
     pub fn set_withdraw_address<DB: Database>(
@@ -1,4 +1,11 @@
-use gears::derive::AppMessage;
+use gears::{
+    derive::AppMessage,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{RenderError, ValueRenderer},
+    },
+    types::rendering::screen::Screen,
+};
 use serde::Serialize;
 
 use crate::{MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward};
@@ -15,3 +22,13 @@ pub enum Message {
     #[msg(url(path = MsgFundCommunityPool::TYPE_URL))]
     FundCommunityPool(MsgFundCommunityPool),
 }
+
+impl ValueRenderer for Message {
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        match self {
+            Message::WithdrawRewards(msg) => msg.format(get_metadata),
+            Message::SetWithdrawAddr(msg) => msg.format(get_metadata),
+            Message::FundCommunityPool(msg) => msg.format(get_metadata),
+        }
+    }
+}
@@ -3,6 +3,7 @@ mod delegator;
 mod fee_pool;
 mod iter;
 mod query;
+mod rendering;
 mod tx;
 mod validator;
 
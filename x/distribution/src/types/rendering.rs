@@ -0,0 +1,193 @@
+//! Implementation of formatting distribution messages into `Screen`
+
+use gears::{
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+            TryPrimitiveValueRendererWithMetadata, ValueRenderer,
+        },
+    },
+    types::rendering::screen::{Indent, Screen},
+};
+
+use crate::{MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward};
+
+impl ValueRenderer for MsgWithdrawDelegatorReward {
+    /// Format `MsgWithdrawDelegatorReward`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = vec![
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Validator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ];
+
+        // default values are omitted like in the cosmos sdk
+        if self.withdraw_commission {
+            screens.push(Screen {
+                title: "Withdraw commission".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.withdraw_commission),
+                indent: Some(Indent::two()),
+                expert: false,
+            });
+        }
+
+        Ok(screens)
+    }
+}
+
+impl ValueRenderer for MsgSetWithdrawAddr {
+    /// Format `MsgSetWithdrawAddr`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Withdraw address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.withdraw_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+impl ValueRenderer for MsgFundCommunityPool {
+    /// Format `MsgFundCommunityPool`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Amount".to_string(),
+                content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.amount.clone(),
+                    get_metadata,
+                )?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Depositor".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.depositor.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        signing::renderer::{test_utils::TestMetadataGetter, value_renderer::ValueRenderer},
+        types::rendering::screen::Screen,
+    };
+
+    use crate::{MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward};
+
+    #[test]
+    fn msg_withdraw_delegator_reward_works() -> anyhow::Result<()> {
+        const MESSAGE: &str = r#"{
+            "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+            "delegator_address": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs",
+            "withdraw_commission": false
+        }"#;
+
+        let msg: MsgWithdrawDelegatorReward = serde_json::from_str(MESSAGE)?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Delegator address", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 },
+            { "title": "Validator address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_withdraw_delegator_reward_with_commission_works() -> anyhow::Result<()> {
+        const MESSAGE: &str = r#"{
+            "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+            "delegator_address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+            "withdraw_commission": true
+        }"#;
+
+        let msg: MsgWithdrawDelegatorReward = serde_json::from_str(MESSAGE)?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Delegator address", "content": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "indent": 2 },
+            { "title": "Validator address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 },
+            { "title": "Withdraw commission", "content": "True", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_set_withdraw_addr_works() -> anyhow::Result<()> {
+        const MESSAGE: &str = r#"{
+            "delegator_address": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs",
+            "withdraw_address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux"
+        }"#;
+
+        let msg: MsgSetWithdrawAddr = serde_json::from_str(MESSAGE)?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Delegator address", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 },
+            { "title": "Withdraw address", "content": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_fund_community_pool_works() -> anyhow::Result<()> {
+        const MESSAGE: &str = r#"{
+            "amount": [{ "denom": "uatom", "amount": "2500000" }, { "denom": "ucosm", "amount": "10000000" }],
+            "depositor": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs"
+        }"#;
+
+        let msg: MsgFundCommunityPool = serde_json::from_str(MESSAGE)?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Amount", "content": "2.5 ATOM, 10'000'000 ucosm", "indent": 2 },
+            { "title": "Depositor", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
 use gears::{
-    core::{errors::CoreError, Protobuf},
+    core::{any::google::Any, errors::CoreError, Protobuf},
     derive::AppMessage,
     types::{
         address::{AccAddress, AddressError, ValAddress},
+        auth::fee::inner::Coin as CoinRaw,
         base::coins::UnsignedCoins,
     },
 };
@@ -159,3 +160,68 @@ impl TryFrom<MsgFundCommunityPoolRaw> for MsgFundCommunityPool {
 }
 
 impl Protobuf<MsgFundCommunityPoolRaw> for MsgFundCommunityPool {}
+
+/// Response of [`MsgWithdrawDelegatorReward`] with the withdrawn delegation rewards
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgWithdrawDelegatorRewardResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub amount: Vec<CoinRaw>,
+}
+
+impl MsgWithdrawDelegatorRewardResponse {
+    pub const TYPE_URL: &'static str =
+        "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorRewardResponse";
+}
+
+impl From<Option<UnsignedCoins>> for MsgWithdrawDelegatorRewardResponse {
+    fn from(amount: Option<UnsignedCoins>) -> Self {
+        Self {
+            amount: amount
+                .map(|coins| coins.into_iter().map(CoinRaw::from).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<MsgWithdrawDelegatorRewardResponse> for Any {
+    fn from(msg: MsgWithdrawDelegatorRewardResponse) -> Self {
+        Any {
+            type_url: MsgWithdrawDelegatorRewardResponse::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+}
+
+/// Response of [`MsgSetWithdrawAddr`]
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgSetWithdrawAddressResponse {}
+
+impl MsgSetWithdrawAddressResponse {
+    pub const TYPE_URL: &'static str = "/cosmos.distribution.v1beta1.MsgSetWithdrawAddressResponse";
+}
+
+impl From<MsgSetWithdrawAddressResponse> for Any {
+    fn from(msg: MsgSetWithdrawAddressResponse) -> Self {
+        Any {
+            type_url: MsgSetWithdrawAddressResponse::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+}
+
+/// Response of [`MsgFundCommunityPool`]
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgFundCommunityPoolResponse {}
+
+impl MsgFundCommunityPoolResponse {
+    pub const TYPE_URL: &'static str = "/cosmos.distribution.v1beta1.MsgFundCommunityPoolResponse";
+}
+
+impl From<MsgFundCommunityPoolResponse> for Any {
+    fn from(msg: MsgFundCommunityPoolResponse) -> Self {
+        Any {
+            type_url: MsgFundCommunityPoolResponse::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::{cell::RefCell, sync::Arc};
 
 use gears::{
     application::handlers::node::ABCIHandler,
-    baseapp::{options::NodeOptions, NullQueryRequest, NullQueryResponse},
+    baseapp::{mode::RunTxMode, options::NodeOptions, NullQueryRequest, NullQueryResponse},
     params::ParamsSubspaceKey,
     store::StoreKey,
     types::{
@@ -11,7 +11,7 @@ use gears::{
         tx::{raw::TxWithRaw, NullTxMsg},
     },
     x::{
-        ante::{BaseAnteHandler, SignGasConsumer},
+        ante::AnteDecorator,
         keepers::{
             auth::AuthKeeper,
             bank::BankKeeper,
@@ -31,10 +31,10 @@ pub struct GenutilAbciHandler<
     BK: StakingBankKeeper<SK, M> + BankKeeper<SK, M>,
     KH: KeeperHooks<SK, AK, M>,
     M: Module,
-    GC: SignGasConsumer,
+    AH: AnteDecorator<SK>,
 > {
     staking: staking::Keeper<SK, PSK, AK, BK, KH, M>,
-    ante_handler: BaseAnteHandler<BK, AK, SK, GC, M>,
+    ante_handler: AH,
 }
 
 impl<
@@ -44,18 +44,28 @@ impl<
         BK: StakingBankKeeper<SK, M>,
         KH: KeeperHooks<SK, AK, M>,
         M: Module,
-        GC: SignGasConsumer,
-    > GenutilAbciHandler<SK, PSK, AK, BK, KH, M, GC>
+        AH: AnteDecorator<SK>,
+    > GenutilAbciHandler<SK, PSK, AK, BK, KH, M, AH>
 {
-    pub fn new(
-        staking: staking::Keeper<SK, PSK, AK, BK, KH, M>,
-        ante_handler: BaseAnteHandler<BK, AK, SK, GC, M>,
-    ) -> Self {
+    /// Gentxs are checked by `ante_handler` before their messages run, which should be the
+    /// ante handler of the app
+    pub fn new(staking: staking::Keeper<SK, PSK, AK, BK, KH, M>, ante_handler: AH) -> Self {
         Self {
             staking,
             ante_handler,
         }
     }
+
+    /// Replaces the ante handler which checks the gentxs
+    pub fn with_ante_handler<N: AnteDecorator<SK>>(
+        self,
+        ante_handler: N,
+    ) -> GenutilAbciHandler<SK, PSK, AK, BK, KH, M, N> {
+        GenutilAbciHandler {
+            staking: self.staking,
+            ante_handler,
+        }
+    }
 }
 
 impl<
@@ -65,8 +75,8 @@ impl<
         BK: StakingBankKeeper<SK, M>,
         KH: KeeperHooks<SK, AK, M>,
         M: Module,
-        GC: SignGasConsumer,
-    > ABCIHandler for GenutilAbciHandler<SK, PSK, AK, BK, KH, M, GC>
+        AH: AnteDecorator<SK> + Clone + Send + Sync + 'static,
+    > ABCIHandler for GenutilAbciHandler<SK, PSK, AK, BK, KH, M, AH>
 {
     type Message = NullTxMsg;
 
@@ -90,7 +100,7 @@ impl<
         &self,
         _ctx: &mut gears::context::tx::TxContext<'_, DB, Self::StoreKey>,
         _tx: &gears::types::tx::raw::TxWithRaw<Self::Message>,
-        _: bool,
+        _: RunTxMode,
     ) -> Result<(), gears::application::handlers::node::TxError> {
         Ok(())
     }
@@ -99,7 +109,7 @@ impl<
         &self,
         _ctx: &mut gears::context::tx::TxContext<'_, DB, Self::StoreKey>,
         _msg: &Self::Message,
-    ) -> Result<(), gears::application::handlers::node::TxError> {
+    ) -> Result<gears::core::any::google::Any, gears::application::handlers::node::TxError> {
         unreachable!()
     }
 
@@ -114,12 +124,12 @@ impl<
             for mut tx in genesis.gen_txs {
                 tx.set_signatures_data();
                 let tx = TxWithRaw::from(tx);
-                let ante_check_res = self.ante_handler.run(
+                let ante_check_res = self.ante_handler.ante_handle(
                     ctx,
                     &tx,
-                    false,
-                    NodeOptions::new(MinGasPrices::default()),
-                    Arc::new(RefCell::new(GasMeter::infinite())),
+                    RunTxMode::Deliver,
+                    &NodeOptions::new(MinGasPrices::default()),
+                    &Arc::new(RefCell::new(GasMeter::infinite())),
                 );
 
                 match ante_check_res {
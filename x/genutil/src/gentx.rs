@@ -168,14 +168,14 @@ impl TxHandler for GentxTxHandler {
         }
 
         let pub_key = match pub_key {
-            Some(var) => PublicKey::from(var),
+            Some(var) => var,
             None => {
                 let file = std::fs::File::open(
                     client_tx_context
                         .home
                         .join("config/priv_validator_key.json"), // TODO: delegate this to tendermint crate
                 )?;
-                tendermint::get_validator_pub_key(file)?.into()
+                tendermint::get_validator_pub_key(file)?
             }
         };
 
@@ -206,7 +206,7 @@ impl TxHandler for GentxTxHandler {
             min_self_delegation,
             delegator_address: from_address.clone(),
             validator_address: from_address.into(),
-            pubkey: pub_key.into(),
+            pubkey: pub_key,
             value: amount,
         });
 
@@ -6,12 +6,12 @@ use gears::tendermint::request::RequestEndBlock;
 use gears::x::keepers::auth::AuthKeeper;
 use gears::{
     application::handlers::node::{ABCIHandler, ModuleInfo, TxError},
-    baseapp::errors::QueryError,
+    baseapp::{errors::QueryError, mode::RunTxMode},
     context::{
         block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext,
         TransactionalContext,
     },
-    core::Protobuf,
+    core::{any::google::Any, Protobuf},
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
     tendermint::types::{
@@ -21,7 +21,7 @@ use gears::{
         },
         request::query::RequestQuery,
     },
-    types::tx::raw::TxWithRaw,
+    types::tx::{raw::TxWithRaw, TxMessage},
     x::{
         keepers::{gov::GovernanceBankKeeper, staking::GovStakingKeeper},
         module::Module,
@@ -34,7 +34,7 @@ use crate::{
     errors::GovTxError,
     genesis::GovGenesisState,
     keeper::GovKeeper,
-    msg::{deposit::Deposit, GovMsg},
+    msg::{deposit::Deposit, proposal::MsgSubmitProposalResponse, GovMsg},
     query::{
         request::{
             QueryAllParamsRequest, QueryDepositRequest, QueryDepositsRequest, QueryParamsRequest,
@@ -115,7 +115,7 @@ impl<
         &self,
         _ctx: &mut TxContext<'_, DB, Self::StoreKey>,
         _tx: &TxWithRaw<Self::Message>,
-        _: bool,
+        _: RunTxMode,
     ) -> Result<(), TxError> {
         Ok(())
     }
@@ -124,13 +124,15 @@ impl<
         &self,
         ctx: &mut TxContext<'_, DB, Self::StoreKey>,
         msg: &Self::Message,
-    ) -> Result<(), TxError> {
+    ) -> Result<Any, TxError> {
         enum EmitEvent {
             Regular,
             Deposit(u64),
             Proposal((String, Option<u64>)),
         }
 
+        let mut response = msg.empty_response();
+
         let (address_str, proposal) = match msg {
             GovMsg::Deposit(msg) => {
                 self.keeper
@@ -158,6 +160,8 @@ impl<
                     .map_err(GovTxError::from)
                     .map_err(|e| e.into::<MI>())?;
 
+                response = MsgSubmitProposalResponse { proposal_id }.into();
+
                 self.keeper
                     .deposit_add(
                         ctx,
@@ -228,7 +232,7 @@ impl<
             }
         }
 
-        Ok(())
+        Ok(response)
     }
 
     fn init_genesis<DB: Database>(
@@ -53,6 +53,7 @@ use crate::{
     },
     types::{
         deposit_iter::DepositIterator,
+        events::EventProposalVote,
         proposal::{
             active_iter::ActiveProposalIterator, inactive_iter::InactiveProposalIterator,
             ProposalModel, ProposalStatus, ProposalsIterator, TallyResult,
@@ -445,17 +446,11 @@ impl<
 
         // TODO:NOW HOOK https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/gov/keeper/vote.go#L31
 
-        ctx.push_event(Event::new(
-            "proposal_vote",
-            vec![
-                EventAttribute::new("option".into(), format!("{:?}", vote.options).into(), false),
-                EventAttribute::new(
-                    "proposal_id".into(),
-                    format!("{}", vote.proposal_id).into(),
-                    false,
-                ),
-            ],
-        ));
+        ctx.emit_typed(&EventProposalVote {
+            option: vote.options,
+            proposal_id: vote.proposal_id,
+        })
+        .expect(SERDE_JSON_CONVERSION);
 
         Ok(())
     }
@@ -14,6 +14,7 @@ use weighted_vote::MsgVoteWeighted;
 
 pub mod deposit;
 pub mod proposal;
+mod rendering;
 pub mod vote;
 pub mod weighted_vote;
 
@@ -30,7 +31,12 @@ pub enum GovMsg {
 }
 
 impl ValueRenderer for GovMsg {
-    fn format<MG: MetadataGetter>(&self, _: &MG) -> Result<Vec<Screen>, RenderError> {
-        Err(RenderError::NotImplemented)
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        match self {
+            GovMsg::Deposit(msg) => msg.format(get_metadata),
+            GovMsg::Vote(msg) => msg.format(get_metadata),
+            GovMsg::Weighted(msg) => msg.format(get_metadata),
+            GovMsg::Proposal(msg) => msg.format(get_metadata),
+        }
     }
 }
@@ -8,10 +8,12 @@ use gears::{
     },
 };
 use ibc_proto::google::protobuf::Any;
+use prost::Message;
 use serde::{Deserialize, Serialize};
 
 mod inner {
     pub use ibc_proto::cosmos::gov::v1beta1::MsgSubmitProposal;
+    pub use ibc_proto::cosmos::gov::v1beta1::MsgSubmitProposalResponse;
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -111,3 +113,22 @@ impl From<MsgSubmitProposal> for Any {
         }
     }
 }
+
+/// Response of [`MsgSubmitProposal`] with the id of the submitted proposal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MsgSubmitProposalResponse {
+    pub proposal_id: u64,
+}
+
+impl MsgSubmitProposalResponse {
+    pub const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.MsgSubmitProposalResponse";
+}
+
+impl From<MsgSubmitProposalResponse> for Any {
+    fn from(MsgSubmitProposalResponse { proposal_id }: MsgSubmitProposalResponse) -> Self {
+        Any {
+            type_url: MsgSubmitProposalResponse::TYPE_URL.to_string(),
+            value: inner::MsgSubmitProposalResponse { proposal_id }.encode_to_vec(),
+        }
+    }
+}
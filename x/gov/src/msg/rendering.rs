@@ -0,0 +1,414 @@
+//! Implementation of formatting gov messages into `Screen`
+
+use gears::{
+    core::any::google::Any,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+            TryPrimitiveValueRenderer, TryPrimitiveValueRendererWithMetadata, ValueRenderer,
+        },
+    },
+    types::{
+        decimal256::Decimal256,
+        rendering::screen::{Content, Indent, Screen},
+    },
+};
+use prost::Message;
+
+use crate::proposal::{
+    param::RawParameterChangeProposal,
+    text::TextProposal,
+    upgrade::{CancelSoftwareUpgradeProposal, SoftwareUpgradeProposal},
+};
+
+use super::{
+    deposit::Deposit,
+    proposal::MsgSubmitProposal,
+    vote::{Vote, VoteOption},
+    weighted_vote::{MsgVoteWeighted, VoteOptionWeighted},
+};
+
+/// Enums are rendered with the name of their protobuf value
+fn vote_option_content(option: &VoteOption) -> Content {
+    let name = match option {
+        VoteOption::Empty => "VOTE_OPTION_UNSPECIFIED",
+        VoteOption::Yes => "VOTE_OPTION_YES",
+        VoteOption::Abstain => "VOTE_OPTION_ABSTAIN",
+        VoteOption::No => "VOTE_OPTION_NO",
+        VoteOption::NoWithVeto => "VOTE_OPTION_NO_WITH_VETO",
+    };
+
+    Content::try_new(name).expect("hard coded String is not empty")
+}
+
+/// Formats the fields of proposal content which is still encoded as `Any`. Param change
+/// proposals are decoded into their raw form as the subspace keys of the app are unknown here.
+fn content_screens<MG: MetadataGetter>(
+    content: &Any,
+    get_metadata: &MG,
+) -> Result<Vec<Screen>, RenderError> {
+    let decode_error = |e: String| RenderError::Rendering(format!("invalid proposal content: {e}"));
+
+    match content.type_url.as_str() {
+        TextProposal::TYPE_URL => TextProposal::try_from(content.clone())
+            .map_err(|e| decode_error(e.to_string()))?
+            .format(get_metadata),
+        RawParameterChangeProposal::TYPE_URL => {
+            RawParameterChangeProposal::decode(content.value.as_slice())
+                .map_err(|e| decode_error(e.to_string()))?
+                .format(get_metadata)
+        }
+        SoftwareUpgradeProposal::TYPE_URL => SoftwareUpgradeProposal::try_from(content.clone())
+            .map_err(|e| decode_error(e.to_string()))?
+            .format(get_metadata),
+        CancelSoftwareUpgradeProposal::TYPE_URL => {
+            CancelSoftwareUpgradeProposal::try_from(content.clone())
+                .map_err(|e| decode_error(e.to_string()))?
+                .format(get_metadata)
+        }
+        type_url => Err(RenderError::Rendering(format!(
+            "unknown proposal content type: {type_url}"
+        ))),
+    }
+}
+
+impl ValueRenderer for Deposit {
+    /// Format `Deposit`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Proposal id".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.proposal_id),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Depositor".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.depositor.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Amount".to_string(),
+                content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.amount.clone(),
+                    get_metadata,
+                )?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+impl ValueRenderer for Vote {
+    /// Format `Vote`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Proposal id".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.proposal_id),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Voter".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.voter.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Option".to_string(),
+                content: vote_option_content(&self.option),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+impl ValueRenderer for MsgVoteWeighted {
+    /// Format `MsgVoteWeighted`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = vec![
+            Screen {
+                title: "Proposal id".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.proposal_id),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Voter".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.voter.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ];
+
+        let options_count = self.options.len();
+        if options_count == 0 {
+            return Ok(screens);
+        }
+
+        screens.push(Screen {
+            title: "Options".to_string(),
+            content: DefaultPrimitiveRenderer::try_format(format!(
+                "{options_count} WeightedVoteOption"
+            ))?,
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+        for (i, VoteOptionWeighted { option, weight }) in self.options.iter().enumerate() {
+            screens.push(Screen {
+                title: format!("Options ({}/{options_count})", i + 1),
+                content: DefaultPrimitiveRenderer::try_format("WeightedVoteOption object")?,
+                indent: Some(Indent::three()),
+                expert: false,
+            });
+            screens.push(Screen {
+                title: "Option".to_string(),
+                content: vote_option_content(option),
+                indent: Some(Indent::four()),
+                expert: false,
+            });
+            screens.push(Screen {
+                title: "Weight".to_string(),
+                content: DefaultPrimitiveRenderer::format(Decimal256::from(weight.clone())),
+                indent: Some(Indent::four()),
+                expert: false,
+            });
+        }
+        screens.push(Screen {
+            title: String::new(),
+            content: DefaultPrimitiveRenderer::try_format("End of Options")?,
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+
+        Ok(screens)
+    }
+}
+
+impl ValueRenderer for MsgSubmitProposal {
+    /// Format `MsgSubmitProposal`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = vec![Screen {
+            title: "Content".to_string(),
+            content: DefaultPrimitiveRenderer::try_format(self.content.type_url.as_str())?,
+            indent: Some(Indent::two()),
+            expert: false,
+        }];
+        screens.append(&mut content_screens(&self.content, get_metadata)?);
+
+        screens.push(Screen {
+            title: "Initial deposit".to_string(),
+            content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                self.initial_deposit.clone(),
+                get_metadata,
+            )?,
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+        screens.push(Screen {
+            title: "Proposer".to_string(),
+            content: DefaultPrimitiveRenderer::format(self.proposer.clone()),
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+
+        Ok(screens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZero;
+
+    use gears::{
+        core::any::google::Any,
+        signing::renderer::{test_utils::TestMetadataGetter, value_renderer::ValueRenderer},
+        types::{address::AccAddress, rendering::screen::Screen},
+    };
+    use upgrade::types::plan::{Plan, PlanName};
+
+    use crate::{
+        msg::{
+            deposit::Deposit,
+            proposal::MsgSubmitProposal,
+            vote::{Vote, VoteOption},
+        },
+        proposal::{
+            param::{RawParamChange, RawParameterChangeProposal},
+            text::TextProposal,
+            upgrade::SoftwareUpgradeProposal,
+        },
+    };
+
+    const PROPOSER: &str = "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs";
+
+    fn submit_proposal(content: Any) -> anyhow::Result<MsgSubmitProposal> {
+        Ok(MsgSubmitProposal {
+            content,
+            initial_deposit: serde_json::from_str(r#"[{ "denom": "uatom", "amount": "100000" }]"#)?,
+            proposer: AccAddress::from_bech32(PROPOSER)?,
+        })
+    }
+
+    #[test]
+    fn msg_deposit_works() -> anyhow::Result<()> {
+        let msg = Deposit {
+            proposal_id: 1000,
+            depositor: AccAddress::from_bech32(PROPOSER)?,
+            amount: serde_json::from_str(r#"[{ "denom": "uatom", "amount": "10000000" }]"#)?,
+        };
+
+        const SCREENS: &str = r#"[
+            { "title": "Proposal id", "content": "1'000", "indent": 2 },
+            { "title": "Depositor", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 },
+            { "title": "Amount", "content": "10 ATOM", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_vote_works() -> anyhow::Result<()> {
+        let msg = Vote {
+            proposal_id: 3,
+            voter: AccAddress::from_bech32(PROPOSER)?,
+            option: VoteOption::NoWithVeto,
+        };
+
+        const SCREENS: &str = r#"[
+            { "title": "Proposal id", "content": "3", "indent": 2 },
+            { "title": "Voter", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 },
+            { "title": "Option", "content": "VOTE_OPTION_NO_WITH_VETO", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_submit_text_proposal_works() -> anyhow::Result<()> {
+        let msg = submit_proposal(
+            TextProposal {
+                title: "Test proposal".to_string(),
+                description: "Lorem ipsum".to_string(),
+            }
+            .into(),
+        )?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Content", "content": "/cosmos.params.v1beta1/TextProposal", "indent": 2 },
+            { "title": "Title", "content": "Test proposal", "indent": 3 },
+            { "title": "Description", "content": "Lorem ipsum", "indent": 3 },
+            { "title": "Initial deposit", "content": "0.1 ATOM", "indent": 2 },
+            { "title": "Proposer", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_submit_param_change_proposal_works() -> anyhow::Result<()> {
+        let msg = submit_proposal(
+            RawParameterChangeProposal {
+                title: "Change params".to_string(),
+                description: "Lorem ipsum".to_string(),
+                changes: vec![RawParamChange {
+                    subspace: "staking".to_string(),
+                    key: b"MaxValidators".to_vec(),
+                    value: b"\"105\"".to_vec(),
+                }],
+            }
+            .into(),
+        )?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Content", "content": "/cosmos.params.v1beta1/ParameterChangeProposal", "indent": 2 },
+            { "title": "Title", "content": "Change params", "indent": 3 },
+            { "title": "Description", "content": "Lorem ipsum", "indent": 3 },
+            { "title": "Changes", "content": "1 ParamChange", "indent": 3 },
+            { "title": "Changes (1/1)", "content": "ParamChange object", "indent": 4 },
+            { "title": "Subspace", "content": "staking", "indent": 5 },
+            { "title": "Key", "content": "MaxValidators", "indent": 5 },
+            { "title": "Value", "content": "\"105\"", "indent": 5 },
+            { "content": "End of Changes", "indent": 3 },
+            { "title": "Initial deposit", "content": "0.1 ATOM", "indent": 2 },
+            { "title": "Proposer", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_submit_software_upgrade_proposal_works() -> anyhow::Result<()> {
+        let msg = submit_proposal(
+            SoftwareUpgradeProposal {
+                title: "Upgrade".to_string(),
+                description: "Lorem ipsum".to_string(),
+                plan: Plan {
+                    name: PlanName::try_new("v2")?,
+                    height: NonZero::new(1_000_000).expect("height is not zero"),
+                    info: String::new(),
+                },
+            }
+            .into(),
+        )?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Content", "content": "/cosmos.upgrade.v1beta1/SoftwareUpgradeProposal", "indent": 2 },
+            { "title": "Title", "content": "Upgrade", "indent": 3 },
+            { "title": "Description", "content": "Lorem ipsum", "indent": 3 },
+            { "title": "Plan", "content": "Plan object", "indent": 3 },
+            { "title": "Name", "content": "v2", "indent": 4 },
+            { "title": "Height", "content": "1'000'000", "indent": 4 },
+            { "title": "Initial deposit", "content": "0.1 ATOM", "indent": 2 },
+            { "title": "Proposer", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_proposal_content_fails() -> anyhow::Result<()> {
+        let msg = submit_proposal(Any {
+            type_url: "/cosmos.unknown.v1beta1/Proposal".to_string(),
+            value: Vec::new(),
+        })?;
+
+        assert!(ValueRenderer::format(&msg, &TestMetadataGetter).is_err());
+
+        Ok(())
+    }
+}
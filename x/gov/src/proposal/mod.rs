@@ -1,5 +1,6 @@
 mod handler;
 pub mod param;
+mod rendering;
 pub mod text;
 pub mod upgrade;
 
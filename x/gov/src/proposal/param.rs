@@ -64,10 +64,14 @@ impl<PSK: ParamsSubspaceKey> Serialize for ParameterChangeProposal<PSK> {
     }
 }
 
+impl RawParameterChangeProposal {
+    pub const TYPE_URL: &'static str = "/cosmos.params.v1beta1/ParameterChangeProposal";
+}
+
 impl From<RawParameterChangeProposal> for Any {
     fn from(msg: RawParameterChangeProposal) -> Self {
         Any {
-            type_url: "/cosmos.params.v1beta1/ParameterChangeProposal".to_owned(),
+            type_url: Self::TYPE_URL.to_owned(),
             value: msg.encode_to_vec(),
         }
     }
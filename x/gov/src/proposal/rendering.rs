@@ -0,0 +1,185 @@
+//! Implementation of formatting proposal contents into `Screen`. The contents are only signed as
+//! a part of `MsgSubmitProposal` so their fields are nested one level deeper than message fields.
+
+use gears::{
+    params::ParamsSubspaceKey,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+            TryPrimitiveValueRenderer, ValueRenderer,
+        },
+    },
+    types::rendering::screen::{Content, Indent, Screen},
+};
+use upgrade::types::plan::Plan;
+
+use super::{
+    param::{ParameterChangeProposal, RawParamChange, RawParameterChangeProposal},
+    text::TextProposal,
+    upgrade::{CancelSoftwareUpgradeProposal, SoftwareUpgradeProposal},
+    Proposals,
+};
+
+fn title_and_description_screens(
+    title: &str,
+    description: &str,
+) -> Result<Vec<Screen>, RenderError> {
+    let mut screens = Vec::with_capacity(2);
+
+    // default values are omitted like in the cosmos sdk
+    for (screen_title, value) in [("Title", title), ("Description", description)] {
+        if !value.is_empty() {
+            screens.push(Screen {
+                title: screen_title.to_string(),
+                content: DefaultPrimitiveRenderer::try_format(value)?,
+                indent: Some(Indent::three()),
+                expert: false,
+            });
+        }
+    }
+
+    Ok(screens)
+}
+
+/// Param keys and values are strings in the cosmos sdk, so they are shown as text when possible
+fn param_bytes_content(value: &[u8]) -> Result<Content, RenderError> {
+    match std::str::from_utf8(value) {
+        Ok(value) => DefaultPrimitiveRenderer::try_format(value),
+        Err(_) => DefaultPrimitiveRenderer::try_format(value),
+    }
+}
+
+fn plan_screens(plan: &Plan) -> Result<Vec<Screen>, RenderError> {
+    let mut screens = vec![
+        Screen {
+            title: "Plan".to_string(),
+            content: DefaultPrimitiveRenderer::try_format("Plan object")?,
+            indent: Some(Indent::three()),
+            expert: false,
+        },
+        Screen {
+            title: "Name".to_string(),
+            content: DefaultPrimitiveRenderer::try_format(plan.name.clone().into_inner())?,
+            indent: Some(Indent::four()),
+            expert: false,
+        },
+        Screen {
+            title: "Height".to_string(),
+            content: DefaultPrimitiveRenderer::format(plan.height.get()),
+            indent: Some(Indent::four()),
+            expert: false,
+        },
+    ];
+
+    if !plan.info.is_empty() {
+        screens.push(Screen {
+            title: "Info".to_string(),
+            content: DefaultPrimitiveRenderer::try_format(plan.info.as_str())?,
+            indent: Some(Indent::four()),
+            expert: false,
+        });
+    }
+
+    Ok(screens)
+}
+
+impl ValueRenderer for TextProposal {
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        title_and_description_screens(&self.title, &self.description)
+    }
+}
+
+impl ValueRenderer for RawParameterChangeProposal {
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = title_and_description_screens(&self.title, &self.description)?;
+
+        let changes_count = self.changes.len();
+        if changes_count == 0 {
+            return Ok(screens);
+        }
+
+        let change_fields_indent = Indent::try_new(5).expect("indent is less than 16");
+
+        screens.push(Screen {
+            title: "Changes".to_string(),
+            content: DefaultPrimitiveRenderer::try_format(format!("{changes_count} ParamChange"))?,
+            indent: Some(Indent::three()),
+            expert: false,
+        });
+        for (
+            i,
+            RawParamChange {
+                subspace,
+                key,
+                value,
+            },
+        ) in self.changes.iter().enumerate()
+        {
+            screens.push(Screen {
+                title: format!("Changes ({}/{changes_count})", i + 1),
+                content: DefaultPrimitiveRenderer::try_format("ParamChange object")?,
+                indent: Some(Indent::four()),
+                expert: false,
+            });
+            screens.push(Screen {
+                title: "Subspace".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(subspace.as_str())?,
+                indent: Some(change_fields_indent),
+                expert: false,
+            });
+            screens.push(Screen {
+                title: "Key".to_string(),
+                content: param_bytes_content(key)?,
+                indent: Some(change_fields_indent),
+                expert: false,
+            });
+            screens.push(Screen {
+                title: "Value".to_string(),
+                content: param_bytes_content(value)?,
+                indent: Some(change_fields_indent),
+                expert: false,
+            });
+        }
+        screens.push(Screen {
+            title: String::new(),
+            content: DefaultPrimitiveRenderer::try_format("End of Changes")?,
+            indent: Some(Indent::three()),
+            expert: false,
+        });
+
+        Ok(screens)
+    }
+}
+
+impl<PSK: ParamsSubspaceKey> ValueRenderer for ParameterChangeProposal<PSK> {
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        RawParameterChangeProposal::from(self.clone()).format(get_metadata)
+    }
+}
+
+impl ValueRenderer for SoftwareUpgradeProposal {
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = title_and_description_screens(&self.title, &self.description)?;
+        screens.append(&mut plan_screens(&self.plan)?);
+
+        Ok(screens)
+    }
+}
+
+impl ValueRenderer for CancelSoftwareUpgradeProposal {
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        title_and_description_screens(&self.title, &self.description)
+    }
+}
+
+impl<PSK: ParamsSubspaceKey> ValueRenderer for Proposals<PSK> {
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        match self {
+            Proposals::Text(proposal) => proposal.format(get_metadata),
+            Proposals::Params(proposal) => proposal.format(get_metadata),
+            Proposals::Upgrade(proposal) => proposal.format(get_metadata),
+            Proposals::CancelUpgrade(proposal) => proposal.format(get_metadata),
+        }
+    }
+}
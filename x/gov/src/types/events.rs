@@ -0,0 +1,14 @@
+use gears::derive::TypedEvent;
+use serde::Serialize;
+
+use crate::msg::weighted_vote::VoteOptionWeighted;
+
+/// Emitted when a vote is cast on a proposal. The options are JSON encoded with their weights.
+#[derive(Debug, Clone, PartialEq, Serialize, TypedEvent)]
+#[event(kind = "proposal_vote")]
+pub struct EventProposalVote {
+    #[event(no_index)]
+    pub option: Vec<VoteOptionWeighted>,
+    #[event(no_index)]
+    pub proposal_id: u64,
+}
@@ -1,4 +1,5 @@
 pub mod deposit_iter;
+pub mod events;
 pub mod proposal;
 pub mod validator;
 pub mod vote_iters;
@@ -8,9 +8,10 @@ use gears::{
     application::handlers::node::{ModuleInfo, TxError},
     baseapp::errors::QueryError,
     context::{init::InitContext, query::QueryContext, tx::TxContext},
-    core::errors::CoreError,
+    core::{any::google::Any, errors::CoreError},
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
+    types::tx::TxMessage,
 };
 use ibc::primitives::proto::Protobuf;
 //use ibc::core::client::types::{
@@ -46,7 +47,9 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler<SK, PSK,
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
         msg: Message,
-    ) -> Result<(), TxError> {
+    ) -> Result<Any, TxError> {
+        let response = msg.empty_response();
+
         match msg {
             Message::ClientCreate(msg) => {
                 // let MsgCreateClient {
@@ -63,7 +66,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler<SK, PSK,
                     .client_create(ctx, msg)
                     .map_err(|e| TxError::new::<MI>(e.to_string(), nz::u16!(1)))?;
 
-                Ok(())
+                Ok(response)
             } // Message::ClientUpdate(msg) => {
               //     let MsgUpdateClient {
               //         client_id,
@@ -1,7 +1,18 @@
 use bytes::Bytes;
 use gears::{
     core::any::google::Any,
-    types::{address::AccAddress, tx::TxMessage},
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+            TryPrimitiveValueRenderer, ValueRenderer,
+        },
+    },
+    types::{
+        address::AccAddress,
+        rendering::screen::{Indent, Screen},
+        tx::TxMessage,
+    },
 };
 use ibc::{
     clients::tendermint::{client_state::ClientState, consensus_state::ConsensusState},
@@ -57,6 +68,48 @@ impl TxMessage for MsgCreateClient {
     }
 }
 
+impl ValueRenderer for MsgCreateClient {
+    /// Format `MsgCreateClient`. The client and consensus states are rendered as their type URL
+    /// followed by the encoded state, which is hashed when it is long.
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let RawMsgCreateClient {
+            client_state,
+            consensus_state,
+            signer: _,
+        } = self.clone().into();
+
+        let mut screens = Vec::new();
+        for (title, state) in [
+            ("Client state", client_state),
+            ("Consensus state", consensus_state),
+        ] {
+            let state = state.ok_or_else(|| RenderError::Rendering(format!("missing {title}")))?;
+
+            screens.push(Screen {
+                title: title.to_string(),
+                content: DefaultPrimitiveRenderer::try_format(state.type_url)?,
+                indent: Some(Indent::two()),
+                expert: false,
+            });
+            screens.push(Screen {
+                title: "Value".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(state.value.as_slice())?,
+                indent: Some(Indent::three()),
+                expert: true,
+            });
+        }
+        screens.push(Screen {
+            title: "Signer".to_string(),
+            content: DefaultPrimitiveRenderer::format(self.signer.clone()),
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+
+        Ok(screens)
+    }
+}
+
 impl From<MsgCreateClient> for Any {
     fn from(msg: MsgCreateClient) -> Self {
         Any {
@@ -116,3 +169,91 @@ impl From<MsgCreateClient> for RawMsgCreateClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use gears::{
+        signing::renderer::{
+            test_utils::TestMetadataGetter,
+            value_renderer::{DefaultPrimitiveRenderer, TryPrimitiveValueRenderer, ValueRenderer},
+        },
+        types::{address::AccAddress, rendering::screen::Screen},
+    };
+    use ibc::{
+        clients::tendermint::{
+            client_state::ClientState,
+            consensus_state::ConsensusState,
+            types::{
+                AllowUpdate, ClientState as ClientStateType, TrustThreshold,
+                TENDERMINT_CLIENT_STATE_TYPE_URL, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+            },
+        },
+        core::{
+            client::types::Height, commitment_types::specs::ProofSpecs,
+            host::types::identifiers::ChainId,
+        },
+        primitives::proto::Any,
+    };
+
+    use super::MsgCreateClient;
+
+    #[test]
+    fn msg_create_client_works() -> anyhow::Result<()> {
+        let client_state: ClientState = ClientStateType::new(
+            ChainId::new("test-1")?,
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(1000),
+            Duration::from_secs(2000),
+            Duration::from_secs(20),
+            Height::new(1, 3)?,
+            ProofSpecs::cosmos(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        )?
+        .into();
+
+        // timestamp of 1 second, commitment root of [1; 32] and next validators hash of [2; 32]
+        let mut consensus_state = vec![0x0a, 0x02, 0x08, 0x01, 0x12, 0x22, 0x0a, 0x20];
+        consensus_state.extend([1; 32]);
+        consensus_state.extend([0x1a, 0x20]);
+        consensus_state.extend([2; 32]);
+        let consensus_state = ConsensusState::try_from(Any {
+            type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: consensus_state,
+        })?;
+
+        let msg = MsgCreateClient::new(
+            client_state.clone(),
+            consensus_state,
+            AccAddress::from_bech32("cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs")?,
+        );
+
+        // the encoded client state is longer than 35 bytes so only its hash is shown
+        let client_state_value =
+            DefaultPrimitiveRenderer::try_format(Any::from(client_state).value.as_slice())?;
+        assert!(client_state_value.as_ref().starts_with("SHA-256="));
+
+        let screens = format!(
+            r#"[
+                {{ "title": "Client state", "content": "{TENDERMINT_CLIENT_STATE_TYPE_URL}", "indent": 2 }},
+                {{ "title": "Value", "content": "{}", "indent": 3, "expert": true }},
+                {{ "title": "Consensus state", "content": "{TENDERMINT_CONSENSUS_STATE_TYPE_URL}", "indent": 2 }},
+                {{ "title": "Value", "content": "SHA-256=EC30 57FE 753F E274 B6DB 121F 60E2 E3B6 6AE2 44CD 8A0A A0B5 C8B9 17EA 7D5C 09F7", "indent": 3, "expert": true }},
+                {{ "title": "Signer", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 }}
+            ]"#,
+            client_state_value.as_ref()
+        );
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(&screens)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,11 @@
-use gears::derive::AppMessage;
+use gears::{
+    derive::AppMessage,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{RenderError, ValueRenderer},
+    },
+    types::rendering::screen::Screen,
+};
 
 use crate::ics02_client::message::MsgCreateClient;
 
@@ -10,3 +17,11 @@ pub enum Message {
     // ClientUpgrade(MsgUpgradeClient),
     // RecoverClient(MsgRecoverClient),
 }
+
+impl ValueRenderer for Message {
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        match self {
+            Message::ClientCreate(msg) => msg.format(get_metadata),
+        }
+    }
+}
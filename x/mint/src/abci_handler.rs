@@ -143,7 +143,7 @@ impl<
         &self,
         _ctx: &mut gears::context::tx::TxContext<'_, DB, Self::StoreKey>,
         _msg: &Self::Message,
-    ) -> Result<(), gears::application::handlers::node::TxError> {
+    ) -> Result<gears::core::any::google::Any, gears::application::handlers::node::TxError> {
         unreachable!("Module {} doesn't have any tx", MI::NAME)
     }
 
@@ -1,12 +1,12 @@
 use crate::{
-    errors::SlashingTxError, GenesisState, Keeper, Message, QueryParamsRequest,
+    errors::SlashingTxError, GenesisState, Keeper, Message, MsgUnjailResponse, QueryParamsRequest,
     QueryParamsResponse, QuerySigningInfoRequest, QuerySigningInfosRequest,
     QuerySigningInfosResponse,
 };
 use gears::{
     baseapp::{errors::QueryError, QueryResponse},
     context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
-    core::Protobuf,
+    core::{any::google::Any, Protobuf},
     extensions::pagination::Pagination,
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
@@ -55,9 +55,12 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
         msg: &Message,
-    ) -> Result<(), SlashingTxError> {
+    ) -> Result<Any, SlashingTxError> {
         match msg {
-            Message::Unjail(msg) => Ok(self.keeper.unjail_tx_handler(ctx, msg)?),
+            Message::Unjail(msg) => {
+                self.keeper.unjail_tx_handler(ctx, msg)?;
+                Ok(MsgUnjailResponse {}.into())
+            }
         }
     }
 
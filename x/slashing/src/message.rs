@@ -1,4 +1,11 @@
-use gears::derive::AppMessage;
+use gears::{
+    derive::AppMessage,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{RenderError, ValueRenderer},
+    },
+    types::rendering::screen::Screen,
+};
 use serde::Serialize;
 
 use crate::MsgUnjail;
@@ -9,3 +16,11 @@ pub enum Message {
     #[msg(url(path = MsgUnjail::TYPE_URL))]
     Unjail(MsgUnjail),
 }
+
+impl ValueRenderer for Message {
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        match self {
+            Message::Unjail(msg) => msg.format(get_metadata),
+        }
+    }
+}
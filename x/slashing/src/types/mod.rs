@@ -1,5 +1,6 @@
 mod missed_block;
 mod query;
+mod rendering;
 mod signing_info;
 mod tx;
 
@@ -0,0 +1,57 @@
+//! Implementation of formatting slashing messages into `Screen`
+
+use gears::{
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError, ValueRenderer,
+        },
+    },
+    types::rendering::screen::{Indent, Screen},
+};
+
+use crate::MsgUnjail;
+
+impl ValueRenderer for MsgUnjail {
+    /// Format `MsgUnjail`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![Screen {
+            title: "Validator addr".to_string(),
+            content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+            indent: Some(Indent::two()),
+            expert: false,
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        signing::renderer::{test_utils::TestMetadataGetter, value_renderer::ValueRenderer},
+        types::rendering::screen::Screen,
+    };
+
+    use crate::MsgUnjail;
+
+    #[test]
+    fn msg_unjail_works() -> anyhow::Result<()> {
+        const MESSAGE: &str = r#"{
+            "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+            "from_address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux"
+        }"#;
+
+        let msg: MsgUnjail = serde_json::from_str(MESSAGE)?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Validator addr", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use gears::{
-    core::Protobuf,
+    core::{any::google::Any, Protobuf},
     derive::AppMessage,
     types::address::{AccAddress, AddressError, ValAddress},
 };
@@ -44,3 +44,20 @@ impl TryFrom<MsgUnjailRaw> for MsgUnjail {
 }
 
 impl Protobuf<MsgUnjailRaw> for MsgUnjail {}
+
+/// Response of [`MsgUnjail`]
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgUnjailResponse {}
+
+impl MsgUnjailResponse {
+    pub const TYPE_URL: &'static str = "/cosmos.slashing.v1beta1.MsgUnjailResponse";
+}
+
+impl From<MsgUnjailResponse> for Any {
+    fn from(msg: MsgUnjailResponse) -> Self {
+        Any {
+            type_url: MsgUnjailResponse::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+}
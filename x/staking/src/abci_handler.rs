@@ -1,6 +1,7 @@
 use crate::{
-    error::StakingTxError, GenesisState, Keeper, Message, QueryDelegationRequest,
-    QueryDelegationResponse, QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
+    error::StakingTxError, GenesisState, Keeper, Message, MsgBeginRedelegateResponse,
+    MsgUndelegateResponse, QueryDelegationRequest, QueryDelegationResponse,
+    QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
     QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorUnbondingDelegationsResponse,
     QueryParamsRequest, QueryParamsResponse, QueryPoolRequest, QueryPoolResponse,
     QueryRedelegationsRequest, QueryRedelegationsResponse, QueryUnbondingDelegationRequest,
@@ -17,9 +18,9 @@ use crate::{
 };
 use gears::{
     application::handlers::node::{ABCIHandler, ModuleInfo, TxError},
-    baseapp::{errors::QueryError, QueryRequest, QueryResponse},
+    baseapp::{errors::QueryError, mode::RunTxMode, QueryRequest, QueryResponse},
     context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
-    core::Protobuf,
+    core::{any::google::Any, Protobuf},
     derive::Query,
     extensions::{
         gas::GasResultExt,
@@ -31,7 +32,7 @@ use gears::{
         request::{RequestBeginBlock, RequestEndBlock},
         types::{proto::validator::ValidatorUpdate, request::query::RequestQuery},
     },
-    types::pagination::response::PaginationResponse,
+    types::{pagination::response::PaginationResponse, tx::TxMessage},
     x::{
         keepers::{
             auth::AuthKeeper,
@@ -192,7 +193,7 @@ impl<
         &self,
         _: &mut TxContext<'_, DB, Self::StoreKey>,
         _: &gears::types::tx::raw::TxWithRaw<Self::Message>,
-        _: bool,
+        _: RunTxMode,
     ) -> Result<(), TxError> {
         Ok(())
     }
@@ -201,16 +202,38 @@ impl<
         &self,
         ctx: &mut TxContext<'_, DB, Self::StoreKey>,
         msg: &Self::Message,
-    ) -> Result<(), TxError> {
+    ) -> Result<Any, TxError> {
         let result = match msg {
-            Message::CreateValidator(msg) => {
-                self.keeper
-                    .create_validator(ctx, ctx.consensus_params().validator.clone(), msg)
-            }
-            Message::EditValidator(msg) => self.keeper.edit_validator(ctx, msg),
-            Message::Delegate(msg) => self.keeper.delegate_cmd_handler(ctx, msg),
-            Message::Redelegate(msg) => self.keeper.redelegate_cmd_handler(ctx, msg),
-            Message::Undelegate(msg) => self.keeper.undelegate_cmd_handler(ctx, msg),
+            Message::CreateValidator(create_msg) => self
+                .keeper
+                .create_validator(ctx, ctx.consensus_params().validator.clone(), create_msg)
+                .map(|_| msg.empty_response()),
+            Message::EditValidator(edit_msg) => self
+                .keeper
+                .edit_validator(ctx, edit_msg)
+                .map(|_| msg.empty_response()),
+            Message::Delegate(delegate_msg) => self
+                .keeper
+                .delegate_cmd_handler(ctx, delegate_msg)
+                .map(|_| msg.empty_response()),
+            Message::Redelegate(redelegate_msg) => self
+                .keeper
+                .redelegate_cmd_handler(ctx, redelegate_msg)
+                .map(|completion_time| {
+                    MsgBeginRedelegateResponse {
+                        completion_time: Some(completion_time),
+                    }
+                    .into()
+                }),
+            Message::Undelegate(undelegate_msg) => self
+                .keeper
+                .undelegate_cmd_handler(ctx, undelegate_msg)
+                .map(|completion_time| {
+                    MsgUndelegateResponse {
+                        completion_time: Some(completion_time),
+                    }
+                    .into()
+                }),
         };
 
         result.map_err(|e| Into::<StakingTxError>::into(e).into::<MI>())
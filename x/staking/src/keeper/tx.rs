@@ -294,7 +294,7 @@ impl<
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
         msg: &RedelegateMsg,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<Timestamp, anyhow::Error> {
         let shares = self
             .validate_unbond_amount(
                 ctx,
@@ -371,7 +371,7 @@ impl<
             },
         ]);
 
-        Ok(())
+        Ok(completion_time)
     }
 
     /// undelegate_cmd_handler defines a method for performing an undelegation from a delegate and a validator
@@ -379,7 +379,7 @@ impl<
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
         msg: &UndelegateMsg,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<Timestamp, anyhow::Error> {
         let shares = self
             .validate_unbond_amount(
                 ctx,
@@ -445,6 +445,6 @@ impl<
             },
         ]);
 
-        Ok(())
+        Ok(completion_time)
     }
 }
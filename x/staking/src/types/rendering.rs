@@ -0,0 +1,464 @@
+//! Implementation of formatting staking messages into `Screen`
+
+use gears::{
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+            TryPrimitiveValueRenderer, TryPrimitiveValueRendererWithMetadata, ValueRenderer,
+        },
+    },
+    tendermint::types::proto::crypto::PublicKey,
+    types::rendering::screen::{Indent, Screen},
+};
+
+use crate::{
+    CommissionRates, CreateValidator, DelegateMsg, EditDescription, EditValidator, RedelegateMsg,
+    UndelegateMsg,
+};
+
+fn description_screens(description: &EditDescription) -> Result<Vec<Screen>, RenderError> {
+    let mut screens = vec![Screen {
+        title: "Description".to_string(),
+        content: DefaultPrimitiveRenderer::try_format("Description object")?,
+        indent: Some(Indent::two()),
+        expert: false,
+    }];
+
+    let fields = [
+        ("Moniker", &description.moniker),
+        ("Identity", &description.identity),
+        ("Website", &description.website),
+        ("Security contact", &description.security_contact),
+        ("Details", &description.details),
+    ];
+    // default values are omitted like in the cosmos sdk
+    for (title, value) in fields {
+        if let Some(value) = value.as_ref().filter(|value| !value.is_empty()) {
+            screens.push(Screen {
+                title: title.to_string(),
+                content: DefaultPrimitiveRenderer::try_format(value.as_str())?,
+                indent: Some(Indent::three()),
+                expert: false,
+            });
+        }
+    }
+
+    Ok(screens)
+}
+
+fn commission_screens(commission: &CommissionRates) -> Result<Vec<Screen>, RenderError> {
+    Ok(vec![
+        Screen {
+            title: "Commission".to_string(),
+            content: DefaultPrimitiveRenderer::try_format("CommissionRates object")?,
+            indent: Some(Indent::two()),
+            expert: false,
+        },
+        Screen {
+            title: "Rate".to_string(),
+            content: DefaultPrimitiveRenderer::format(commission.rate()),
+            indent: Some(Indent::three()),
+            expert: false,
+        },
+        Screen {
+            title: "Max rate".to_string(),
+            content: DefaultPrimitiveRenderer::format(commission.max_rate()),
+            indent: Some(Indent::three()),
+            expert: false,
+        },
+        Screen {
+            title: "Max change rate".to_string(),
+            content: DefaultPrimitiveRenderer::format(commission.max_change_rate()),
+            indent: Some(Indent::three()),
+            expert: false,
+        },
+    ])
+}
+
+fn pubkey_screens(pubkey: &PublicKey) -> Result<Vec<Screen>, RenderError> {
+    let type_url = match pubkey {
+        PublicKey::Ed25519(_) => "/cosmos.crypto.ed25519.PubKey",
+        PublicKey::Secp256k1(_) => "/cosmos.crypto.secp256k1.PubKey",
+    };
+
+    Ok(vec![
+        Screen {
+            title: "Pubkey".to_string(),
+            content: DefaultPrimitiveRenderer::try_format(type_url)?,
+            indent: Some(Indent::two()),
+            expert: false,
+        },
+        Screen {
+            title: "Key".to_string(),
+            content: DefaultPrimitiveRenderer::try_format(pubkey.raw())?,
+            indent: Some(Indent::three()),
+            expert: false,
+        },
+    ])
+}
+
+impl ValueRenderer for CreateValidator {
+    /// Format `CreateValidator`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = description_screens(&self.description.clone().into())?;
+        screens.append(&mut commission_screens(&self.commission)?);
+
+        screens.push(Screen {
+            title: "Min self delegation".to_string(),
+            content: DefaultPrimitiveRenderer::format(self.min_self_delegation),
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+        screens.push(Screen {
+            title: "Delegator address".to_string(),
+            content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+        screens.push(Screen {
+            title: "Validator address".to_string(),
+            content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+        screens.append(&mut pubkey_screens(&self.pubkey)?);
+        screens.push(Screen {
+            title: "Value".to_string(),
+            content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                self.value.clone(),
+                get_metadata,
+            )?,
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+
+        Ok(screens)
+    }
+}
+
+impl ValueRenderer for EditValidator {
+    /// Format `EditValidator`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = description_screens(&self.description)?;
+
+        screens.push(Screen {
+            title: "Validator address".to_string(),
+            content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+        if let Some(commission_rate) = self.commission_rate {
+            screens.push(Screen {
+                title: "Commission rate".to_string(),
+                content: DefaultPrimitiveRenderer::format(commission_rate),
+                indent: Some(Indent::two()),
+                expert: false,
+            });
+        }
+        if let Some(min_self_delegation) = self.min_self_delegation {
+            screens.push(Screen {
+                title: "Min self delegation".to_string(),
+                content: DefaultPrimitiveRenderer::format(min_self_delegation),
+                indent: Some(Indent::two()),
+                expert: false,
+            });
+        }
+
+        Ok(screens)
+    }
+}
+
+impl ValueRenderer for DelegateMsg {
+    /// Format `DelegateMsg`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Validator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Amount".to_string(),
+                content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.amount.clone(),
+                    get_metadata,
+                )?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+impl ValueRenderer for RedelegateMsg {
+    /// Format `RedelegateMsg`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Validator src address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.src_validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Validator dst address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.dst_validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Amount".to_string(),
+                content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.amount.clone(),
+                    get_metadata,
+                )?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+impl ValueRenderer for UndelegateMsg {
+    /// Format `UndelegateMsg`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Validator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Amount".to_string(),
+                content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.amount.clone(),
+                    get_metadata,
+                )?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        signing::renderer::{test_utils::TestMetadataGetter, value_renderer::ValueRenderer},
+        types::rendering::screen::Screen,
+    };
+
+    use std::str::FromStr;
+
+    use gears::types::{address::ValAddress, decimal256::Decimal256, uint::Uint256};
+
+    use crate::{
+        CreateValidator, DelegateMsg, EditDescription, EditValidator, RedelegateMsg, UndelegateMsg,
+    };
+
+    #[test]
+    fn msg_delegate_works() -> anyhow::Result<()> {
+        const MESSAGE: &str = r#"{
+            "delegator_address": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs",
+            "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+            "amount": { "denom": "uatom", "amount": "10000000" }
+        }"#;
+
+        let msg: DelegateMsg = serde_json::from_str(MESSAGE)?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Delegator address", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 },
+            { "title": "Validator address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 },
+            { "title": "Amount", "content": "10 ATOM", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_begin_redelegate_works() -> anyhow::Result<()> {
+        const MESSAGE: &str = r#"{
+            "delegator_address": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs",
+            "src_validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+            "dst_validator_address": "cosmosvaloper15jlqmacda2pzerhw48gvvxskweg8sz2scfexfk",
+            "amount": { "denom": "ucosm", "amount": "1500" }
+        }"#;
+
+        let msg: RedelegateMsg = serde_json::from_str(MESSAGE)?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Delegator address", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 },
+            { "title": "Validator src address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 },
+            { "title": "Validator dst address", "content": "cosmosvaloper15jlqmacda2pzerhw48gvvxskweg8sz2scfexfk", "indent": 2 },
+            { "title": "Amount", "content": "1'500 ucosm", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_create_validator_works() -> anyhow::Result<()> {
+        const MESSAGE: &str = r#"{
+            "@type": "/cosmos.staking.v1beta1.MsgCreateValidator",
+            "description": {
+                "moniker": "my validator",
+                "identity": "",
+                "website": "https://example.com",
+                "security_contact": "",
+                "details": ""
+            },
+            "commission": {
+                "rate": "0.1",
+                "max_rate": "0.2",
+                "max_change_rate": "0.01"
+            },
+            "min_self_delegation": "1",
+            "delegator_address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+            "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+            "pubkey": {
+                "type": "tendermint/PubKeyEd25519",
+                "value": "11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo="
+            },
+            "value": { "denom": "uatom", "amount": "1000000" }
+        }"#;
+
+        let msg: CreateValidator = serde_json::from_str(MESSAGE)?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Description", "content": "Description object", "indent": 2 },
+            { "title": "Moniker", "content": "my validator", "indent": 3 },
+            { "title": "Website", "content": "https://example.com", "indent": 3 },
+            { "title": "Commission", "content": "CommissionRates object", "indent": 2 },
+            { "title": "Rate", "content": "0.1", "indent": 3 },
+            { "title": "Max rate", "content": "0.2", "indent": 3 },
+            { "title": "Max change rate", "content": "0.01", "indent": 3 },
+            { "title": "Min self delegation", "content": "1", "indent": 2 },
+            { "title": "Delegator address", "content": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "indent": 2 },
+            { "title": "Validator address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 },
+            { "title": "Pubkey", "content": "/cosmos.crypto.ed25519.PubKey", "indent": 2 },
+            { "title": "Key", "content": "D75A 9801 82B1 0AB7 D54B FED3 C964 073A 0EE1 72F3 DAA6 2325 AF02 1A68 F707 511A", "indent": 3 },
+            { "title": "Value", "content": "1 ATOM", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_edit_validator_works() -> anyhow::Result<()> {
+        let msg = EditValidator::new(
+            EditDescription {
+                moniker: Some("new moniker".to_string()),
+                identity: Some(String::new()),
+                website: None,
+                security_contact: Some("security@example.com".to_string()),
+                details: None,
+            },
+            Some(Decimal256::from_str("0.12")?),
+            Some(Uint256::from(100u64)),
+            ValAddress::from_bech32("cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4")?,
+        );
+
+        const SCREENS: &str = r#"[
+            { "title": "Description", "content": "Description object", "indent": 2 },
+            { "title": "Moniker", "content": "new moniker", "indent": 3 },
+            { "title": "Security contact", "content": "security@example.com", "indent": 3 },
+            { "title": "Validator address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 },
+            { "title": "Commission rate", "content": "0.12", "indent": 2 },
+            { "title": "Min self delegation", "content": "100", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_edit_validator_without_changes_works() -> anyhow::Result<()> {
+        let msg = EditValidator::new(
+            EditDescription::default(),
+            None,
+            None,
+            ValAddress::from_bech32("cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4")?,
+        );
+
+        const SCREENS: &str = r#"[
+            { "title": "Description", "content": "Description object", "indent": 2 },
+            { "title": "Validator address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn msg_undelegate_works() -> anyhow::Result<()> {
+        const MESSAGE: &str = r#"{
+            "delegator_address": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs",
+            "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+            "amount": { "denom": "uatom", "amount": "2500000" }
+        }"#;
+
+        let msg: UndelegateMsg = serde_json::from_str(MESSAGE)?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Delegator address", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 2 },
+            { "title": "Validator address", "content": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4", "indent": 2 },
+            { "title": "Amount", "content": "2.5 ATOM", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter)?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+}
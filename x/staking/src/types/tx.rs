@@ -1,8 +1,7 @@
 use crate::consts::proto::*;
 use gears::{
-    core::{errors::CoreError, Protobuf},
+    core::{any::google::Any, errors::CoreError, Protobuf},
     derive::{AppMessage, Protobuf},
-    signing::renderer::value_renderer::ValueRenderer,
     tendermint::types::{proto::crypto::PublicKey, time::timestamp::Timestamp},
     types::{
         address::{AccAddress, ValAddress},
@@ -466,18 +465,6 @@ pub struct CreateValidator {
 
 impl Protobuf<inner::MsgCreateValidator> for CreateValidator {}
 
-impl ValueRenderer for CreateValidator {
-    fn format<MG: gears::signing::handler::MetadataGetter>(
-        &self,
-        _get_metadata: &MG,
-    ) -> Result<
-        Vec<gears::types::rendering::screen::Screen>,
-        gears::signing::renderer::value_renderer::RenderError,
-    > {
-        Err(gears::signing::renderer::value_renderer::RenderError::NotImplemented)
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, AppMessage)]
 #[msg(url = "/cosmos.staking.v1beta1.MsgEditValidator")]
 pub struct EditValidator {
@@ -737,3 +724,43 @@ impl TryFrom<UndelegateMsgRaw> for UndelegateMsg {
 }
 
 impl Protobuf<UndelegateMsgRaw> for UndelegateMsg {}
+
+/// Response of [`RedelegateMsg`] with the time at which the redelegation completes
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgBeginRedelegateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub completion_time: Option<Timestamp>,
+}
+
+impl MsgBeginRedelegateResponse {
+    pub const TYPE_URL: &'static str = "/cosmos.staking.v1beta1.MsgBeginRedelegateResponse";
+}
+
+impl From<MsgBeginRedelegateResponse> for Any {
+    fn from(msg: MsgBeginRedelegateResponse) -> Self {
+        Any {
+            type_url: MsgBeginRedelegateResponse::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+}
+
+/// Response of [`UndelegateMsg`] with the time at which the unbonding completes
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgUndelegateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub completion_time: Option<Timestamp>,
+}
+
+impl MsgUndelegateResponse {
+    pub const TYPE_URL: &'static str = "/cosmos.staking.v1beta1.MsgUndelegateResponse";
+}
+
+impl From<MsgUndelegateResponse> for Any {
+    fn from(msg: MsgUndelegateResponse) -> Self {
+        Any {
+            type_url: MsgUndelegateResponse::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+}
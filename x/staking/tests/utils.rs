@@ -218,7 +218,7 @@ impl ABCIHandler for MockStakingAbciHandler {
         &self,
         ctx: &mut gears::context::tx::TxContext<'_, DB, Self::StoreKey>,
         msg: &Self::Message,
-    ) -> Result<(), gears::application::handlers::node::TxError> {
+    ) -> Result<gears::core::any::google::Any, gears::application::handlers::node::TxError> {
         self.staking.msg(ctx, msg)
     }
 
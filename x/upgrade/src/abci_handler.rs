@@ -5,7 +5,7 @@ use std::{
 
 use gears::{
     application::handlers::node::{ABCIHandler, ModuleInfo},
-    baseapp::{errors::QueryError, genesis::NullGenesis, QueryResponse},
+    baseapp::{errors::QueryError, genesis::NullGenesis, mode::RunTxMode, QueryResponse},
     context::{query::QueryContext, QueryableContext},
     core::Protobuf,
     params::ParamsSubspaceKey,
@@ -70,7 +70,7 @@ where
         &self,
         _: &mut gears::context::tx::TxContext<'_, DB, Self::StoreKey>,
         _: &gears::types::tx::raw::TxWithRaw<Self::Message>,
-        _: bool,
+        _: RunTxMode,
     ) -> Result<(), gears::application::handlers::node::TxError> {
         Ok(())
     }
@@ -78,9 +78,9 @@ where
     fn msg<DB: gears::store::database::Database>(
         &self,
         _: &mut gears::context::tx::TxContext<'_, DB, Self::StoreKey>,
-        _: &Self::Message,
-    ) -> Result<(), gears::application::handlers::node::TxError> {
-        Ok(())
+        msg: &Self::Message,
+    ) -> Result<gears::core::any::google::Any, gears::application::handlers::node::TxError> {
+        match *msg {}
     }
 
     fn init_genesis<DB: gears::store::database::Database>(